@@ -1,4 +1,4 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -21,13 +21,82 @@ pub enum Commands {
         /// Ruta al archivo fuente
         #[arg(required = true)]
         file: PathBuf,
+
+        /// Archivo de estado: se carga antes de ejecutar (si existe) y se guarda al terminar
+        #[arg(long)]
+        state: Option<PathBuf>,
+
+        /// Vuelve a ejecutar el archivo cada vez que cambia en disco
+        #[arg(short, long)]
+        watch: bool,
+
+        /// Exporta los resultados en un formato estructurado en lugar del texto de consola
+        #[arg(short, long, value_enum)]
+        format: Option<ExportFormat>,
+
+        /// Escribe la exportación en este archivo (sin `--format` se usa JSON)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
 
     /// Información del sistema y módulos
     Info,
 
+    /// Genera la visualización de un grafo descrito como lista de aristas
+    Graph {
+        /// Archivo con una arista por línea: `origen destino [peso]`
+        #[arg(required = true)]
+        file: PathBuf,
+
+        /// Formato de salida
+        #[arg(short, long, value_enum, default_value_t = GraphFormat::Dot)]
+        format: GraphFormat,
+
+        /// Interpretar las aristas como dirigidas
+        #[arg(short, long)]
+        directed: bool,
+    },
+
+    /// Resuelve un problema de optimización (formato LP o archivo Codex)
+    Solve {
+        /// Archivo `.lp` (formato CPLEX) o script Codex con modelos de optimización
+        #[arg(required = true)]
+        file: PathBuf,
+
+        /// Exigir valores enteros en todas las variables (Branch & Bound)
+        #[arg(long)]
+        integer: bool,
+
+        /// Formato de salida
+        #[arg(short, long, value_enum, default_value_t = SolveFormat::Text)]
+        format: SolveFormat,
+    },
+
+    /// Sesión interactiva: ejecuta bloques Codex línea a línea conservando los modelos
+    Repl,
+
     // Futuros comandos escalables:
-    // Repl,
     // Check { file: PathBuf },
     // Build { project: PathBuf },
-}
\ No newline at end of file
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SolveFormat {
+    Text,
+    Json,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Json,
+    Yaml,
+    Markdown,
+    Csv,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+    Plantuml,
+}
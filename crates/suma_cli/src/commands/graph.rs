@@ -0,0 +1,63 @@
+use std::fs;
+use std::path::PathBuf;
+use anyhow::{bail, Context, Result};
+
+use suma_core::data_structures::graphs::{DirectedWeightedGraph, UndirectedWeightedGraph};
+use suma_core::formatting::visualizable::{ToDot, ToMermaid, ToPlantUml};
+
+use crate::cli::GraphFormat;
+
+pub fn execute(path: &PathBuf, format: GraphFormat, directed: bool) -> Result<()> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Could not read file '{}'", path.display()))?;
+
+    let edges = parse_edge_list(&content)?;
+
+    let output = if directed {
+        let (graph, _) = DirectedWeightedGraph::<String>::from_edges(&edges);
+        render(&graph, format)?
+    } else {
+        let (graph, _) = UndirectedWeightedGraph::<String>::from_edges(&edges);
+        render(&graph, format)?
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+fn render<G>(graph: &G, format: GraphFormat) -> Result<String>
+where
+    G: ToDot + ToMermaid + ToPlantUml,
+{
+    let output = match format {
+        GraphFormat::Dot => graph.to_dot()?,
+        GraphFormat::Mermaid => graph.to_mermaid()?,
+        GraphFormat::Plantuml => graph.to_plantuml()?,
+    };
+    Ok(output)
+}
+
+/// Lee una arista por línea: `origen destino [peso]` (peso 1 si se omite).
+/// Las líneas vacías y las que empiezan con `#` se ignoran.
+fn parse_edge_list(content: &str) -> Result<Vec<(String, String, f64)>> {
+    let mut edges = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let weight = match parts.as_slice() {
+            [_, _] => 1.0,
+            [_, _, w] => w.parse::<f64>()
+                .with_context(|| format!("Line {}: invalid weight '{}'", index + 1, w))?,
+            _ => bail!("Line {}: expected 'from to [weight]', got '{}'", index + 1, line),
+        };
+
+        edges.push((parts[0].to_string(), parts[1].to_string(), weight));
+    }
+
+    Ok(edges)
+}
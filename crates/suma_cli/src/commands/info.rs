@@ -1,14 +1,52 @@
 use anyhow::Result;
 use colored::*;
 
-pub fn execute() -> Result<()> {
-    println!("{}", "SUMA CLI v0.1.0".green().bold());
-    println!("Arquitectura:   Modular (Core -> Codex -> CLI)");
-    println!("Estado:         Alpha");
-    println!("\nDominios Registrados:");
-    println!("  - {}", "Optimización".cyan());
-    println!("  - {}", "Lógica Booleana".cyan());
-    println!("  - {}", "Álgebra Lineal".cyan());
+/// Formatos de salida que ofrece `suma_core::formatting`.
+const EXPORT_FORMATS: &[&str] = &["JSON", "NDJSON", "YAML", "CSV", "Markdown", "Excel (.xlsx)"];
+const VISUAL_FORMATS: &[&str] = &["DOT (Graphviz)", "Mermaid", "PlantUML"];
 
+pub fn execute() -> Result<()> {
+    println!("{}", render());
     Ok(())
-}
\ No newline at end of file
+}
+
+fn render() -> String {
+    let mut out = String::new();
+
+    out.push_str(&format!("{}\n", format!("SUMA CLI v{}", env!("CARGO_PKG_VERSION")).green().bold()));
+    out.push_str("Arquitectura:   Modular (Core -> Codex -> CLI)\n");
+    out.push_str("Estado:         Alpha\n");
+
+    out.push_str("\nDominios Registrados:\n");
+    for (name, keywords) in super::build_engine().domains() {
+        out.push_str(&format!("  - {} ({})\n", name.cyan(), keywords.join(", ")));
+    }
+
+    out.push_str("\nFormatos de Exportación:\n");
+    out.push_str(&format!("  - Tablas:         {}\n", EXPORT_FORMATS.join(", ")));
+    out.push_str(&format!("  - Visualización:  {}", VISUAL_FORMATS.join(", ")));
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info_lists_registered_domains() {
+        let output = render();
+
+        for (name, keywords) in crate::commands::build_engine().domains() {
+            assert!(output.contains(name), "falta el dominio {}", name);
+            for kw in keywords {
+                assert!(output.contains(kw), "falta la palabra clave {}", kw);
+            }
+        }
+        for name in ["Optimización", "Lógica Booleana", "Álgebra Lineal", "Consultas"] {
+            assert!(output.contains(name));
+        }
+        assert!(output.contains(env!("CARGO_PKG_VERSION")));
+        assert!(output.contains("Mermaid"));
+    }
+}
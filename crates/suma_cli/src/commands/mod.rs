@@ -1,2 +1,27 @@
 pub mod run;
-pub mod info;
\ No newline at end of file
+pub mod info;
+pub mod graph;
+pub mod solve;
+pub mod repl;
+
+use suma_codex::CodexEngine;
+
+// Imports de los Parsers (Plugins)
+use suma_codex::domains::optimization::parser::OptimizationParser;
+use suma_codex::domains::boolean_algebra::parser::BooleanParser;
+use suma_codex::domains::linear_algebra::parser::LinearAlgebraParser;
+use suma_codex::domains::queries::parser::QueryParser;
+use suma_codex::domains::probability::ProbabilityParser;
+use suma_codex::domains::graph::GraphParser;
+
+/// Motor Codex con todos los dominios que soporta la CLI.
+pub fn build_engine() -> CodexEngine {
+    let mut engine = CodexEngine::new();
+    engine.register(OptimizationParser);
+    engine.register(BooleanParser);
+    engine.register(LinearAlgebraParser);
+    engine.register(ProbabilityParser);
+    engine.register(GraphParser);
+    engine.register(QueryParser);
+    engine
+}
@@ -0,0 +1,209 @@
+use std::io::{self, BufRead, Write};
+use anyhow::Result;
+use colored::*;
+
+use suma_codex::CodexEngine;
+use suma_codex::engine::executor::CodexExecutor;
+use suma_codex::outputs::CodexOutput;
+
+use super::run::print_output;
+
+const PROMPT: &str = "suma> ";
+const CONTINUATION_PROMPT: &str = "  ... ";
+
+const HELP: &str = "\
+Escribe bloques Codex; los que ocupan varias líneas se ejecutan al cerrar su última '}'.
+Los modelos definidos se recuerdan durante toda la sesión:
+
+  Optimization \"Plan\" {
+      maximize 3*x + 2*y
+      constraints { x + y <= 4 }
+  }
+  query \"Plan\" { solve }
+
+Comandos:
+  :help    Muestra esta ayuda
+  :quit    Sale del REPL (también :q, :exit o Ctrl+D)";
+
+/// Comandos propios del REPL (empiezan con ':').
+#[derive(Debug, PartialEq, Eq)]
+enum ReplCommand {
+    Help,
+    Quit,
+    Unknown(String),
+}
+
+impl ReplCommand {
+    fn parse(line: &str) -> Option<Self> {
+        let command = line.trim().strip_prefix(':')?;
+        Some(match command {
+            "help" | "h" => ReplCommand::Help,
+            "quit" | "q" | "exit" => ReplCommand::Quit,
+            other => ReplCommand::Unknown(other.to_string()),
+        })
+    }
+}
+
+/// Sesión interactiva: acumula líneas hasta completar los bloques `{ }` y los
+/// ejecuta con un único `CodexExecutor`, de modo que los modelos definidos en
+/// una entrada siguen disponibles para las consultas de las siguientes.
+pub struct ReplSession {
+    engine: CodexEngine,
+    executor: CodexExecutor,
+    buffer: String,
+    depth: i32,
+}
+
+impl ReplSession {
+    pub fn new(verbose: bool) -> Self {
+        Self {
+            engine: super::build_engine(),
+            executor: CodexExecutor::new(verbose),
+            buffer: String::new(),
+            depth: 0,
+        }
+    }
+
+    /// `true` si hay un bloque abierto esperando más líneas.
+    pub fn is_pending(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+
+    /// Agrega una línea de código. Cuando las llaves quedan balanceadas, procesa
+    /// lo acumulado y envía las salidas a `observer`; devuelve `true` en ese caso.
+    pub fn feed<F>(&mut self, line: &str, observer: F) -> bool
+    where F: FnMut(&str, CodexOutput)
+    {
+        if line.trim().is_empty() && !self.is_pending() {
+            return false;
+        }
+
+        self.depth += brace_delta(line);
+        self.buffer.push_str(line);
+        self.buffer.push('\n');
+
+        // Una '}' de más también cierra la entrada: el parser reportará el error.
+        if self.depth > 0 {
+            return false;
+        }
+
+        let source = std::mem::take(&mut self.buffer);
+        self.depth = 0;
+
+        let results = self.engine.process_file(&source);
+        if results.is_empty() {
+            println!("{}", "[WARNING] No se reconoció ningún bloque ejecutable.".yellow());
+        } else {
+            self.executor.run(results, observer);
+        }
+        true
+    }
+}
+
+/// Diferencia entre llaves abiertas y cerradas, ignorando las que van entre comillas.
+fn brace_delta(line: &str) -> i32 {
+    let mut in_string = false;
+    let mut delta = 0;
+    for c in line.chars() {
+        match c {
+            '"' => in_string = !in_string,
+            '{' if !in_string => delta += 1,
+            '}' if !in_string => delta -= 1,
+            _ => {}
+        }
+    }
+    delta
+}
+
+pub fn execute(verbose: bool) -> Result<()> {
+    println!("{}", format!("SUMA REPL v{}", env!("CARGO_PKG_VERSION")).green().bold());
+    println!("{}", "Escribe :help para ver la ayuda, :quit para salir.".dimmed());
+
+    let mut session = ReplSession::new(verbose);
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        print!("{}", if session.is_pending() { CONTINUATION_PROMPT } else { PROMPT });
+        io::stdout().flush()?;
+
+        // EOF (Ctrl+D) termina la sesión
+        let Some(line) = lines.next() else {
+            println!();
+            break;
+        };
+        let line = line?;
+
+        if !session.is_pending() {
+            match ReplCommand::parse(&line) {
+                Some(ReplCommand::Quit) => break,
+                Some(ReplCommand::Help) => {
+                    println!("{}", HELP);
+                    continue;
+                }
+                Some(ReplCommand::Unknown(name)) => {
+                    println!("{}", format!("[ERROR] Comando desconocido ':{}'. Usa :help.", name).red());
+                    continue;
+                }
+                None => {}
+            }
+        }
+
+        session.feed(&line, print_output);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(ReplCommand::parse(":quit"), Some(ReplCommand::Quit));
+        assert_eq!(ReplCommand::parse("  :q "), Some(ReplCommand::Quit));
+        assert_eq!(ReplCommand::parse(":help"), Some(ReplCommand::Help));
+        assert_eq!(ReplCommand::parse(":foo"), Some(ReplCommand::Unknown("foo".into())));
+        assert_eq!(ReplCommand::parse("query \"X\" { solve }"), None);
+    }
+
+    #[test]
+    fn test_brace_delta_ignores_strings() {
+        assert_eq!(brace_delta("Optimization \"Plan\" {"), 1);
+        assert_eq!(brace_delta("constraints { x <= 4 }"), 0);
+        assert_eq!(brace_delta("}"), -1);
+        assert_eq!(brace_delta("query \"a{b\" {"), 1);
+    }
+
+    #[test]
+    fn test_session_keeps_models_between_entries() {
+        let mut session = ReplSession::new(false);
+        let mut outputs: Vec<(String, CodexOutput)> = Vec::new();
+
+        let definition = [
+            "Optimization \"Plan\" {",
+            "    maximize 3*x + 2*y",
+            "    constraints {",
+            "        x + y <= 4",
+            "        x <= 3",
+            "    }",
+        ];
+        for line in definition {
+            assert!(!session.feed(line, |alias, o| outputs.push((alias.to_string(), o))));
+            assert!(session.is_pending());
+        }
+        assert!(session.feed("}", |alias, o| outputs.push((alias.to_string(), o))));
+        assert!(!session.is_pending());
+
+        // La consulta llega en otra entrada y encuentra el modelo
+        assert!(session.feed("query \"Plan\" { solve }", |alias, o| outputs.push((alias.to_string(), o))));
+
+        assert!(outputs.iter().all(|(_, o)| !matches!(o, CodexOutput::Error(_))), "{:?}", outputs);
+        let result = outputs.iter().find_map(|(alias, o)| match o {
+            CodexOutput::Message(text) if alias == "Result" => Some(text.clone()),
+            _ => None,
+        });
+        assert!(result.expect("Falta el resultado de 'solve'").contains("Z = 11"));
+    }
+}
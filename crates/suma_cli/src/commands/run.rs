@@ -1,22 +1,49 @@
 use std::fs;
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use anyhow::{Context, Result};
 use colored::*;
+use serde::Serialize;
+use serde_json::{json, Value};
 
 // Imports del Motor
-use suma_codex::CodexEngine;
 use suma_codex::engine::executor::CodexExecutor;
 
-// Imports de los Parsers (Plugins)
-use suma_codex::domains::optimization::parser::OptimizationParser;
-use suma_codex::domains::boolean_algebra::parser::BooleanParser;
-use suma_codex::domains::linear_algebra::parser::LinearAlgebraParser;
-use suma_codex::domains::queries::parser::QueryParser; 
-
 use suma_codex::outputs::CodexOutput;
+use suma_core::formatting::export::{export_csv_to_writer, Exportable};
+use suma_core::linear_algebra::DenseMatrix;
+
+use crate::cli::ExportFormat;
+use crate::utils::watch::watch_loop;
+
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Modo `--watch`: ejecuta el archivo y lo repite cada vez que se guarda.
+/// Los errores se muestran sin terminar el proceso, para poder corregir y volver a guardar.
+pub fn watch(
+    path: &PathBuf,
+    state: Option<&PathBuf>,
+    format: Option<ExportFormat>,
+    output: Option<&PathBuf>,
+    verbose: bool,
+) -> Result<()> {
+    watch_loop(path, WATCH_POLL_INTERVAL, None, || {
+        if let Err(err) = execute(path, state, format, output, verbose) {
+            println!("{}", format!("[ERROR] {:#}", err).red().bold());
+        }
+        println!("{}", format!("-- Watching {} (Ctrl+C to exit) --", path.display()).dimmed());
+    })
+}
 
-pub fn execute(path: &PathBuf, verbose: bool) -> Result<()> {
+/// Ejecuta el archivo. Con `format` (o `output`) las salidas se recogen y se exportan
+/// al terminar; si no, se imprimen en consola a medida que llegan.
+pub fn execute(
+    path: &PathBuf,
+    state: Option<&PathBuf>,
+    format: Option<ExportFormat>,
+    output: Option<&PathBuf>,
+    verbose: bool,
+) -> Result<()> {
     if verbose {
         println!(">> Reading file: {:?}", path);
     }
@@ -24,14 +51,8 @@ pub fn execute(path: &PathBuf, verbose: bool) -> Result<()> {
     let content = fs::read_to_string(path)
         .with_context(|| format!("Could not read file '{}'", path.display()))?;
 
-    // 1. Configurar Motor
-    let mut engine = CodexEngine::new();
-    
-    // Registramos los dominios
-    engine.register(OptimizationParser);
-    engine.register(BooleanParser);
-    engine.register(LinearAlgebraParser);
-    engine.register(QueryParser); 
+    // 1. Configurar Motor (con todos los dominios registrados)
+    let engine = super::build_engine();
 
     // 2. Parsing
     let start = Instant::now();
@@ -50,33 +71,157 @@ pub fn execute(path: &PathBuf, verbose: bool) -> Result<()> {
     // 3. Ejecución
     if verbose { println!("-- Execution Start --"); }
     
-    let mut console_observer = |label: &str, output: CodexOutput| {
-        print!("➜ {}: ", label.blue().bold());
+    let mut executor = CodexExecutor::new(verbose);
+    if let Some(state_path) = state.filter(|p| p.exists()) {
+        executor.load_state(state_path)?;
+        if verbose { println!(">> State loaded from {:?}", state_path); }
+    }
 
+    let export_format = format.or(output.map(|_| ExportFormat::Json));
+    let mut collected = Vec::new();
+    match export_format {
+        Some(_) => executor.run(results, |label: &str, out| collected.push(ExportedOutput::new(label, out))),
+        None => executor.run(results, print_output),
+    }
+
+    if let Some(state_path) = state {
+        executor.save_state(state_path)?;
+        if verbose { println!(">> State saved to {:?}", state_path); }
+    }
+    
+    if verbose { println!("-- Execution End --"); }
+
+    if let Some(format) = export_format {
+        let rendered = render_export(collected, format)?;
         match output {
-            CodexOutput::LinAlgScalar(val) => {
-                println!("{:.4}", val.to_string().green());
-            },
-            CodexOutput::LinAlgMatrix(mat) | CodexOutput::LinAlgVector(mat) => {
-                println!();
-                println!("{:.2}", mat);
-            },
-            // Manejamos Message por si acaso (para compatibilidad)
-            CodexOutput::Message(msg) => {
-                println!();
-                println!("{}", msg); // Quitamos italic para que se lea mejor en resultados grandes
-            },
-            CodexOutput::Error(err) => {
-                println!("{}", err.red().bold());
+            Some(output_path) => {
+                fs::write(output_path, rendered)
+                    .with_context(|| format!("Could not write file '{}'", output_path.display()))?;
+                if verbose { println!(">> Results written to {:?}", output_path); }
             }
-            // Agrega un catch-all por si agregamos nuevos tipos y olvidamos actualizar aquí
-            _ => println!("{:?}", output),
+            None => print!("{}", rendered),
         }
+    }
+
+    Ok(())
+}
+
+/// Tabla de texto con columnas alineadas a la derecha según su celda más ancha.
+fn render_table(headers: &[String], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = headers.iter().enumerate()
+        .map(|(col, header)| {
+            rows.iter()
+                .filter_map(|row| row.get(col))
+                .map(|cell| cell.chars().count())
+                .fold(header.chars().count(), usize::max)
+        })
+        .collect();
+
+    let format_row = |cells: &[String]| {
+        let padded: Vec<String> = cells.iter().zip(&widths)
+            .map(|(cell, &width)| format!("{:>width$}", cell, width = width))
+            .collect();
+        format!("  {}\n", padded.join(" | "))
     };
 
-    CodexExecutor::execute(results, verbose, &mut console_observer);
-    
-    if verbose { println!("-- Execution End --"); }
+    let mut out = format_row(headers).bold().to_string();
+    let separator: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+    out.push_str(&format!("  {}\n", separator.join("-+-")));
+    for row in rows {
+        out.push_str(&format_row(row));
+    }
+    out
+}
 
-    Ok(())
-}
\ No newline at end of file
+/// Una salida del executor en forma serializable para `--format`.
+#[derive(Debug, Serialize)]
+struct ExportedOutput {
+    label: String,
+    kind: &'static str,
+    value: Value,
+}
+
+impl ExportedOutput {
+    fn new(label: &str, output: CodexOutput) -> Self {
+        let (kind, value) = match output {
+            CodexOutput::LinAlgScalar(val) => ("scalar", json!(val)),
+            CodexOutput::LinAlgVector(mat) => ("vector", json!(mat.data)),
+            CodexOutput::LinAlgMatrix(mat) => ("matrix", matrix_rows(&mat)),
+            CodexOutput::Distribution(dist) => (
+                "distribution",
+                Value::Object(dist.into_iter().map(|(state, p)| (state, json!(p))).collect()),
+            ),
+            CodexOutput::Message(msg) => ("message", Value::String(msg)),
+            CodexOutput::Error(err) => ("error", Value::String(err)),
+            CodexOutput::Table { headers, rows } => ("table", json!({ "headers": headers, "rows": rows })),
+        };
+        Self { label: label.to_string(), kind, value }
+    }
+
+    /// Para los formatos tabulares el valor ocupa una sola celda de texto.
+    fn into_cell(self, line_break: &str) -> Self {
+        let text = match self.value {
+            Value::String(text) => text,
+            other => other.to_string(),
+        };
+        Self { value: Value::String(text.replace('\n', line_break)), ..self }
+    }
+}
+
+fn matrix_rows(mat: &DenseMatrix<f64>) -> Value {
+    json!(mat.data.chunks(mat.cols.max(1)).collect::<Vec<_>>())
+}
+
+fn render_export(outputs: Vec<ExportedOutput>, format: ExportFormat) -> Result<String> {
+    let rendered = match format {
+        ExportFormat::Json => format!("{}\n", outputs.to_json()?),
+        ExportFormat::Yaml => outputs.to_yaml()?,
+        ExportFormat::Csv => {
+            // Un registro por fila, en el orden de los campos del struct
+            let cells: Vec<_> = outputs.into_iter().map(|o| o.into_cell("\n")).collect();
+            let mut buffer = Vec::new();
+            export_csv_to_writer(cells.iter(), &mut buffer)?;
+            String::from_utf8(buffer)?
+        }
+        ExportFormat::Markdown => {
+            let cells: Vec<_> = outputs.into_iter().map(|o| o.into_cell("<br>")).collect();
+            format!("{}\n", cells.to_markdown()?)
+        }
+    };
+    Ok(rendered)
+}
+
+/// Observador de consola: pinta cada salida del executor según su tipo.
+pub fn print_output(label: &str, output: CodexOutput) {
+    print!("➜ {}: ", label.blue().bold());
+
+    match output {
+        CodexOutput::LinAlgScalar(val) => {
+            println!("{:.4}", val.to_string().green());
+        },
+        CodexOutput::LinAlgMatrix(mat) | CodexOutput::LinAlgVector(mat) => {
+            println!();
+            println!("{:.2}", mat);
+        },
+        // Manejamos Message por si acaso (para compatibilidad)
+        CodexOutput::Message(msg) => {
+            println!();
+            println!("{}", msg); // Quitamos italic para que se lea mejor en resultados grandes
+        },
+        CodexOutput::Distribution(dist) => {
+            println!();
+            for (state, p) in dist {
+                println!("  P({}) = {}", state, format!("{:.4}", p).green());
+            }
+        },
+        CodexOutput::Error(err) => {
+            println!("{}", err.red().bold());
+        }
+        CodexOutput::Table { headers, rows } => {
+            println!();
+            print!("{}", render_table(&headers, &rows));
+        }
+        // Agrega un catch-all por si agregamos nuevos tipos y olvidamos actualizar aquí
+        _ => println!("{:?}", output),
+    }
+}
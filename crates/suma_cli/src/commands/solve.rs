@@ -0,0 +1,121 @@
+use std::fs;
+use std::path::Path;
+use anyhow::{bail, Context, Result};
+use serde_json::json;
+
+use suma_core::optimization::linear::model::LinearProblem;
+use suma_core::optimization::linear::algorithms::simplex::solve_primal;
+use suma_core::optimization::linear::error::Solution;
+use suma_core::optimization::integer::problem::IntegerProblem;
+use suma_core::optimization::integer::branch_bound::solve_integer;
+use suma_codex::engine::executor::CodexExecutor;
+use suma_codex::outputs::CodexOutput;
+
+use crate::cli::SolveFormat;
+
+pub fn execute(path: &Path, integer: bool, format: SolveFormat) -> Result<()> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Could not read file '{}'", path.display()))?;
+
+    let problems = load_problems(path, &content)?;
+    if problems.is_empty() {
+        bail!("No optimization models found in '{}'", path.display());
+    }
+
+    let mut reports = Vec::new();
+    for (name, problem) in problems {
+        let solution = solve(&problem, integer)
+            .with_context(|| format!("Could not solve model '{}'", name))?;
+        reports.push((name, solution));
+    }
+
+    match format {
+        SolveFormat::Text => print!("{}", render_text(&reports)),
+        SolveFormat::Json => println!("{}", render_json(&reports)?),
+    }
+    Ok(())
+}
+
+/// Un archivo `.lp` (o que empieza con Maximize/Minimize) se lee en formato LP;
+/// cualquier otro se procesa como script Codex y se toman sus modelos de optimización.
+/// Los errores del script (sintaxis incluida) se reportan en lugar de ignorarse.
+fn load_problems(path: &Path, content: &str) -> Result<Vec<(String, LinearProblem)>> {
+    if is_lp_format(path, content) {
+        let problem = LinearProblem::from_lp_string(content)?;
+        return Ok(vec![(problem.name.clone(), problem)]);
+    }
+
+    let results = super::build_engine().process_file(content);
+    let mut executor = CodexExecutor::new(false);
+    let mut errors = Vec::new();
+    executor.run(results, |label: &str, output| {
+        if let CodexOutput::Error(message) = output {
+            errors.push(format!("{}: {}", label, message));
+        }
+    });
+    if !errors.is_empty() {
+        bail!("Errors in '{}':\n{}", path.display(), errors.join("\n"));
+    }
+
+    let mut problems: Vec<_> = executor.state().optimization.into_iter().collect();
+    problems.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(problems)
+}
+
+fn is_lp_format(path: &Path, content: &str) -> bool {
+    if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("lp")) {
+        return true;
+    }
+    content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('\\'))
+        .is_some_and(|line| {
+            let lower = line.to_lowercase();
+            lower.starts_with("max") || lower.starts_with("min")
+        })
+}
+
+fn solve(problem: &LinearProblem, integer: bool) -> Result<Solution> {
+    if integer {
+        let mut integer_problem = IntegerProblem::new(problem.clone());
+        for var in problem.get_variables() {
+            integer_problem.mark_as_integer(&var);
+        }
+        Ok(solve_integer(&integer_problem)?)
+    } else {
+        Ok(solve_primal(problem)?)
+    }
+}
+
+fn render_text(reports: &[(String, Solution)]) -> String {
+    let mut out = String::new();
+    for (name, solution) in reports {
+        if reports.len() > 1 {
+            out.push_str(&format!("Model '{}'\n", name));
+        }
+        out.push_str(&format!("{:?} (Z = {:.4})\n", solution.status, solution.objective_value));
+        for (var, value) in solution.sorted_variables() {
+            out.push_str(&format!("  {} = {:.4}\n", var, value));
+        }
+    }
+    out
+}
+
+/// Misma forma que `run --format json`: una lista de `{ label, kind, value }`.
+fn render_json(reports: &[(String, Solution)]) -> Result<String> {
+    let models: Vec<_> = reports
+        .iter()
+        .map(|(name, solution)| json!({
+            "label": name,
+            "kind": "solution",
+            "value": {
+                "status": format!("{:?}", solution.status),
+                "objective_value": solution.objective_value,
+                "variables": solution.variables,
+            },
+        }))
+        .collect();
+
+    Ok(serde_json::to_string_pretty(&models)?)
+}
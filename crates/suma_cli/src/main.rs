@@ -18,11 +18,21 @@ fn main() -> Result<()> {
         Commands::Info => {
             commands::info::execute()?;
         }
-        Commands::Run { file } => {
-            commands::run::execute(file, args.verbose)?;
+        Commands::Run { file, state, watch: false, format, output } => {
+            commands::run::execute(file, state.as_ref(), *format, output.as_ref(), args.verbose)?;
+        }
+        Commands::Run { file, state, watch: true, format, output } => {
+            commands::run::watch(file, state.as_ref(), *format, output.as_ref(), args.verbose)?;
+        }
+        Commands::Graph { file, format, directed } => {
+            commands::graph::execute(file, *format, *directed)?;
+        }
+        Commands::Solve { file, integer, format } => {
+            commands::solve::execute(file, *integer, *format)?;
+        }
+        Commands::Repl => {
+            commands::repl::execute(args.verbose)?;
         }
-        // Aquí agregarás nuevos casos fácilmente:
-        // Commands::Repl => commands::repl::execute()?,
     }
 
     Ok(())
@@ -0,0 +1,111 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+use anyhow::{Context, Result};
+
+/// Detecta cambios en un archivo comparando fecha de modificación y tamaño (sondeo).
+pub struct FileWatcher {
+    path: PathBuf,
+    last_seen: (SystemTime, u64),
+}
+
+impl FileWatcher {
+    pub fn new(path: &Path) -> Result<Self> {
+        let last_seen = fingerprint(path)?;
+        Ok(Self { path: path.to_path_buf(), last_seen })
+    }
+
+    /// `true` si el archivo cambió desde la última consulta.
+    /// Si el archivo desaparece momentáneamente (editores que reescriben), no cuenta como cambio.
+    pub fn changed(&mut self) -> bool {
+        match fingerprint(&self.path) {
+            Ok(current) if current != self.last_seen => {
+                self.last_seen = current;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Bloquea hasta detectar un cambio, consultando cada `poll`.
+    pub fn wait_for_change(&mut self, poll: Duration) {
+        while !self.changed() {
+            thread::sleep(poll);
+        }
+    }
+}
+
+fn fingerprint(path: &Path) -> Result<(SystemTime, u64)> {
+    let meta = fs::metadata(path)
+        .with_context(|| format!("Could not read file '{}'", path.display()))?;
+    Ok((meta.modified()?, meta.len()))
+}
+
+/// Ejecuta `run` y lo repite cada vez que cambia `path`.
+/// `max_runs` limita el número de ejecuciones (`None` = hasta que se interrumpa el proceso).
+pub fn watch_loop<F>(path: &Path, poll: Duration, max_runs: Option<usize>, mut run: F) -> Result<()>
+where
+    F: FnMut(),
+{
+    let mut watcher = FileWatcher::new(path)?;
+    let mut runs = 0;
+
+    loop {
+        run();
+        runs += 1;
+        if max_runs.is_some_and(|max| runs >= max) {
+            return Ok(());
+        }
+        watcher.wait_for_change(poll);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    fn touch(path: &Path, content: &str, offset_secs: u64) {
+        fs::write(path, content).unwrap();
+        // Forzamos una fecha distinta: algunos sistemas de archivos tienen resolución de 1-2 s
+        let file = File::options().write(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(offset_secs)).unwrap();
+    }
+
+    #[test]
+    fn test_watcher_detects_change() {
+        let path = std::env::temp_dir().join("suma_cli_watch_detect.suma");
+        fs::write(&path, "a").unwrap();
+
+        let mut watcher = FileWatcher::new(&path).unwrap();
+        assert!(!watcher.changed());
+
+        touch(&path, "ab", 10);
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_watch_loop_reprocesses_on_change() {
+        let path = std::env::temp_dir().join("suma_cli_watch_loop.suma");
+        fs::write(&path, "v1").unwrap();
+
+        let writer_path = path.clone();
+        let writer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            touch(&writer_path, "v2", 10);
+        });
+
+        let mut seen = Vec::new();
+        watch_loop(&path, Duration::from_millis(5), Some(2), || {
+            seen.push(fs::read_to_string(&path).unwrap());
+        }).unwrap();
+        writer.join().unwrap();
+        fs::remove_file(&path).ok();
+
+        assert_eq!(seen, vec!["v1".to_string(), "v2".to_string()]);
+    }
+}
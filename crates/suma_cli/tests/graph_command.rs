@@ -0,0 +1,58 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn graph_command_emits_dot() {
+    let path = std::env::temp_dir().join("suma_cli_graph_test.txt");
+    fs::write(&path, "# triángulo\nA B 2\nB C\nC A 4.5\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_suma_cli"))
+        .args(["graph", path.to_str().unwrap(), "--format", "dot", "--directed"])
+        .output()
+        .expect("failed to run suma_cli");
+    fs::remove_file(&path).ok();
+
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("digraph G {"));
+    assert!(stdout.trim_end().ends_with('}'));
+    assert!(stdout.contains("0 -> 1 [label=\"2\"];"));
+    assert!(stdout.contains("1 -> 2 [label=\"1\"];"));
+    assert!(stdout.contains("2 -> 0 [label=\"4.5\"];"));
+    // Los ids internos se declaran con el nombre que usó el archivo
+    assert!(stdout.contains("0 [label=\"A\"];"), "stdout: {}", stdout);
+    assert!(stdout.contains("2 [label=\"C\"];"));
+}
+
+#[test]
+fn graph_command_mermaid_uses_labels() {
+    let path = std::env::temp_dir().join("suma_cli_graph_mermaid.txt");
+    fs::write(&path, "Lima Cusco 3\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_suma_cli"))
+        .args(["graph", path.to_str().unwrap(), "--format", "mermaid"])
+        .output()
+        .expect("failed to run suma_cli");
+    fs::remove_file(&path).ok();
+
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("0[\"Lima\"]"), "stdout: {}", stdout);
+    assert!(stdout.contains("1[\"Cusco\"]"));
+}
+
+#[test]
+fn graph_command_rejects_malformed_lines() {
+    let path = std::env::temp_dir().join("suma_cli_graph_bad.txt");
+    fs::write(&path, "A B C D\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_suma_cli"))
+        .args(["graph", path.to_str().unwrap()])
+        .output()
+        .expect("failed to run suma_cli");
+    fs::remove_file(&path).ok();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Line 1"));
+}
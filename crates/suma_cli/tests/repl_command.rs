@@ -0,0 +1,48 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn run_repl(input: &str) -> std::process::Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_suma_cli"))
+        .arg("repl")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run suma_cli");
+
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn repl_remembers_models_across_lines() {
+    let input = "\
+Optimization \"Plan\" {
+    maximize 30*x + 50*y
+    constraints {
+        x + 2*y <= 20
+        x <= 10
+    }
+}
+query \"Plan\" { solve }
+:quit
+query \"Plan\" { shadow_prices }
+";
+    let output = run_repl(input);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Z = 550.0000"), "stdout: {}", stdout);
+    // Lo que viene después de :quit no se ejecuta
+    assert!(!stdout.contains("Shadow Prices"), "stdout: {}", stdout);
+}
+
+#[test]
+fn repl_help_and_unknown_commands() {
+    let output = run_repl(":help\n:nope\n");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(":quit"));
+    assert!(stdout.contains("Comando desconocido ':nope'"));
+}
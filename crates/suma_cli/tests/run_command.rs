@@ -0,0 +1,67 @@
+use std::fs;
+use std::process::Command;
+
+const SCRIPT: &str = r#"
+Optimization "Plan" {
+    maximize 30*x + 50*y
+    constraints {
+        x + 2*y <= 20
+        x <= 10
+    }
+}
+
+LinearSystem "Sistema" {
+    coefficients: [1, 2; 3, 4]
+    constants:    [5; 6]
+}
+
+query "Plan" { solve }
+query "Sistema" { determinant as det }
+"#;
+
+fn run_export(file_name: &str, extra_args: &[&str]) -> std::process::Output {
+    let path = std::env::temp_dir().join(file_name);
+    fs::write(&path, SCRIPT).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_suma_cli"))
+        .arg("run")
+        .arg(&path)
+        .args(extra_args)
+        .output()
+        .expect("failed to run suma_cli");
+    fs::remove_file(&path).ok();
+    output
+}
+
+#[test]
+fn run_command_exports_json() {
+    let output = run_export("suma_cli_run_json.codex", &["--format", "json"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let records = records.as_array().unwrap();
+
+    let det = records.iter().find(|r| r["label"] == "det").expect("falta el determinante");
+    assert_eq!(det["kind"], "scalar");
+    assert!((det["value"].as_f64().unwrap() + 2.0).abs() < 1e-9);
+
+    let solve = records.iter().find(|r| r["label"] == "Result").expect("falta el resultado de solve");
+    assert_eq!(solve["kind"], "message");
+    assert!(solve["value"].as_str().unwrap().contains("Z = 550.0000"));
+}
+
+#[test]
+fn run_command_writes_csv_to_output_file() {
+    let out_path = std::env::temp_dir().join("suma_cli_run_export.csv");
+    let output = run_export(
+        "suma_cli_run_csv.codex",
+        &["--format", "csv", "--output", out_path.to_str().unwrap()],
+    );
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.stdout.is_empty(), "stdout: {}", String::from_utf8_lossy(&output.stdout));
+
+    let csv = fs::read_to_string(&out_path).unwrap();
+    fs::remove_file(&out_path).ok();
+    assert!(csv.starts_with("label,kind,value\n"), "{}", csv);
+    assert!(csv.contains("det,scalar,-2"), "{}", csv);
+}
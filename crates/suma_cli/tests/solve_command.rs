@@ -0,0 +1,81 @@
+use std::fs;
+use std::process::Command;
+
+const LP: &str = "\\ Problema de ejemplo
+Maximize
+ obj: 30 x + 50 y
+Subject To
+ madera: x + 2 y <= 20
+ horas: x <= 10
+End
+";
+
+fn run_solve(file_name: &str, content: &str, extra_args: &[&str]) -> std::process::Output {
+    let path = std::env::temp_dir().join(file_name);
+    fs::write(&path, content).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_suma_cli"))
+        .arg("solve")
+        .arg(&path)
+        .args(extra_args)
+        .output()
+        .expect("failed to run suma_cli");
+    fs::remove_file(&path).ok();
+    output
+}
+
+#[test]
+fn solve_command_prints_optimum() {
+    let output = run_solve("suma_cli_solve_test.lp", LP, &[]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Optimal (Z = 550.0000)"), "stdout: {}", stdout);
+    assert!(stdout.contains("x = 10.0000"));
+    assert!(stdout.contains("y = 5.0000"));
+}
+
+#[test]
+fn solve_command_integer_json() {
+    // Relajación: x = 2.5 (Z = 2.5). Entera: x = 2 (Z = 2).
+    let lp = "Maximize\n obj: x\nSubject To\n c1: 2 x <= 5\nEnd\n";
+    let output = run_solve("suma_cli_solve_int.lp", lp, &["--integer", "--format", "json"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("\"objective_value\": 2.0"), "stdout: {}", stdout);
+    assert!(stdout.contains("\"x\": 2.0"));
+}
+
+#[test]
+fn solve_command_reports_infeasible() {
+    let lp = "Maximize\n obj: x\nSubject To\n c1: x >= 5\n c2: x <= 2\nEnd\n";
+    let output = run_solve("suma_cli_solve_infeasible.lp", lp, &[]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn solve_command_reads_codex_models() {
+    let codex = "Optimization \"Plan\" {\n    maximize 3*x + 2*y\n    constraints {\n        x + y <= 4\n        x <= 3\n    }\n}\n";
+    let output = run_solve("suma_cli_solve_model.codex", codex, &["--format", "json"]);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&stdout).expect("JSON válido");
+    let models = value.as_array().expect("Una lista, como en run --format json");
+    assert_eq!(models.len(), 1);
+    assert_eq!(models[0]["label"], "Plan");
+    assert_eq!(models[0]["kind"], "solution");
+    assert_eq!(models[0]["value"]["objective_value"], 11.0);
+}
+
+#[test]
+fn solve_command_reports_codex_syntax_errors() {
+    let codex = "Optimization \"Plan\" {\n    maximize 3*x + \n    constraints { x <= 3 }\n}\n";
+    let output = run_solve("suma_cli_solve_broken.codex", codex, &[]);
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Syntax Error"), "stderr: {}", stderr);
+    assert!(!stderr.contains("No optimization models found"), "stderr: {}", stderr);
+}
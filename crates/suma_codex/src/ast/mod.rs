@@ -6,6 +6,9 @@ use crate::domains::boolean_algebra::BooleanModel;
 use crate::domains::linear_algebra::ast::LinearAlgebraBlock;
 use crate::domains::optimization::ast::OptimizationBlock;
 use crate::domains::queries::ast::QueryBlock;
+use crate::domains::probability::ast::BayesNetModel;
+use crate::domains::graph::ast::GraphModel;
+use crate::parsers::utils::SyntaxError;
 
 #[derive(Debug, Serialize, Clone)]
 pub enum CodexResult {
@@ -18,5 +21,14 @@ pub enum CodexResult {
     // Resultado de Matrices (Gauss-Jordan/Systems)
     LinearAlgebra(LinearAlgebraBlock),
 
+    // Redes Bayesianas (Inferencia)
+    Probability(BayesNetModel),
+
+    // Grafos ponderados (Caminos mínimos / MST)
+    Graph(GraphModel),
+
     Query(QueryBlock),
+
+    // Bloque que no se pudo parsear, con su posición en el archivo
+    ParseError(SyntaxError),
 }
\ No newline at end of file
@@ -1,11 +1,11 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum BoolOp {
     And, Or
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum BoolExpr {
     Literal(bool),
     Variable(String),
@@ -18,7 +18,7 @@ pub enum BoolExpr {
 }
 
 // El resultado final de este dominio es simplemente una expresión raíz
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BooleanModel {
     pub name: Option<String>,
     pub root: BoolExpr,
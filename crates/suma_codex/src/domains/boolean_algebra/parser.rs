@@ -1,6 +1,7 @@
 use pest::Parser;
 use pest_derive::Parser;
 use crate::parsers::traits::{DomainParser, DomainResult};
+use crate::parsers::utils::SyntaxError;
 use super::ast::*;
 
 #[derive(Parser)]
@@ -10,6 +11,10 @@ pub struct BooleanPestGrammar;
 pub struct BooleanParser;
 
 impl DomainParser for BooleanParser {
+    fn domain_name(&self) -> &'static str {
+        "Lógica Booleana"
+    }
+
     fn valid_keywords(&self) -> Vec<&'static str> {
         vec!["Boolean"]
     }
@@ -17,19 +22,23 @@ impl DomainParser for BooleanParser {
     fn parse_domain(&self, content: &str) -> DomainResult {
         // Parseamos SOLO la expresión interna del bloque
         let pairs = BooleanPestGrammar::parse(Rule::boolean_block, content)
-            .map_err(|e| format!("Error en lógica booleana: {}", e))?;
+            .map_err(SyntaxError::from_pest)?;
 
         let root_pair = pairs.into_iter().next().unwrap(); // boolean_block
 
         let mut model = BooleanModel {
             root: BoolExpr::Literal(false), // temporal
-            name: None,                     // nombre lo asigna el dispatcher
+            name: None,
         };
 
         // Iteramos sobre los hijos de boolean_block
         for inner_pair in root_pair.into_inner() {
-            if inner_pair.as_rule() == Rule::expression {
-                model.root = build_expression(inner_pair);
+            match inner_pair.as_rule() {
+                Rule::model_id => {
+                    model.name = Some(inner_pair.as_str().trim_matches(|c| c == '"' || c == '\'').to_string());
+                }
+                Rule::expression => model.root = build_expression(inner_pair),
+                _ => {}
             }
         }
 
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Grafo ponderado definido en un bloque `Graph "Nombre" { edges { ... } }`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphModel {
+    pub name: String,
+    pub directed: bool,
+    pub edges: Vec<GraphEdgeDef>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphEdgeDef {
+    pub from: String,
+    pub to: String,
+    pub weight: f64,
+}
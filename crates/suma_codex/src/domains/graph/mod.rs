@@ -0,0 +1,5 @@
+pub mod ast;
+pub mod parser;
+
+pub use ast::GraphModel;
+pub use parser::GraphParser;
@@ -0,0 +1,99 @@
+use pest::Parser;
+use pest_derive::Parser;
+use crate::parsers::traits::{DomainParser, DomainResult};
+use crate::parsers::utils::SyntaxError;
+use super::ast::{GraphEdgeDef, GraphModel};
+
+#[derive(Parser)]
+#[grammar = "domains/graph/grammar.pest"]
+pub struct GraphPestGrammar;
+
+pub struct GraphParser;
+
+impl DomainParser for GraphParser {
+    fn domain_name(&self) -> &'static str {
+        "Grafos"
+    }
+
+    fn valid_keywords(&self) -> Vec<&'static str> {
+        vec!["Graph"]
+    }
+
+    fn parse_domain(&self, content: &str) -> DomainResult {
+        let pairs = GraphPestGrammar::parse(Rule::graph_block, content)
+            .map_err(SyntaxError::from_pest)?;
+
+        let root = pairs.into_iter().next().ok_or("Bloque vacío")?;
+        let definition = root.into_inner().next().unwrap();
+        let model = parse_definition(definition)?;
+        Ok(Box::new(model))
+    }
+}
+
+// --- HELPERS ---
+
+fn parse_definition(pair: pest::iterators::Pair<Rule>) -> Result<GraphModel, String> {
+    let mut inner = pair.into_inner();
+
+    // 1. ID (model_id -> string_lit)
+    let id_pair = inner.next().unwrap();
+    let name = id_pair.into_inner().next().unwrap().as_str().trim_matches('"').to_string();
+
+    // 2. Aristas
+    let mut edges = Vec::new();
+    let mut directed = None;
+    let edges_section = inner.next().unwrap();
+
+    for edge_pair in edges_section.into_inner() {
+        let mut parts = edge_pair.into_inner();
+        let from = parts.next().unwrap().as_str().to_string();
+        let is_directed = parts.next().unwrap().as_str() == "->";
+        let to = parts.next().unwrap().as_str().to_string();
+        let weight = match parts.next() {
+            Some(number) => number.as_str().parse::<f64>()
+                .map_err(|_| format!("Peso inválido: '{}'", number.as_str()))?,
+            None => 1.0,
+        };
+
+        // No se mezclan aristas dirigidas y no dirigidas en un mismo grafo
+        match directed {
+            None => directed = Some(is_directed),
+            Some(d) if d != is_directed => {
+                return Err(format!("El grafo '{}' mezcla aristas dirigidas (->) y no dirigidas (-)", name));
+            }
+            _ => {}
+        }
+
+        edges.push(GraphEdgeDef { from, to, weight });
+    }
+
+    Ok(GraphModel { name, directed: directed.unwrap_or(false), edges })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_graph() {
+        let code = r#"Graph "G" {
+            edges { A-B:3, B-C:2
+                    A-C }
+        }"#;
+
+        let result = GraphParser.parse_domain(code).unwrap();
+        let model = result.downcast_ref::<GraphModel>().unwrap();
+
+        assert_eq!(model.name, "G");
+        assert!(!model.directed);
+        assert_eq!(model.edges.len(), 3);
+        assert_eq!(model.edges[0].weight, 3.0);
+        assert_eq!(model.edges[2].weight, 1.0);
+    }
+
+    #[test]
+    fn test_mixed_edges_rejected() {
+        let code = r#"Graph "G" { edges { A->B:1, B-C:2 } }"#;
+        assert!(GraphParser.parse_domain(code).is_err());
+    }
+}
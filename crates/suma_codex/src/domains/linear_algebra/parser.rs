@@ -2,6 +2,7 @@ use pest::Parser;
 use pest_derive::Parser;
 
 use crate::parsers::traits::{DomainParser, DomainResult};
+use crate::parsers::utils::SyntaxError;
 use super::ast::{
     LinearAlgebraBlock, LinAlgStmt, SystemDef, MatrixData
 };
@@ -13,6 +14,10 @@ pub struct LinearAlgebraPestGrammar;
 pub struct LinearAlgebraParser;
 
 impl DomainParser for LinearAlgebraParser {
+    fn domain_name(&self) -> &'static str {
+        "Álgebra Lineal"
+    }
+
     fn valid_keywords(&self) -> Vec<&'static str> {
         // Solo respondemos a definiciones de sistemas
         vec!["LinearSystem"] 
@@ -20,7 +25,7 @@ impl DomainParser for LinearAlgebraParser {
 
     fn parse_domain(&self, content: &str) -> DomainResult {
         let pairs = LinearAlgebraPestGrammar::parse(Rule::linear_algebra_block, content)
-            .map_err(|e| format!("{}", e))?;
+            .map_err(SyntaxError::from_pest)?;
 
         let mut statements = Vec::new();
 
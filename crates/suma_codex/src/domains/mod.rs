@@ -1,5 +1,7 @@
 pub mod optimization;
 pub mod boolean_algebra;
 pub mod linear_algebra;
+pub mod probability;
+pub mod graph;
 
 pub mod queries;
\ No newline at end of file
@@ -1,6 +1,7 @@
 use pest::Parser;
 use pest_derive::Parser;
 use crate::parsers::traits::{DomainParser, DomainResult};
+use crate::parsers::utils::SyntaxError;
 use super::ast::{
     OptimizationBlock, OptimizationModel, 
     OptimizationDirection, ConstraintModel
@@ -14,6 +15,10 @@ pub struct OptimizationPestGrammar;
 pub struct OptimizationParser;
 
 impl DomainParser for OptimizationParser {
+    fn domain_name(&self) -> &'static str {
+        "Optimización"
+    }
+
     fn valid_keywords(&self) -> Vec<&'static str> {
         // Solo respondemos a la definición del modelo
         vec!["Optimization"] 
@@ -21,7 +26,7 @@ impl DomainParser for OptimizationParser {
 
     fn parse_domain(&self, content: &str) -> DomainResult {
         let pairs = OptimizationPestGrammar::parse(Rule::optimization_block, content)
-            .map_err(|e| format!("{}", e))?;
+            .map_err(SyntaxError::from_pest)?;
 
         if let Some(root) = pairs.clone().next() {
             // La gramática ahora solo debería permitir definiciones aquí
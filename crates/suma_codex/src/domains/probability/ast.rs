@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Red bayesiana definida en un bloque `BayesNet "Nombre" { ... }`.
+/// Los nodos se guardan en el orden en que se declararon.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BayesNetModel {
+    pub name: String,
+    pub nodes: Vec<BayesNodeDef>,
+}
+
+/// Nodo binario: para cada combinación de valores de los padres (en el orden de `parents`),
+/// la probabilidad de que el nodo sea `true`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BayesNodeDef {
+    pub name: String,
+    pub parents: Vec<String>,
+    pub table: Vec<(Vec<bool>, f64)>,
+}
@@ -0,0 +1,119 @@
+use pest::Parser;
+use pest_derive::Parser;
+use crate::parsers::traits::{DomainParser, DomainResult};
+use crate::parsers::utils::SyntaxError;
+use super::ast::{BayesNetModel, BayesNodeDef};
+
+#[derive(Parser)]
+#[grammar = "domains/probability/grammar.pest"]
+pub struct ProbabilityPestGrammar;
+
+pub struct ProbabilityParser;
+
+impl DomainParser for ProbabilityParser {
+    fn domain_name(&self) -> &'static str {
+        "Probabilidad"
+    }
+
+    fn valid_keywords(&self) -> Vec<&'static str> {
+        vec!["BayesNet"]
+    }
+
+    fn parse_domain(&self, content: &str) -> DomainResult {
+        let pairs = ProbabilityPestGrammar::parse(Rule::bayes_block, content)
+            .map_err(SyntaxError::from_pest)?;
+
+        let root = pairs.into_iter().next().ok_or("Bloque vacío")?;
+        let definition = root.into_inner().next().unwrap();
+        let model = parse_definition(definition)?;
+        Ok(Box::new(model))
+    }
+}
+
+// --- HELPERS ---
+
+fn parse_definition(pair: pest::iterators::Pair<Rule>) -> Result<BayesNetModel, String> {
+    let mut inner = pair.into_inner();
+
+    // 1. ID (model_id -> string_lit)
+    let id_pair = inner.next().unwrap();
+    let name = id_pair.into_inner().next().unwrap().as_str().trim_matches('"').to_string();
+
+    // 2. Nodos
+    let mut nodes = Vec::new();
+    for node_pair in inner {
+        if node_pair.as_rule() == Rule::node_def {
+            nodes.push(parse_node(node_pair)?);
+        }
+    }
+
+    Ok(BayesNetModel { name, nodes })
+}
+
+fn parse_node(pair: pest::iterators::Pair<Rule>) -> Result<BayesNodeDef, String> {
+    let mut inner = pair.into_inner();
+    let name = inner.next().unwrap().as_str().to_string();
+
+    let mut parents = Vec::new();
+    let mut table = Vec::new();
+
+    for part in inner {
+        match part.as_rule() {
+            Rule::parents => {
+                parents = part.into_inner().map(|p| p.as_str().to_string()).collect();
+            }
+            // Nodo raíz: "Rain: 0.2"
+            Rule::probability => {
+                table.push((vec![], parse_probability(part.as_str())?));
+            }
+            Rule::table => {
+                for row in part.into_inner() {
+                    let mut values = Vec::new();
+                    let mut probability = 0.0;
+                    for item in row.into_inner() {
+                        match item.as_rule() {
+                            Rule::bool_lit => values.push(item.as_str() == "true"),
+                            Rule::probability => probability = parse_probability(item.as_str())?,
+                            _ => {}
+                        }
+                    }
+                    table.push((values, probability));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(BayesNodeDef { name, parents, table })
+}
+
+fn parse_probability(text: &str) -> Result<f64, String> {
+    let value: f64 = text.parse().map_err(|_| format!("Probabilidad inválida: '{}'", text))?;
+    if !(0.0..=1.0).contains(&value) {
+        return Err(format!("La probabilidad {} está fuera de [0, 1]", value));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bayes_net() {
+        let code = r#"BayesNet "Sprinkler" {
+            Rain: 0.2
+            Sprinkler | Rain: [true: 0.01, false: 0.4]
+            WetGrass | Sprinkler, Rain: [true true: 0.99, true false: 0.9, false true: 0.8, false false: 0.0]
+        }"#;
+
+        let result = ProbabilityParser.parse_domain(code).unwrap();
+        let model = result.downcast_ref::<BayesNetModel>().unwrap();
+
+        assert_eq!(model.name, "Sprinkler");
+        assert_eq!(model.nodes.len(), 3);
+        assert_eq!(model.nodes[0].table, vec![(vec![], 0.2)]);
+        assert_eq!(model.nodes[2].parents, vec!["Sprinkler", "Rain"]);
+        assert_eq!(model.nodes[2].table[1], (vec![true, false], 0.9));
+    }
+}
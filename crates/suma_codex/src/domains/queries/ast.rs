@@ -9,5 +9,6 @@ pub struct QueryBlock {
 #[derive(Debug, Clone, Serialize)]
 pub struct QueryCommand {
     pub action: String,         // Ej: "solve", "determinant"
+    pub args: Option<String>,   // Ej: Some("WetGrass | Rain=true") en "P(WetGrass | Rain=true)"
     pub alias: Option<String>,  // Ej: Some("det_A")
 }
\ No newline at end of file
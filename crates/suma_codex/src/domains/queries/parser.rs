@@ -1,6 +1,7 @@
 use pest::Parser;
 use pest_derive::Parser;
 use crate::parsers::traits::{DomainParser, DomainResult};
+use crate::parsers::utils::SyntaxError;
 use super::ast::{QueryBlock, QueryCommand};
 
 #[derive(Parser)]
@@ -10,13 +11,17 @@ pub struct QueryPestGrammar;
 pub struct QueryParser;
 
 impl DomainParser for QueryParser {
+    fn domain_name(&self) -> &'static str {
+        "Consultas"
+    }
+
     fn valid_keywords(&self) -> Vec<&'static str> {
         vec!["query"] // ESTE será el único dueño de la palabra "query"
     }
 
     fn parse_domain(&self, content: &str) -> DomainResult {
         let pairs = QueryPestGrammar::parse(Rule::query_block, content)
-            .map_err(|e| format!("{}", e))?;
+            .map_err(SyntaxError::from_pest)?;
 
         if let Some(root) = pairs.clone().next() {
             let mut inner = root.into_inner();
@@ -33,17 +38,23 @@ impl DomainParser for QueryParser {
                 // Acción
                 let action = cmd_inner.next().unwrap().as_str().to_string();
                 
-                // Alias (Opcional)
-                let alias = if let Some(alias_pair) = cmd_inner.next() {
-                    // alias_pair es "as X", queremos el X (segundo token dentro)
-                    // Ojo con la gramática: alias_clause = { "as" ~ identifier }
-                    // inner de alias_clause tiene el identifier.
-                    Some(alias_pair.into_inner().next().unwrap().as_str().to_string())
-                } else {
-                    None
-                };
-
-                commands.push(QueryCommand { action, alias });
+                // Argumentos y alias (ambos opcionales, en ese orden)
+                let mut args = None;
+                let mut alias = None;
+                for extra in cmd_inner {
+                    match extra.as_rule() {
+                        Rule::arguments => {
+                            args = Some(extra.into_inner().next().unwrap().as_str().trim().to_string());
+                        }
+                        // alias_clause = { "as" ~ identifier }: nos quedamos con el identifier
+                        Rule::alias_clause => {
+                            alias = Some(extra.into_inner().next().unwrap().as_str().to_string());
+                        }
+                        _ => {}
+                    }
+                }
+
+                commands.push(QueryCommand { action, args, alias });
             }
 
             Ok(Box::new(QueryBlock { target_id, commands }))
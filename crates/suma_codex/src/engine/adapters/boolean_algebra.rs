@@ -0,0 +1,150 @@
+use anyhow::{anyhow, bail, Result};
+use std::collections::HashMap;
+
+// Core Imports
+use suma_core::boolean_algebra::ast::Node;
+use suma_core::boolean_algebra::BooleanExpr;
+
+// Domain Imports
+use crate::domains::boolean_algebra::ast::{BoolExpr, BoolOp};
+use crate::domains::boolean_algebra::BooleanModel;
+use crate::domains::queries::ast::QueryBlock;
+use crate::outputs::CodexOutput;
+
+pub struct BooleanExecutor {
+    verbose: bool,
+    // Memoria persistente: expresiones definidas, por nombre
+    expressions: HashMap<String, BooleanExpr>,
+    // Definiciones originales, para guardar el estado en disco
+    models: HashMap<String, BooleanModel>,
+}
+
+impl BooleanExecutor {
+    pub fn new(verbose: bool) -> Self {
+        Self {
+            verbose,
+            expressions: HashMap::new(),
+            models: HashMap::new(),
+        }
+    }
+
+    // --- Lógica de Definición (Convertir y guardar la expresión) ---
+    pub fn execute<F>(&mut self, model: &BooleanModel, observer: &mut F) -> Result<()>
+    where F: FnMut(&str, CodexOutput)
+    {
+        let Some(name) = &model.name else {
+            bail!("El bloque Boolean necesita un nombre para poder consultarlo, ej: Boolean \"F\" {{ A and B }}");
+        };
+        if self.verbose { println!(">> BooleanAdapter: Storing expression '{}'", name); }
+
+        self.store(name, model);
+
+        if self.verbose {
+            observer("System", CodexOutput::Message(format!("Expresión booleana '{}' registrada.", name)));
+        }
+        Ok(())
+    }
+
+    /// Identificadores de los modelos definidos en este dominio.
+    pub fn known_ids(&self) -> Vec<String> {
+        self.expressions.keys().cloned().collect()
+    }
+
+    // --- Persistencia ---
+
+    pub fn export_state(&self) -> HashMap<String, BooleanModel> {
+        self.models.clone()
+    }
+
+    pub fn import_state(&mut self, models: HashMap<String, BooleanModel>) {
+        for (name, model) in &models {
+            self.store(name, model);
+        }
+    }
+
+    fn store(&mut self, name: &str, model: &BooleanModel) {
+        let ast = to_core_node(&model.root);
+        let variables = ast.extract_variables();
+        self.expressions.insert(name.to_string(), BooleanExpr { ast, variables });
+        self.models.insert(name.to_string(), model.clone());
+    }
+
+    // --- IMPLEMENTACIÓN DEL POLIMORFISMO (Query Genérica) ---
+    pub fn try_execute_query<F>(&mut self, query: &QueryBlock, observer: &mut F) -> bool
+    where F: FnMut(&str, CodexOutput)
+    {
+        let Some(expr) = self.expressions.get(&query.target_id) else {
+            return false;
+        };
+
+        if self.verbose { println!(">> BooleanAdapter: Generic Query for '{}'", query.target_id); }
+
+        for cmd in &query.commands {
+            let label = cmd.alias.as_deref().unwrap_or(&cmd.action);
+
+            let result = match cmd.action.as_str() {
+                "truth_table" => Ok(truth_table(expr)),
+                "tautology" => Ok(CodexOutput::Message(expr.is_tautology().to_string())),
+                "contradiction" => Ok(CodexOutput::Message(expr.is_contradiction().to_string())),
+                "simplify" => Ok(CodexOutput::Message(expr.simplify().to_string())),
+                // evaluate(A=true, B=false) o evaluate {A=1, B=0}
+                "evaluate" => cmd.args.as_deref()
+                    .ok_or_else(|| anyhow!("'evaluate' necesita valores, ej: evaluate {{A=true, B=false}}"))
+                    .and_then(|args| evaluate(expr, args)),
+                _ => {
+                    observer("Warning", CodexOutput::Error(format!("Comando '{}' no soportado por Boolean", cmd.action)));
+                    continue;
+                }
+            };
+
+            match result {
+                Ok(output) => observer(label, output),
+                Err(e) => observer(label, CodexOutput::Error(format!("{}", e))),
+            }
+        }
+
+        true
+    }
+}
+
+// --- Helpers ---
+
+/// Traduce el AST del Codex al `Node` del Core.
+fn to_core_node(expr: &BoolExpr) -> Node {
+    match expr {
+        BoolExpr::Literal(value) => Node::constant(*value),
+        BoolExpr::Variable(name) => Node::var(name),
+        BoolExpr::Not(inner) => Node::not(to_core_node(inner)),
+        BoolExpr::BinaryOp { op: BoolOp::And, lhs, rhs } => Node::and(to_core_node(lhs), to_core_node(rhs)),
+        BoolExpr::BinaryOp { op: BoolOp::Or, lhs, rhs } => Node::or(to_core_node(lhs), to_core_node(rhs)),
+    }
+}
+
+/// Tabla de verdad con una columna por variable y la del resultado, en 1/0.
+fn truth_table(expr: &BooleanExpr) -> CodexOutput {
+    let table = expr.truth_table();
+    let bit = |value: bool| if value { "1" } else { "0" }.to_string();
+
+    let rows = (0..table.num_rows())
+        .map(|row| table.column_order.iter().map(|column| bit(table.columns[column][row])).collect())
+        .collect();
+
+    CodexOutput::Table { headers: table.column_order.clone(), rows }
+}
+
+fn evaluate(expr: &BooleanExpr, args: &str) -> Result<CodexOutput> {
+    let mut values = HashMap::new();
+    for assignment in args.split(',').map(str::trim).filter(|a| !a.is_empty()) {
+        let (var, value) = assignment.split_once('=')
+            .ok_or_else(|| anyhow!("Asignación inválida '{}', se esperaba 'A=true'", assignment))?;
+        let value = match value.trim().to_ascii_lowercase().as_str() {
+            "true" | "1" => true,
+            "false" | "0" => false,
+            other => bail!("Valor inválido '{}' para '{}', se esperaba true/false o 1/0", other, var.trim()),
+        };
+        values.insert(var.trim(), value);
+    }
+
+    let result = expr.evaluate(&values).map_err(|e| anyhow!("{}", e))?;
+    Ok(CodexOutput::Message(result.to_string()))
+}
@@ -0,0 +1,200 @@
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+// Core Imports
+use suma_core::data_structures::graphs::{
+    bellman_ford, dijkstra_path, minimum_spanning_tree, DirectedWeightedGraph, UndirectedWeightedGraph,
+    weighted::Weight, WeightedGraph,
+};
+
+// Domain Imports
+use crate::domains::graph::ast::GraphModel;
+use crate::domains::queries::ast::QueryBlock;
+use crate::outputs::CodexOutput;
+
+/// Grafo ya construido en el Core, con el mapa nombre -> id de sus nodos.
+enum StoredGraph {
+    Undirected(UndirectedWeightedGraph<String>, HashMap<String, usize>),
+    Directed(DirectedWeightedGraph<String>, HashMap<String, usize>),
+}
+
+impl StoredGraph {
+    fn ids(&self) -> &HashMap<String, usize> {
+        match self {
+            StoredGraph::Undirected(_, ids) | StoredGraph::Directed(_, ids) => ids,
+        }
+    }
+
+    fn name_of(&self, id: usize) -> &str {
+        self.ids().iter()
+            .find(|(_, node)| **node == id)
+            .map(|(name, _)| name.as_str())
+            .unwrap_or("?")
+    }
+}
+
+pub struct GraphExecutor {
+    verbose: bool,
+    // Memoria persistente: grafos definidos, por nombre
+    graphs: HashMap<String, StoredGraph>,
+    // Definiciones originales, para guardar el estado en disco
+    models: HashMap<String, GraphModel>,
+}
+
+impl GraphExecutor {
+    pub fn new(verbose: bool) -> Self {
+        Self {
+            verbose,
+            graphs: HashMap::new(),
+            models: HashMap::new(),
+        }
+    }
+
+    // --- Lógica de Definición (Construir y guardar el grafo) ---
+    pub fn execute<F>(&mut self, model: &GraphModel, observer: &mut F) -> Result<()>
+    where F: FnMut(&str, CodexOutput)
+    {
+        if self.verbose { println!(">> GraphAdapter: Storing graph '{}'", model.name); }
+
+        self.store(model);
+
+        if self.verbose {
+            observer("System", CodexOutput::Message(format!("Grafo '{}' registrado.", model.name)));
+        }
+        Ok(())
+    }
+
+    /// Identificadores de los modelos definidos en este dominio.
+    pub fn known_ids(&self) -> Vec<String> {
+        self.graphs.keys().cloned().collect()
+    }
+
+    // --- Persistencia ---
+
+    pub fn export_state(&self) -> HashMap<String, GraphModel> {
+        self.models.clone()
+    }
+
+    pub fn import_state(&mut self, models: HashMap<String, GraphModel>) {
+        for model in models.values() {
+            self.store(model);
+        }
+    }
+
+    fn store(&mut self, model: &GraphModel) {
+        let edges: Vec<(String, String, f64)> = model.edges.iter()
+            .map(|e| (e.from.clone(), e.to.clone(), e.weight))
+            .collect();
+
+        let stored = if model.directed {
+            let (graph, ids) = DirectedWeightedGraph::<String>::from_edges(&edges);
+            StoredGraph::Directed(graph, ids)
+        } else {
+            let (graph, ids) = UndirectedWeightedGraph::<String>::from_edges(&edges);
+            StoredGraph::Undirected(graph, ids)
+        };
+        self.graphs.insert(model.name.clone(), stored);
+        self.models.insert(model.name.clone(), model.clone());
+    }
+
+    // --- IMPLEMENTACIÓN DEL POLIMORFISMO (Query Genérica) ---
+    pub fn try_execute_query<F>(&mut self, query: &QueryBlock, observer: &mut F) -> bool
+    where F: FnMut(&str, CodexOutput)
+    {
+        let Some(stored) = self.graphs.get(&query.target_id) else {
+            return false;
+        };
+        // Dijkstra da distancias incorrectas con pesos negativos: esos grafos van por Bellman-Ford
+        let negative_weights = self.models[&query.target_id].edges.iter().any(|e| e.weight < 0.0);
+
+        if self.verbose { println!(">> GraphAdapter: Generic Query for '{}'", query.target_id); }
+
+        for cmd in &query.commands {
+            let label = cmd.alias.as_deref().unwrap_or(&cmd.action);
+
+            let result = match cmd.action.as_str() {
+                // shortest_path A to C, shortest_path(A to C) o shortest_path(A, C)
+                "shortest_path" | "path" => cmd.args.as_deref()
+                    .ok_or_else(|| anyhow!("'{}' necesita origen y destino, ej: shortest_path A to C", cmd.action))
+                    .and_then(|args| shortest_path(stored, args, negative_weights)),
+                "mst" => minimum_tree(stored),
+                _ => {
+                    observer("Warning", CodexOutput::Error(format!("Comando '{}' no soportado por Graph", cmd.action)));
+                    continue;
+                }
+            };
+
+            match result {
+                Ok(text) => observer(label, CodexOutput::Message(text)),
+                Err(e) => observer(label, CodexOutput::Error(format!("{}", e))),
+            }
+        }
+
+        true
+    }
+}
+
+// --- Helpers ---
+
+fn shortest_path(stored: &StoredGraph, args: &str, negative_weights: bool) -> Result<String> {
+    let (from, to) = args.split_once(" to ")
+        .or_else(|| args.split_once(','))
+        .ok_or_else(|| anyhow!("Argumentos inválidos '{}', se esperaba 'A to B'", args))?;
+    let (from, to) = (from.trim(), to.trim());
+
+    let ids = stored.ids();
+    let start = *ids.get(from).ok_or_else(|| anyhow!("Nodo desconocido: '{}'", from))?;
+    let goal = *ids.get(to).ok_or_else(|| anyhow!("Nodo desconocido: '{}'", to))?;
+
+    let found = match stored {
+        StoredGraph::Undirected(graph, _) if negative_weights => bellman_ford_path(stored, graph, start, goal)?,
+        StoredGraph::Directed(graph, _) if negative_weights => bellman_ford_path(stored, graph, start, goal)?,
+        StoredGraph::Undirected(graph, _) => dijkstra_path(graph, start, goal),
+        StoredGraph::Directed(graph, _) => dijkstra_path(graph, start, goal),
+    };
+
+    let (path, cost) = found.ok_or_else(|| anyhow!("No hay camino de '{}' a '{}'", from, to))?;
+    let names: Vec<&str> = path.iter().map(|&id| stored.name_of(id)).collect();
+    Ok(format!("{} (costo = {})", names.join(" -> "), cost))
+}
+
+/// Igual que `dijkstra_path`, pero admite pesos negativos. Un ciclo negativo
+/// (en un grafo no dirigido basta una arista negativa) deja el camino sin definir.
+fn bellman_ford_path<G>(stored: &StoredGraph, graph: &G, start: usize, goal: usize) -> Result<Option<(Vec<usize>, G::EdgeData)>>
+where
+    G: WeightedGraph<NodeId = usize>,
+    G::EdgeData: Weight,
+{
+    let (distances, previous) = bellman_ford(graph, start).map_err(|negative| {
+        let names: Vec<&str> = negative.cycle.iter().map(|&id| stored.name_of(id)).collect();
+        anyhow!("Ciclo de peso negativo ({}): el camino mínimo no está definido", names.join(" -> "))
+    })?;
+
+    let cost = distances[&goal];
+    if cost == G::EdgeData::inf() {
+        return Ok(None);
+    }
+    let mut path = vec![goal];
+    while let Some(&prev) = previous.get(path.last().unwrap()) {
+        path.push(prev);
+    }
+    path.reverse();
+    Ok(Some((path, cost)))
+}
+
+fn minimum_tree(stored: &StoredGraph) -> Result<String> {
+    let StoredGraph::Undirected(graph, _) = stored else {
+        return Err(anyhow!("'mst' solo está definido para grafos no dirigidos"));
+    };
+
+    let tree = minimum_spanning_tree(graph);
+    let total: f64 = tree.iter().map(|(_, _, w)| w.into_inner()).sum();
+
+    let mut out = String::new();
+    for (a, b, w) in &tree {
+        writeln!(out, "  {} - {}: {}", stored.name_of(*a), stored.name_of(*b), w)?;
+    }
+    write!(out, "  Total = {}", total)?;
+    Ok(out)
+}
@@ -96,6 +96,21 @@ impl LinearAlgebraExecutor {
 
         true // Retornamos true porque SÍ manejamos el ID
     }
+    /// Identificadores de los modelos definidos en este dominio.
+    pub fn known_ids(&self) -> Vec<String> {
+        self.artifacts.keys().cloned().collect()
+    }
+
+    // --- Persistencia ---
+
+    pub fn export_state(&self) -> HashMap<String, SystemDef> {
+        self.artifacts.clone()
+    }
+
+    pub fn import_state(&mut self, systems: HashMap<String, SystemDef>) {
+        self.artifacts.extend(systems);
+    }
+
     // --- Lógica Interna ---
 
     fn register_system(&mut self, def: &SystemDef) -> Result<(), String> {
@@ -1,3 +1,5 @@
-mod boolean_algebra;
+pub mod boolean_algebra;
 pub mod linear_algebra;
-pub mod optimization;
\ No newline at end of file
+pub mod optimization;
+pub mod probability;
+pub mod graph;
\ No newline at end of file
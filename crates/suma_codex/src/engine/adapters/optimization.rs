@@ -63,7 +63,7 @@ impl OptimizationExecutor {
                             // Formato Compacto: "Optimal (Z = 550.0000)"
                             writeln!(out, "{:?} (Z = {:.4})", solution.status, solution.objective_value).unwrap();
                             
-                            for (k, v) in &solution.variables {
+                            for (k, v) in solution.sorted_variables() {
                                 // Opcional: mostrar solo si > 0.0001 si quieres limpiar más
                                 writeln!(out, "  {} = {:.4}", k, v).unwrap();
                             }
@@ -77,7 +77,7 @@ impl OptimizationExecutor {
                         Ok(solution) => {
                             let mut out = String::new();
                             // Solo listamos valores, sin encabezado gigante
-                            for (k, v) in &solution.shadow_prices {
+                            for (k, v) in solution.sorted_shadow_prices() {
                                 writeln!(out, "  {}: {:.4}", k, v).unwrap();
                             }
                             observer("Shadow Prices", CodexOutput::Message(out));
@@ -100,6 +100,21 @@ impl OptimizationExecutor {
         true
     }
 
+    /// Identificadores de los modelos definidos en este dominio.
+    pub fn known_ids(&self) -> Vec<String> {
+        self.models.keys().cloned().collect()
+    }
+
+    // --- Persistencia ---
+
+    pub fn export_state(&self) -> HashMap<String, LinearProblem> {
+        self.models.clone()
+    }
+
+    pub fn import_state(&mut self, models: HashMap<String, LinearProblem>) {
+        self.models.extend(models);
+    }
+
     // --- Lógica de Definición (Guardar modelo) ---
     fn handle_definition<F>(&mut self, model: &OptimizationModel, observer: &mut F) -> Result<()>
     where F: FnMut(&str, CodexOutput) 
@@ -165,7 +180,7 @@ impl OptimizationExecutor {
                     
                     let mut out = String::new();
                     writeln!(out, "{:?} (Z = {:.4})", solution.status, solution.objective_value)?;
-                    for (k, v) in &solution.variables {
+                    for (k, v) in solution.sorted_variables() {
                         writeln!(out, "  {} = {:.4}", k, v)?;
                     }
                     observer("Result", CodexOutput::Message(out));
@@ -173,7 +188,7 @@ impl OptimizationExecutor {
                 OptimizationRequest::ShadowPrices => {
                     let solution = solve_primal(problem).map_err(|e| anyhow!("{}", e))?;
                     let mut out = String::new();
-                    for (k, v) in &solution.shadow_prices {
+                    for (k, v) in solution.sorted_shadow_prices() {
                         writeln!(out, "  {}: {:.4}", k, v)?;
                     }
                     observer("Shadow Prices", CodexOutput::Message(out));
@@ -238,6 +253,9 @@ impl OptimizationExecutor {
                     return Err(anyhow!("División por variable no soportada"));
                 }
             },
+            Expr::Pow(..) | Expr::Func(..) => {
+                return Err(anyhow!("No linealidad: {}", expr));
+            },
         }
         Ok(lin)
     }
@@ -250,4 +268,4 @@ impl OptimizationExecutor {
             dest.add_term(var, current + (coeff * scale));
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,212 @@
+use anyhow::{anyhow, bail, Result};
+use std::collections::HashMap;
+
+// Core Imports
+use suma_core::probability::bayes::BayesianNetwork;
+use suma_core::probability::bayes::BN_base::{BayesianNetworkBase, State};
+
+// Domain Imports
+use crate::domains::probability::ast::{BayesNetModel, BayesNodeDef};
+use crate::domains::queries::ast::QueryBlock;
+use crate::outputs::CodexOutput;
+
+/// Filas `(valores de los padres, P(nodo = true))`, como las recibe `add_binary_node`.
+type BinaryTable = Vec<(Vec<bool>, f64)>;
+
+/// Muestras usadas por likelihood weighting en cada consulta.
+const INFERENCE_SAMPLES: usize = 20_000;
+
+pub struct ProbabilityExecutor {
+    verbose: bool,
+    // Memoria persistente: redes definidas, por nombre
+    networks: HashMap<String, BayesianNetwork>,
+    // Definiciones originales (la red del Core no es serializable, se reconstruye al cargar)
+    models: HashMap<String, BayesNetModel>,
+}
+
+impl ProbabilityExecutor {
+    pub fn new(verbose: bool) -> Self {
+        Self {
+            verbose,
+            networks: HashMap::new(),
+            models: HashMap::new(),
+        }
+    }
+
+    // --- Lógica de Definición (Construir y guardar la red) ---
+    pub fn execute<F>(&mut self, model: &BayesNetModel, observer: &mut F) -> Result<()>
+    where F: FnMut(&str, CodexOutput)
+    {
+        if self.verbose { println!(">> ProbAdapter: Storing network '{}'", model.name); }
+
+        let network = build_network(model)?;
+        self.networks.insert(model.name.clone(), network);
+        self.models.insert(model.name.clone(), model.clone());
+
+        if self.verbose {
+            observer("System", CodexOutput::Message(format!("Red bayesiana '{}' registrada.", model.name)));
+        }
+        Ok(())
+    }
+
+    /// Identificadores de los modelos definidos en este dominio.
+    pub fn known_ids(&self) -> Vec<String> {
+        self.networks.keys().cloned().collect()
+    }
+
+    // --- Persistencia ---
+
+    pub fn export_state(&self) -> HashMap<String, BayesNetModel> {
+        self.models.clone()
+    }
+
+    pub fn import_state(&mut self, models: HashMap<String, BayesNetModel>) -> Result<()> {
+        for (name, model) in models {
+            let network = build_network(&model)?;
+            self.networks.insert(name.clone(), network);
+            self.models.insert(name, model);
+        }
+        Ok(())
+    }
+
+    // --- IMPLEMENTACIÓN DEL POLIMORFISMO (Query Genérica) ---
+    pub fn try_execute_query<F>(&mut self, query: &QueryBlock, observer: &mut F) -> bool
+    where F: FnMut(&str, CodexOutput)
+    {
+        let Some(network) = self.networks.get(&query.target_id) else {
+            return false;
+        };
+
+        if self.verbose { println!(">> ProbAdapter: Generic Query for '{}'", query.target_id); }
+
+        for cmd in &query.commands {
+            let default_label = match &cmd.args {
+                Some(args) => format!("{}({})", cmd.action, args),
+                None => cmd.action.clone(),
+            };
+            let label = cmd.alias.as_deref().unwrap_or(&default_label);
+
+            match cmd.action.as_str() {
+                // P(X | A=true, B=false): distribución marginal de X dada la evidencia
+                "P" | "probability" | "marginal" => {
+                    let result = cmd.args.as_deref()
+                        .ok_or_else(|| anyhow!("'{}' necesita argumentos, ej: P(X | Y=true)", cmd.action))
+                        .and_then(|args| parse_probability_query(network, args));
+
+                    match result {
+                        Ok((target, evidence)) => {
+                            let distribution = network.likelihood_weighting_sampling(&evidence, target, INFERENCE_SAMPLES);
+                            observer(label, CodexOutput::Distribution(sorted_distribution(distribution)));
+                        }
+                        Err(e) => observer(label, CodexOutput::Error(format!("{}", e))),
+                    }
+                }
+                "nodes" => {
+                    let mut names: Vec<String> = network.get_nodes().into_iter()
+                        .filter_map(|id| network.get_name_from_id(id).cloned())
+                        .collect();
+                    names.sort();
+                    observer(label, CodexOutput::Message(names.join(", ")));
+                }
+                _ => {
+                    observer("Warning", CodexOutput::Error(format!("Comando '{}' no soportado por Probability", cmd.action)));
+                }
+            }
+        }
+
+        true
+    }
+}
+
+// --- Helpers ---
+
+/// Construye la red en el orden de declaración. Los padres deben declararse antes que sus hijos.
+fn build_network(model: &BayesNetModel) -> Result<BayesianNetwork> {
+    let mut network = BayesianNetwork::new();
+
+    for node in &model.nodes {
+        let (parents, table) = normalize_table(&network, node)?;
+        network
+            .add_binary_node(&node.name, parents.iter().map(|p| p.as_str()).collect(), table)
+            .map_err(|e| anyhow!("Nodo '{}': {}", node.name, e))?;
+    }
+
+    Ok(network)
+}
+
+/// Valida la tabla del nodo y la reordena según el id de los padres, que es el orden en que
+/// `BayesianNetwork::get_parent_values` entrega sus valores.
+fn normalize_table(network: &BayesianNetwork, node: &BayesNodeDef) -> Result<(Vec<String>, BinaryTable)> {
+    let mut parent_ids = Vec::new();
+    for parent in &node.parents {
+        let id = network.get_id_from_name(parent)
+            .ok_or_else(|| anyhow!("Nodo '{}': el padre '{}' no está definido (decláralo antes)", node.name, parent))?;
+        parent_ids.push(id);
+    }
+
+    let expected_rows = 1usize << node.parents.len();
+    if node.table.len() != expected_rows {
+        bail!("Nodo '{}': se esperaban {} filas en la tabla, hay {}", node.name, expected_rows, node.table.len());
+    }
+    if let Some((values, _)) = node.table.iter().find(|(values, _)| values.len() != node.parents.len()) {
+        bail!("Nodo '{}': la fila {:?} no tiene un valor por padre", node.name, values);
+    }
+
+    // Permutación: posiciones de los padres ordenadas por id
+    let mut order: Vec<usize> = (0..parent_ids.len()).collect();
+    order.sort_by_key(|&i| parent_ids[i]);
+
+    let parents = order.iter().map(|&i| node.parents[i].clone()).collect();
+    let mut table: BinaryTable = Vec::new();
+    for (values, probability) in &node.table {
+        let reordered: Vec<bool> = order.iter().map(|&i| values[i]).collect();
+        if table.iter().any(|(existing, _)| *existing == reordered) {
+            bail!("Nodo '{}': la combinación {:?} está repetida", node.name, values);
+        }
+        table.push((reordered, *probability));
+    }
+
+    Ok((parents, table))
+}
+
+/// Interpreta `X` o `X | A=true, B=false`.
+fn parse_probability_query(network: &BayesianNetwork, args: &str) -> Result<(usize, HashMap<usize, State>)> {
+    let (target, given) = match args.split_once('|') {
+        Some((target, given)) => (target.trim(), given.trim()),
+        None => (args.trim(), ""),
+    };
+
+    let target_id = network.get_id_from_name(target)
+        .ok_or_else(|| anyhow!("Variable desconocida: '{}'", target))?;
+
+    let mut evidence = HashMap::new();
+    for assignment in given.split(',').map(str::trim).filter(|a| !a.is_empty()) {
+        let (name, value) = assignment.split_once('=')
+            .ok_or_else(|| anyhow!("Evidencia inválida '{}', se esperaba Nombre=valor", assignment))?;
+        let id = network.get_id_from_name(name.trim())
+            .ok_or_else(|| anyhow!("Variable desconocida: '{}'", name.trim()))?;
+        evidence.insert(id, State::from_str(value.trim()));
+    }
+
+    Ok((target_id, evidence))
+}
+
+/// `true` primero, `false` después y el resto en orden alfabético.
+fn sorted_distribution(distribution: HashMap<State, f64>) -> Vec<(String, f64)> {
+    let mut entries: Vec<(State, f64)> = distribution.into_iter().collect();
+    entries.sort_by_key(|(state, _)| match state {
+        State::True => (0, String::new()),
+        State::False => (1, String::new()),
+        State::Value(v) => (2, v.clone()),
+    });
+    entries.into_iter()
+        .map(|(state, p)| {
+            let name = match state {
+                State::True => "true".to_string(),
+                State::False => "false".to_string(),
+                State::Value(v) => v,
+            };
+            (name, p)
+        })
+        .collect()
+}
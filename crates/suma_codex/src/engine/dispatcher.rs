@@ -4,6 +4,7 @@ use std::any::Any;
 
 use crate::parsers::traits::DomainParser;
 use crate::parsers::codex_parser::{CodexParser, Rule};
+use crate::parsers::utils::SyntaxError;
 use crate::ast::CodexResult;
 use pest::Parser;
 
@@ -12,6 +13,8 @@ use crate::domains::queries::ast::QueryBlock;
 use crate::domains::boolean_algebra::BooleanModel;
 use crate::domains::optimization::ast::OptimizationBlock;
 use crate::domains::linear_algebra::ast::LinearAlgebraBlock;
+use crate::domains::probability::ast::BayesNetModel;
+use crate::domains::graph::ast::GraphModel;
 
 pub struct CodexEngine {
     parsers: Vec<Box<dyn DomainParser>>,
@@ -35,14 +38,23 @@ impl CodexEngine {
         self.parsers.push(Box::new(parser));
     }
 
+    /// Dominios registrados, en orden de registro, con sus palabras clave.
+    pub fn domains(&self) -> Vec<(&'static str, Vec<&'static str>)> {
+        self.parsers
+            .iter()
+            .map(|p| (p.domain_name(), p.valid_keywords()))
+            .collect()
+    }
+
     pub fn process_file(&self, content: &str) -> Vec<CodexResult> {
         let mut results = Vec::new();
 
         let pairs = match CodexParser::parse(Rule::program, content) {
             Ok(p) => p,
             Err(e) => {
-                eprintln!("Error crítico de sintaxis global: {}", e);
-                return vec![];
+                let error = SyntaxError::from_pest(e);
+                let message = format!("Error crítico de sintaxis global: {}", error.message);
+                return vec![CodexResult::ParseError(SyntaxError { message, ..error })];
             }
         };
 
@@ -59,6 +71,8 @@ impl CodexEngine {
     fn handle_domain_block(&self, pair: pest::iterators::Pair<Rule>, results: &mut Vec<CodexResult>) {
         // 1. CAPTURAR EL TEXTO COMPLETO
         let full_text = pair.as_str(); 
+        // Posición del bloque en el archivo: los errores del dominio son relativos a ella
+        let (line, col) = pair.line_col();
         
         // 2. IDENTIFICAR LA KEYWORD PARA EL RUTEO
         // Si viene 'query "X" {...}', la keyword es "query".
@@ -74,10 +88,19 @@ impl CodexEngine {
                 Ok(any_ast) => {
                     self.convert_and_store(any_ast, results)
                 }
-                Err(e) => println!("Error en bloque '{}': {}", keyword, e),
+                Err(e) => {
+                    let error = match e.downcast::<SyntaxError>() {
+                        Ok(syntax) => syntax.offset(line, col),
+                        // Errores semánticos sin posición propia: se ubican al inicio del bloque
+                        Err(other) => SyntaxError::new(other.to_string(), line, col),
+                    };
+                    let message = format!("Error en bloque '{}': {}", keyword, error.message);
+                    results.push(CodexResult::ParseError(SyntaxError { message, ..error }));
+                }
             }
         } else {
-            eprintln!("Advertencia: Palabra clave desconocida '{}'. ¿Olvidaste registrar el dominio?", keyword);
+            let message = format!("Palabra clave desconocida '{}'. ¿Olvidaste registrar el dominio?", keyword);
+            results.push(CodexResult::ParseError(SyntaxError::new(message, line, col)));
         }
     }
 
@@ -92,6 +115,12 @@ impl CodexEngine {
         else if let Some(block) = any_ast.downcast_ref::<LinearAlgebraBlock>() {
             results.push(CodexResult::LinearAlgebra(block.clone()));
         }
+        else if let Some(model) = any_ast.downcast_ref::<BayesNetModel>() {
+            results.push(CodexResult::Probability(model.clone()));
+        }
+        else if let Some(model) = any_ast.downcast_ref::<GraphModel>() {
+            results.push(CodexResult::Graph(model.clone()));
+        }
         else if let Some(block) = any_ast.downcast_ref::<QueryBlock>() {
             results.push(CodexResult::Query(block.clone()));
         }
@@ -5,31 +5,64 @@ use crate::outputs::CodexOutput;
 // Asegúrate de que estos módulos sean pub en 'src/engine/adapters/mod.rs'
 use crate::engine::adapters::linear_algebra::LinearAlgebraExecutor;
 use crate::engine::adapters::optimization::OptimizationExecutor;
+use crate::engine::adapters::probability::ProbabilityExecutor;
+use crate::engine::adapters::graph::GraphExecutor;
+use crate::engine::adapters::boolean_algebra::BooleanExecutor;
+use crate::engine::state::CodexState;
 
-pub struct CodexExecutor;
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// Orquestador de ejecución. Guarda los adaptadores (con su memoria de modelos)
+/// para que varias ejecuciones compartan las definiciones, y puede volcar o
+/// recuperar ese estado de disco con `save_state` / `load_state`.
+pub struct CodexExecutor {
+    verbose: bool,
+    lin_alg: LinearAlgebraExecutor,
+    opt: OptimizationExecutor,
+    prob: ProbabilityExecutor,
+    graph: GraphExecutor,
+    bool_exec: BooleanExecutor,
+}
 
 impl CodexExecutor {
+    pub fn new(verbose: bool) -> Self {
+        Self {
+            verbose,
+            lin_alg: LinearAlgebraExecutor::new(verbose),
+            opt: OptimizationExecutor::new(verbose),
+            prob: ProbabilityExecutor::new(verbose),
+            graph: GraphExecutor::new(verbose),
+            bool_exec: BooleanExecutor::new(verbose),
+        }
+    }
+
+    /// Ejecuta una lista de resultados con un executor nuevo (sin estado previo).
+    pub fn execute<F>(results: Vec<CodexResult>, verbose: bool, observer: F)
+    where F: FnMut(&str, CodexOutput)
+    {
+        Self::new(verbose).run(results, observer);
+    }
+
     /// Ejecuta una lista de resultados (Bloques parseados).
     /// 
     /// # Arquitectura
-    /// Instancia los adaptadores con memoria (Stateful) antes del bucle.
+    /// Los adaptadores con memoria (Stateful) viven en el executor, así que una
+    /// definición en el paso 1 es recordada en el paso 5 (y en ejecuciones siguientes).
     /// Itera sobre los resultados y despacha según el tipo.
     /// Para las Queries, utiliza un patrón de "Cadena de Responsabilidad".
-    pub fn execute<F>(results: Vec<CodexResult>, verbose: bool, mut observer: F) 
+    pub fn run<F>(&mut self, results: Vec<CodexResult>, mut observer: F)
     where F: FnMut(&str, CodexOutput) 
     {
+        let verbose = self.verbose;
         if verbose {
             println!(">> Executor: Orchestrating {} blocks...", results.len());
         }
 
-        // --- 1. PERSISTENCIA DE ESTADO ---
-        // Instanciamos los adaptadores FUERA del loop.
-        // Esto permite que una definición en el paso 1 sea recordada en el paso 5.
-        let mut lin_alg = LinearAlgebraExecutor::new(verbose);
-        let mut opt = OptimizationExecutor::new(verbose);
-        // let mut bool_exec = BooleanExecutor::new(verbose); 
+        let Self { lin_alg, opt, prob, graph, bool_exec, .. } = self;
 
-        // --- 2. BUCLE DE EJECUCIÓN ---
+        // --- BUCLE DE EJECUCIÓN ---
         for (_i, result) in results.iter().enumerate() {
             if verbose {
                 // print!("   [{}] ", i + 1); // Opcional: log de paso
@@ -54,10 +87,29 @@ impl CodexExecutor {
                     }
                 },
 
+                CodexResult::Probability(model) => {
+                    if verbose { println!("[PROBABILITY] Processing definition"); }
+                    if let Err(e) = prob.execute(model, &mut observer) {
+                        observer("Probability Error", CodexOutput::Error(format!("{}", e)));
+                    }
+                },
+
+                CodexResult::Graph(model) => {
+                    if verbose { println!("[GRAPH] Processing definition"); }
+                    if let Err(e) = graph.execute(model, &mut observer) {
+                        observer("Graph Error", CodexOutput::Error(format!("{}", e)));
+                    }
+                },
+
                 CodexResult::Boolean(model) => {
                     if verbose { println!("[BOOLEAN] Processing definition: {:?}", model.name); }
-                    // Placeholder hasta que tengas el BooleanExecutor listo
-                    observer("System", CodexOutput::Message("Dominio Booleano registrado (Sin ejecución aún)".into()));
+                    if let Err(e) = bool_exec.execute(model, &mut observer) {
+                        observer("Boolean Error", CodexOutput::Error(format!("{}", e)));
+                    }
+                },
+
+                CodexResult::ParseError(error) => {
+                    observer("Syntax Error", CodexOutput::Error(error.to_string()));
                 },
 
                 // --- QUERY GENÉRICA (POLIMORFISMO) ---
@@ -78,16 +130,91 @@ impl CodexExecutor {
                         true 
                     };
 
-                    // 3. Si nadie respondió
-                    if !handled_lin && !handled_opt {
+                    // 3. Preguntar a Probabilidad
+                    let handled_prob = if !handled_opt {
+                        prob.try_execute_query(query, &mut observer)
+                    } else {
+                        true
+                    };
+
+                    // 4. Preguntar a Grafos
+                    let handled_graph = if !handled_prob {
+                        graph.try_execute_query(query, &mut observer)
+                    } else {
+                        true
+                    };
+
+                    // 5. Preguntar a Lógica Booleana
+                    let handled_bool = if !handled_graph {
+                        bool_exec.try_execute_query(query, &mut observer)
+                    } else {
+                        true
+                    };
+
+                    // 6. Si nadie respondió, listamos lo que sí está definido (ayuda con typos)
+                    if !handled_bool {
+                        let mut known: Vec<String> = lin_alg.known_ids().into_iter()
+                            .chain(opt.known_ids())
+                            .chain(prob.known_ids())
+                            .chain(graph.known_ids())
+                            .chain(bool_exec.known_ids())
+                            .map(|id| format!("'{}'", id))
+                            .collect();
+                        known.sort();
+
+                        let defined = if known.is_empty() {
+                            "No hay identificadores definidos.".to_string()
+                        } else {
+                            format!("Identificadores definidos: {}.", known.join(", "))
+                        };
+
                         observer("Error", CodexOutput::Error(
-                            format!("El identificador '{}' no fue encontrado en ningún dominio activo (LinearAlgebra, Optimization).", query.target_id)
+                            format!("El identificador '{}' no fue encontrado en ningún dominio activo (LinearAlgebra, Optimization, Probability, Graph, Boolean). {}", query.target_id, defined)
                         ));
                     }
                 }
             }
         }
     }
+
+    // --- PERSISTENCIA EN DISCO ---
+
+    /// Modelos definidos hasta ahora en todos los dominios.
+    pub fn state(&self) -> CodexState {
+        CodexState {
+            linear_algebra: self.lin_alg.export_state(),
+            optimization: self.opt.export_state(),
+            probability: self.prob.export_state(),
+            graphs: self.graph.export_state(),
+            boolean: self.bool_exec.export_state(),
+        }
+    }
+
+    /// Agrega (o reemplaza, si el nombre coincide) los modelos de `state`.
+    pub fn restore(&mut self, state: CodexState) -> Result<()> {
+        self.lin_alg.import_state(state.linear_algebra);
+        self.opt.import_state(state.optimization);
+        self.prob.import_state(state.probability)?;
+        self.graph.import_state(state.graphs);
+        self.bool_exec.import_state(state.boolean);
+        Ok(())
+    }
+
+    pub fn save_state(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(&self.state())?;
+        fs::write(path, json)
+            .with_context(|| format!("No se pudo guardar el estado en '{}'", path.display()))
+    }
+
+    pub fn load_state(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("No se pudo leer el estado de '{}'", path.display()))?;
+        let state: CodexState = serde_json::from_str(&json)
+            .with_context(|| format!("Estado inválido en '{}'", path.display()))?;
+        self.restore(state)
+    }
 }
 
 // ==========================================
@@ -104,12 +231,16 @@ mod tests {
     use crate::domains::linear_algebra::parser::LinearAlgebraParser;
     // Importante: Importar el parser de Queries globales
     use crate::domains::queries::parser::QueryParser;
+    use crate::domains::probability::ProbabilityParser;
+    use crate::domains::graph::GraphParser;
 
     fn engine_setup() -> CodexEngine {
         let mut engine = CodexEngine::new();
         engine.register(OptimizationParser);
         engine.register(BooleanParser);
         engine.register(LinearAlgebraParser);
+        engine.register(ProbabilityParser);
+        engine.register(GraphParser);
         engine.register(QueryParser); // <--- ¡No olvidar registrar este!
         engine
     }
@@ -213,4 +344,307 @@ mod tests {
         });
         assert!(error_caught, "El executor debería haber reportado un error de 'no encontrado'");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_probability_marginal_query() {
+        let engine = engine_setup();
+        let code = r#"
+        BayesNet "Sprinkler" {
+            Rain: 0.2
+            Sprinkler | Rain: [true: 0.01, false: 0.4]
+            WetGrass | Sprinkler, Rain: [true true: 0.99, true false: 0.9, false true: 0.8, false false: 0.0]
+        }
+
+        query "Sprinkler" {
+            P(WetGrass | Rain=true) as wet_if_rain
+        }
+        "#;
+
+        let results = engine.process_file(code);
+        let mut p_wet = None;
+
+        CodexExecutor::execute(results, false, |alias, output| {
+            match output {
+                CodexOutput::Distribution(dist) if alias == "wet_if_rain" => {
+                    p_wet = dist.iter().find(|(state, _)| state == "true").map(|(_, p)| *p);
+                }
+                CodexOutput::Error(e) => panic!("Error inesperado: {}", e),
+                _ => {}
+            }
+        });
+
+        // Exacto: 0.01 * 0.99 + 0.99 * 0.8 = 0.8019
+        let p = p_wet.expect("La consulta no devolvió una distribución");
+        assert!((p - 0.8019).abs() < 0.02, "P(WetGrass | Rain=true) = {}", p);
+    }
+
+    #[test]
+    fn test_graph_shortest_path_query() {
+        let engine = engine_setup();
+        let code = r#"
+        Graph "Ciudades" {
+            edges { A-B:3, B-C:2, A-C:7, C-D:1 }
+        }
+
+        query "Ciudades" {
+            shortest_path A to D as ruta
+            mst
+        }
+        "#;
+
+        let results = engine.process_file(code);
+        let mut route = None;
+        let mut tree = None;
+
+        CodexExecutor::execute(results, false, |alias, output| {
+            match output {
+                CodexOutput::Message(text) if alias == "ruta" => route = Some(text),
+                CodexOutput::Message(text) if alias == "mst" => tree = Some(text),
+                CodexOutput::Error(e) => panic!("Error inesperado: {}", e),
+                _ => {}
+            }
+        });
+
+        assert_eq!(route.as_deref(), Some("A -> B -> C -> D (costo = 6)"));
+        assert!(tree.expect("Falta la salida de 'mst'").contains("Total = 6"));
+    }
+
+    #[test]
+    fn test_graph_negative_weights_use_bellman_ford() {
+        let engine = engine_setup();
+        // Dijkstra fijaría C en 2 (A->C) antes de ver A->B->C = 4 - 3 = 1
+        let code = r#"
+        Graph "Red" {
+            edges { A->B:4, A->C:2, B->C:-3, C->D:1 }
+        }
+        Graph "Lazo" {
+            edges { A-B:-1, B-C:2 }
+        }
+
+        query "Red" { shortest_path A to D as ruta }
+        query "Lazo" { shortest_path A to C as ruta }
+        "#;
+
+        let mut outputs = Vec::new();
+        CodexExecutor::execute(engine.process_file(code), false, |alias, output| outputs.push((alias.to_string(), output)));
+
+        assert!(
+            matches!(&outputs[0], (alias, CodexOutput::Message(text)) if alias == "ruta" && text == "A -> B -> C -> D (costo = 2)"),
+            "{:?}", outputs
+        );
+        // En un grafo no dirigido una arista negativa ya es un ciclo negativo
+        assert!(
+            matches!(&outputs[1], (_, CodexOutput::Error(e)) if e.contains("Ciclo de peso negativo")),
+            "{:?}", outputs
+        );
+    }
+
+    #[test]
+    fn test_save_and_load_state() {
+        let engine = engine_setup();
+        let path = std::env::temp_dir().join("suma_codex_state_test.json");
+
+        // 1. Primera sesión: solo definiciones
+        let definitions = engine.process_file(r#"
+        Optimization "Plan" {
+            maximize 3*x + 2*y
+            constraints {
+                x + y <= 4
+                x <= 3
+            }
+        }
+
+        Graph "Red" {
+            edges { A-B:1, B-C:1, A-C:5 }
+        }
+        "#);
+        let mut first = CodexExecutor::new(false);
+        first.run(definitions, |_, _| {});
+        first.save_state(&path).unwrap();
+
+        // 2. Segunda sesión: executor nuevo, carga el estado y consulta
+        let mut second = CodexExecutor::new(false);
+        second.load_state(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let queries = engine.process_file(r#"
+        query "Plan" { solve }
+        query "Red" { shortest_path(A to C) as ruta }
+        "#);
+
+        let mut outputs = Vec::new();
+        second.run(queries, |alias, output| outputs.push((alias.to_string(), output)));
+
+        assert!(outputs.iter().all(|(_, o)| !matches!(o, CodexOutput::Error(_))), "{:?}", outputs);
+        let text_of = |label: &str| outputs.iter().find_map(|(alias, o)| match o {
+            CodexOutput::Message(text) if alias == label => Some(text.clone()),
+            _ => None,
+        });
+        assert!(text_of("Result").expect("Falta el resultado de 'solve'").contains("Z = 11"));
+        assert_eq!(text_of("ruta").as_deref(), Some("A -> B -> C (costo = 2)"));
+    }
+
+    #[test]
+    fn test_missing_artifact_lists_known_ids() {
+        let engine = engine_setup();
+        let code = r#"
+        Optimization "Plan_Produccion" {
+            maximize x
+            constraints {
+                x <= 5
+            }
+        }
+
+        LinearSystem "Sistema_1" {
+            coefficients: [1, 0; 0, 1]
+            constants:    [1; 2]
+        }
+
+        query "Plan_Producion" {
+            solve
+        }
+        "#;
+
+        let results = engine.process_file(code);
+        let mut message = None;
+        CodexExecutor::execute(results, false, |_, output| {
+            if let CodexOutput::Error(msg) = output {
+                message = Some(msg);
+            }
+        });
+
+        let message = message.expect("Se esperaba un error de identificador no encontrado");
+        assert!(message.contains("'Plan_Producion'"));
+        assert!(message.contains("Identificadores definidos: 'Plan_Produccion', 'Sistema_1'."), "{}", message);
+    }
+
+    #[test]
+    fn test_shadow_prices_sorted_by_constraint() {
+        let engine = engine_setup();
+        let code = r#"
+        Optimization "Mezcla" {
+            maximize 3*x + 2*y + 4*z
+            constraints {
+                x + y + 2*z <= 4
+                2*x + z <= 5
+                x + 3*y + z <= 7
+                x <= 10
+                y <= 10
+                z <= 10
+                x + y <= 20
+                x + z <= 20
+                y + z <= 20
+                x + y + z <= 30
+                2*x + 2*y <= 40
+            }
+        }
+
+        query "Mezcla" {
+            shadow_prices
+        }
+        "#;
+
+        let results = engine.process_file(code);
+        let mut prices = None;
+        CodexExecutor::execute(results, false, |alias, output| {
+            if let (CodexOutput::Message(text), "Shadow Prices") = (output, alias) {
+                prices = Some(text);
+            }
+        });
+
+        let prices = prices.expect("Falta la salida de 'shadow_prices'");
+        let names: Vec<&str> = prices
+            .lines()
+            .filter_map(|line| line.trim().split(':').next())
+            .collect();
+        // Orden natural: c2 antes que c10
+        let expected: Vec<String> = (0..11).map(|i| format!("c{}", i)).collect();
+        assert_eq!(names, expected, "{}", prices);
+    }
+
+    #[test]
+    fn test_boolean_queries() {
+        let engine = engine_setup();
+        let code = r#"
+        Boolean "Excluyente" {
+            (A and not B) or (not A and B)
+        }
+
+        Boolean "Siempre" {
+            A or not A or (B and false)
+        }
+
+        Boolean "Redundante" {
+            not (not A and true) or (B and false)
+        }
+
+        query "Excluyente" {
+            truth_table as tabla
+            tautology
+            contradiction
+            evaluate {A=true, B=0} as valor
+        }
+
+        query "Siempre" {
+            tautology
+        }
+
+        query "Redundante" {
+            simplify
+            tautology
+        }
+        "#;
+
+        let results = engine.process_file(code);
+        let mut outputs = Vec::new();
+        CodexExecutor::execute(results, false, |alias, output| outputs.push((alias.to_string(), output)));
+
+        assert!(outputs.iter().all(|(_, o)| !matches!(o, CodexOutput::Error(_))), "{:?}", outputs);
+        let messages: Vec<(&str, &str)> = outputs.iter()
+            .filter_map(|(alias, o)| match o {
+                CodexOutput::Message(text) => Some((alias.as_str(), text.as_str())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(messages, vec![
+            ("tautology", "false"),
+            ("contradiction", "false"),
+            ("valor", "true"),
+            ("tautology", "true"),
+            // Identidad, dominación y doble negación dejan solo la variable
+            ("simplify", "A"),
+            ("tautology", "false"),
+        ]);
+
+        let Some((_, CodexOutput::Table { headers, rows })) = outputs.iter().find(|(alias, _)| alias == "tabla") else {
+            panic!("Falta la tabla de verdad: {:?}", outputs);
+        };
+        assert_eq!(&headers[..2], ["A", "B"]);
+        let results: Vec<&str> = rows.iter().map(|row| row[2].as_str()).collect();
+        assert_eq!(results, vec!["0", "1", "1", "0"]);
+    }
+
+    #[test]
+    fn test_parse_errors_report_position() {
+        let engine = engine_setup();
+        let code = "Graph \"Red\" {\n    edges { A-B:3 }\n}\n\nLinearSystem \"S\" {\n    coefficients: [1, 2; 3, 4]\n    constants: [5; ]\n}\n";
+
+        let results = engine.process_file(code);
+        let mut errors = Vec::new();
+        CodexExecutor::execute(results, false, |alias, output| {
+            if let CodexOutput::Error(msg) = output {
+                errors.push((alias.to_string(), msg));
+            }
+        });
+
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        let (alias, msg) = &errors[0];
+        assert_eq!(alias, "Syntax Error");
+        assert!(msg.starts_with("Error en bloque 'LinearSystem'"), "{}", msg);
+        assert!(msg.contains("at line 7, col"), "{}", msg);
+
+        // Un error global (llaves sin cerrar) también trae su posición
+        let results = engine.process_file("Graph \"G\" {\n  edges { A-B }\n");
+        assert!(matches!(&results[..], [CodexResult::ParseError(e)] if e.line >= 1), "{:?}", results);
+    }
+}
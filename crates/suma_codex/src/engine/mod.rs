@@ -1,5 +1,6 @@
 pub mod dispatcher;
 pub mod executor;
+pub mod state;
 mod adapters;
 
 // Reexportamos para que el usuario pueda usar engine::CodexEngine
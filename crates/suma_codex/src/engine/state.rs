@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+use suma_core::optimization::linear::model::LinearProblem;
+
+use crate::domains::boolean_algebra::BooleanModel;
+use crate::domains::graph::ast::GraphModel;
+use crate::domains::linear_algebra::ast::SystemDef;
+use crate::domains::probability::ast::BayesNetModel;
+
+/// Modelos definidos en cada dominio, por nombre. Es lo que `CodexExecutor`
+/// guarda en disco para retomar una sesión en otra invocación.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CodexState {
+    #[serde(default)]
+    pub linear_algebra: HashMap<String, SystemDef>,
+    #[serde(default)]
+    pub optimization: HashMap<String, LinearProblem>,
+    #[serde(default)]
+    pub probability: HashMap<String, BayesNetModel>,
+    #[serde(default)]
+    pub graphs: HashMap<String, GraphModel>,
+    #[serde(default)]
+    pub boolean: HashMap<String, BooleanModel>,
+}
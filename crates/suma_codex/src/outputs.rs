@@ -10,11 +10,17 @@ pub enum CodexOutput {
     LinAlgVector(DenseMatrix<f64>), // Usamos DenseMatrix porque un vector es una matriz Nx1
     LinAlgMatrix(DenseMatrix<f64>),
     
+    // --- Salidas de Probabilidad ---
+    Distribution(Vec<(String, f64)>), // (estado, probabilidad)
+
     // --- Salidas Genéricas ---
     Message(String),      // Mensajes informativos simples
     Error(String),        // Errores de runtime controlados
+    Table {               // Tablas (ej: tabla de verdad), celdas ya formateadas
+        headers: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
     
     // A futuro agregarás aquí:
     // OptimizationResult(...),
-    // BooleanTable(...),
 }
\ No newline at end of file
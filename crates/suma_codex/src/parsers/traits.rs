@@ -4,6 +4,9 @@ use std::any::Any;
 pub type DomainResult = Result<Box<dyn Any>, Box<dyn Error>>;
 
 pub trait DomainParser {
+    /// Nombre legible del dominio (para listados como `suma info`)
+    fn domain_name(&self) -> &'static str;
+
     // CAMBIO: Ahora el parser define una LISTA de palabras clave que acepta
     fn valid_keywords(&self) -> Vec<&'static str>;
     
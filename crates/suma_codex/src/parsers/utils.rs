@@ -1,8 +1,71 @@
 // src/parsers/utils.rs
-use pest::error::Error;
+use std::fmt;
+use pest::error::{Error, LineColLocation};
 use pest::RuleType;
+use serde::Serialize;
 
 // Función genérica que convierte errores de PEST a Strings legibles
 pub fn format_pest_error<R: RuleType>(e: Error<R>) -> String {
     format!("Error de sintaxis en dominio: {}", e)
 }
+
+/// Error de sintaxis con su posición (línea y columna, desde 1).
+/// Los parsers de dominio la dan relativa a su bloque; el dispatcher la
+/// traslada al archivo completo con `offset`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SyntaxError {
+    pub message: String,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl SyntaxError {
+    pub fn new(message: impl Into<String>, line: usize, col: usize) -> Self {
+        Self { message: message.into(), line, col }
+    }
+
+    /// Toma el mensaje y la posición de inicio de un error de PEST.
+    pub fn from_pest<R: RuleType>(e: Error<R>) -> Self {
+        let (line, col) = match e.line_col {
+            LineColLocation::Pos(pos) | LineColLocation::Span(pos, _) => pos,
+        };
+        Self::new(e.variant.message(), line, col)
+    }
+
+    /// Convierte una posición relativa a un bloque que empieza en (`line`, `col`)
+    /// en una posición del archivo. Solo la primera línea del bloque se desplaza en columnas.
+    pub fn offset(self, line: usize, col: usize) -> Self {
+        let (abs_line, abs_col) = if self.line <= 1 {
+            (line, col + self.col.saturating_sub(1))
+        } else {
+            (line + self.line - 1, self.col)
+        };
+        Self { line: abs_line, col: abs_col, ..self }
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at line {}, col {})", self.message, self.line, self.col)
+    }
+}
+
+impl std::error::Error for SyntaxError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_to_file_position() {
+        // Error en la primera línea del bloque: se suman las columnas
+        let first = SyntaxError::new("x", 1, 5).offset(10, 3);
+        assert_eq!((first.line, first.col), (10, 7));
+
+        // En líneas siguientes la columna ya es absoluta
+        let later = SyntaxError::new("x", 3, 9).offset(10, 3);
+        assert_eq!((later.line, later.col), (12, 9));
+
+        assert_eq!(later.to_string(), "x (at line 12, col 9)");
+    }
+}
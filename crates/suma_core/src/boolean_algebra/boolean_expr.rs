@@ -188,6 +188,73 @@ impl BooleanExpr {
             .all(|&result| !result)
     }
     
+    /// Índices de las filas de la tabla de verdad donde la expresión es verdadera,
+    /// p. ej. `Σm(1, 3, 7)`. La numeración es la de `truth_table()`: la primera
+    /// variable de `variables` es el bit más significativo.
+    /// Una expresión constante tiene una única fila (índice 0).
+    pub fn minterms(&self) -> Vec<u32> {
+        self.rows_with_result(true)
+    }
+
+    /// Índices de las filas donde la expresión es falsa, p. ej. `ΠM(0, 2)`.
+    pub fn maxterms(&self) -> Vec<u32> {
+        self.rows_with_result(false)
+    }
+
+    fn rows_with_result(&self, expected: bool) -> Vec<u32> {
+        let truth_table = self.truth_table();
+        let result_label = truth_table.column_order.last()
+            .expect("Truth table must have at least one column");
+        truth_table.columns.get(result_label)
+            .expect("Result column must exist")
+            .iter()
+            .enumerate()
+            .filter(|&(_, &result)| result == expected)
+            .map(|(row, _)| row as u32)
+            .collect()
+    }
+
+    /// Primera asignación que hace verdadera la expresión, o `None` si es insatisfacible.
+    /// Usa búsqueda DPLL sobre el AST, sin construir la tabla de verdad.
+    /// Las variables que no influyen en el resultado se devuelven en `false`.
+    pub fn satisfy(&self) -> Option<HashMap<String, bool>> {
+        let mut model = crate::boolean_algebra::sat::find_model(&self.ast)?;
+        for var in &self.variables {
+            model.entry(var.clone()).or_insert(false);
+        }
+        Some(model)
+    }
+
+    /// Número de asignaciones de las variables que hacen verdadera la expresión.
+    pub fn count_models(&self) -> u64 {
+        crate::boolean_algebra::sat::count_models(&self.ast, self.variables.len())
+    }
+
+    /// Ordena el AST aplicando leyes algebraicas locales (doble negación, identidad,
+    /// dominación, idempotencia y plegado de constantes) hasta un punto fijo.
+    /// No enumera asignaciones, así que sirve también con muchas variables.
+    pub fn simplify(&self) -> BooleanExpr {
+        let ast = crate::boolean_algebra::simplify::simplify(&self.ast);
+        let variables = ast.extract_variables();
+        BooleanExpr { ast, variables }
+    }
+
+    /// Reescribe la expresión usando solo compuertas NAND.
+    pub fn to_nand_only(&self) -> BooleanExpr {
+        BooleanExpr {
+            ast: crate::boolean_algebra::universal::to_nand_only(&self.ast),
+            variables: self.variables.clone(),
+        }
+    }
+
+    /// Reescribe la expresión usando solo compuertas NOR.
+    pub fn to_nor_only(&self) -> BooleanExpr {
+        BooleanExpr {
+            ast: crate::boolean_algebra::universal::to_nor_only(&self.ast),
+            variables: self.variables.clone(),
+        }
+    }
+
     /// Verifica si dos expresiones son equivalentes
     pub fn equivalent_to(&self, other: &BooleanExpr) -> bool {
         // Para ser equivalentes, deben tener las mismas variables
@@ -220,6 +287,41 @@ impl BooleanExpr {
         true
     }
     
+    /// Filas de la tabla de verdad en las que `self` y `other` difieren, sobre la unión
+    /// (ordenada alfabéticamente) de sus variables. Cada fila es
+    /// `(asignación, resultado de self, resultado de other)`. Vacío si son equivalentes.
+    pub fn truth_table_diff(&self, other: &BooleanExpr) -> Vec<(HashMap<String, bool>, bool, bool)> {
+        let mut all_vars: Vec<String> = self.variables.iter()
+            .chain(other.variables.iter())
+            .cloned()
+            .collect::<std::collections::HashSet<_>>()
+            .into_iter()
+            .collect();
+        all_vars.sort();
+
+        let num_combinations = 1 << all_vars.len();
+        let mut diff = Vec::new();
+
+        for i in 0..num_combinations {
+            let mut values = HashMap::new();
+
+            for (j, var) in all_vars.iter().enumerate() {
+                let value = (i >> (all_vars.len() - 1 - j)) & 1 == 1;
+                values.insert(var.as_str(), value);
+            }
+
+            let self_result = self.ast.evaluate(&values);
+            let other_result = other.ast.evaluate(&values);
+
+            if self_result != other_result {
+                let assignment = values.into_iter().map(|(k, v)| (k.to_string(), v)).collect();
+                diff.push((assignment, self_result, other_result));
+            }
+        }
+
+        diff
+    }
+
     // --- FUNCIONES AUXILIARES ---
     
     /// Valida que un nombre de variable sea válido
@@ -276,6 +378,131 @@ impl Clone for BooleanExpr {
 mod tests {
     use super::*;
 
+    // --- Tests de simplificación estructural ---
+    #[test]
+    fn test_simplify_rules() {
+        let cases = [
+            ("~~A", "A"),
+            ("A & true", "A"),
+            ("A | false", "A"),
+            ("A & false", "false"),
+            ("A | true", "true"),
+            ("(A | B) & (A | B)", "A | B"),
+            ("~(true & false) & ~~B", "B"),
+        ];
+        for (input, expected) in cases {
+            let simplified = BooleanExpr::new(input).unwrap().simplify();
+            assert_eq!(simplified.ast, BooleanExpr::new(expected).unwrap().ast, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_simplify_preserves_semantics() {
+        for input in ["(A & true) | (B & ~~C)", "A xor false -> (B <-> true)", "~(A | false) & (C | C)", "~(~A & true) | (B & false)"] {
+            let expr = BooleanExpr::new(input).unwrap();
+            let simplified = expr.simplify();
+            assert!(simplified.complexity() <= expr.complexity(), "{}", input);
+            assert!(expr.truth_table_diff(&simplified).is_empty(), "{} -> {}", input, simplified.to_string());
+        }
+
+        // Sin reglas aplicables la estructura se conserva
+        let expr = BooleanExpr::new("A -> (B xor C)").unwrap();
+        assert_eq!(expr.simplify().ast, expr.ast);
+    }
+
+    #[test]
+    fn test_simplify_drops_eliminated_variables() {
+        let simplified = BooleanExpr::new("(A & false) | B").unwrap().simplify();
+        assert_eq!(simplified.variables, vec!["B"]);
+    }
+
+    // --- Tests de compuertas universales ---
+    #[test]
+    fn test_nand_and_nor_only_preserve_truth_table() {
+        for input in ["A & B", "A | ~B", "A xor B", "A <-> (B | C)", "(A -> B) & (C xor ~A)", "~~A | (B & true)"] {
+            let expr = BooleanExpr::new(input).unwrap();
+            let nand = expr.to_nand_only();
+            let nor = expr.to_nor_only();
+            assert_eq!(nand.variables, expr.variables);
+            assert!(expr.truth_table_diff(&nand).is_empty(), "NAND: {}", input);
+            assert!(expr.truth_table_diff(&nor).is_empty(), "NOR: {}", input);
+        }
+    }
+
+    // --- Tests de minterms / maxterms ---
+    #[test]
+    fn test_minterms_and_maxterms() {
+        // A es el bit más significativo: fila 5 = A=1, B=0, C=1
+        let expr = BooleanExpr::new("(A & C) | (~A & B)").unwrap();
+        assert_eq!(expr.minterms(), vec![2, 3, 5, 7]);
+        assert_eq!(expr.maxterms(), vec![0, 1, 4, 6]);
+
+        let table = expr.truth_table();
+        for row in expr.minterms() {
+            assert_eq!(table.combinations[row as usize], vec![(row >> 2) & 1 == 1, (row >> 1) & 1 == 1, row & 1 == 1]);
+        }
+    }
+
+    #[test]
+    fn test_minterms_of_constants() {
+        let expr = BooleanExpr::new("true | false").unwrap();
+        assert_eq!(expr.minterms(), vec![0]);
+        assert!(expr.maxterms().is_empty());
+
+        let expr = BooleanExpr::new("true & false").unwrap();
+        assert!(expr.minterms().is_empty());
+        assert_eq!(expr.maxterms(), vec![0]);
+    }
+
+    // --- Tests de satisfacibilidad ---
+    #[test]
+    fn test_satisfy_returns_model() {
+        let expr = BooleanExpr::new("(A | B) & ~A & (B -> C)").unwrap();
+        let model = expr.satisfy().expect("Satisfacible");
+        assert_eq!(model, HashMap::from([
+            ("A".to_string(), false),
+            ("B".to_string(), true),
+            ("C".to_string(), true),
+        ]));
+
+        let values: HashMap<&str, bool> = model.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        assert!(expr.evaluate(&values).unwrap());
+    }
+
+    #[test]
+    fn test_satisfy_unsatisfiable() {
+        let expr = BooleanExpr::new("(A xor B) & (A <-> B)").unwrap();
+        assert_eq!(expr.satisfy(), None);
+        assert_eq!(expr.count_models(), 0);
+    }
+
+    #[test]
+    fn test_count_models_matches_truth_table() {
+        for input in ["A | B", "A -> B", "(A xor B) xor C", "~(A & B) | (C <-> D)", "A & (B | ~B)"] {
+            let expr = BooleanExpr::new(input).unwrap();
+            let table = expr.truth_table();
+            let result = table.column_order.last().unwrap();
+            let expected = table.columns[result].iter().filter(|&&r| r).count() as u64;
+            assert_eq!(expr.count_models(), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_satisfy_many_variables() {
+        // 41 variables: la tabla tendría 2^41 filas
+        let clauses: Vec<String> = (0..20)
+            .map(|i| format!("(x{} | ~y{}) & (y{} -> y{})", i, i, i, i + 1))
+            .collect();
+        // y0 obliga a toda la cadena y0 -> y1 -> ... -> y20, incompatible con ~y20
+        let expr = BooleanExpr::new(&format!("{} & y0 & ~y20", clauses.join(" & "))).unwrap();
+        assert_eq!(expr.satisfy(), None);
+
+        let expr = BooleanExpr::new(&clauses.join(" & ")).unwrap();
+        let model = expr.satisfy().expect("Satisfacible");
+        let values: HashMap<&str, bool> = model.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        assert!(expr.evaluate(&values).unwrap());
+    }
+
     // --- Tests básicos de creación ---
     #[test]
     fn test_boolean_expr_creation() {
@@ -490,6 +717,29 @@ mod tests {
         assert!(!expr3.equivalent_to(&expr4));
     }
 
+    #[test]
+    fn test_truth_table_diff() {
+        // A & B vs A | C: difieren cuando exactamente una de las dos es verdadera
+        let expr1 = BooleanExpr::new("A & B").unwrap();
+        let expr2 = BooleanExpr::new("A | C").unwrap();
+
+        let diff = expr1.truth_table_diff(&expr2);
+        let row = |a, b, c| HashMap::from([("A".to_string(), a), ("B".to_string(), b), ("C".to_string(), c)]);
+
+        // Orden de filas: A, B, C en binario (A es el bit más significativo)
+        assert_eq!(diff, vec![
+            (row(false, false, true), false, true),
+            (row(false, true, true), false, true),
+            (row(true, false, false), false, true),
+            (row(true, false, true), false, true),
+        ]);
+
+        // Expresiones equivalentes: sin diferencias
+        let implies = BooleanExpr::new("A implies B").unwrap();
+        let or_form = BooleanExpr::new("~A | B").unwrap();
+        assert!(implies.truth_table_diff(&or_form).is_empty());
+    }
+
     // --- Tests de representación de strings ---
     #[test]
     fn test_string_representation() {
@@ -4,6 +4,9 @@ pub mod parser;
 pub mod boolean_expr;
 pub mod truth_table;
 pub mod error;  // NUEVO
+pub mod sat;
+pub mod simplify;
+pub mod universal;
 
 // Re-export para fácil acceso
 pub use truth_table::TruthTable;
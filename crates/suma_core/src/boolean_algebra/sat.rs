@@ -0,0 +1,200 @@
+// src/boolean_algebra/sat.rs
+//! Búsqueda de modelos estilo DPLL directamente sobre el AST.
+//!
+//! En lugar de recorrer las 2^n filas de la tabla de verdad, se asigna una variable
+//! a la vez y se simplifica la expresión (evaluación parcial). Los literales que
+//! la conjunción de nivel superior obliga (`A & ~B & ...`) se propagan sin ramificar,
+//! que es el equivalente a la propagación unitaria sobre CNF.
+
+use std::collections::HashMap;
+use crate::boolean_algebra::ast::Node;
+
+/// Primera asignación (parcial) que satisface `node`, o `None` si es insatisfacible.
+/// Las variables que no aparecen en la asignación pueden tomar cualquier valor.
+pub fn find_model(node: &Node) -> Option<HashMap<String, bool>> {
+    let mut assignment = HashMap::new();
+    if search(node.clone(), &mut assignment) {
+        Some(assignment)
+    } else {
+        None
+    }
+}
+
+/// Número de asignaciones de `num_vars` variables que satisfacen `node`.
+/// Satura en `u64::MAX` si el resultado no cabe.
+pub fn count_models(node: &Node, num_vars: usize) -> u64 {
+    count(node.clone(), num_vars)
+}
+
+fn search(node: Node, assignment: &mut HashMap<String, bool>) -> bool {
+    let (node, forced) = propagate(node);
+    let Some(node) = node else {
+        return false;
+    };
+
+    let mut added = Vec::new();
+    for (var, value) in forced {
+        assignment.insert(var.clone(), value);
+        added.push(var);
+    }
+
+    let found = match &node {
+        Node::Constant(value) => *value,
+        _ => {
+            let var = first_variable(&node).expect("Una expresión no constante tiene variables");
+            [true, false].into_iter().any(|value| {
+                assignment.insert(var.clone(), value);
+                if search(restrict(&node, &var, value), assignment) {
+                    true
+                } else {
+                    assignment.remove(&var);
+                    false
+                }
+            })
+        }
+    };
+
+    if !found {
+        for var in added {
+            assignment.remove(&var);
+        }
+    }
+    found
+}
+
+fn count(node: Node, free_vars: usize) -> u64 {
+    // Las asignaciones que contradicen un literal forzado no cuentan: no hay que ramificar
+    let (node, forced) = propagate(node);
+    let Some(node) = node else {
+        return 0;
+    };
+    let free_vars = free_vars - forced.len();
+
+    match &node {
+        Node::Constant(true) => pow2(free_vars),
+        Node::Constant(false) => 0,
+        _ => {
+            let var = first_variable(&node).expect("Una expresión no constante tiene variables");
+            count(restrict(&node, &var, true), free_vars - 1)
+                .saturating_add(count(restrict(&node, &var, false), free_vars - 1))
+        }
+    }
+}
+
+fn pow2(exponent: usize) -> u64 {
+    1u64.checked_shl(exponent as u32).unwrap_or(u64::MAX)
+}
+
+/// Aplica repetidamente los literales forzados. Devuelve `None` si hay un conflicto
+/// (la expresión se vuelve falsa), junto con los literales asignados.
+fn propagate(mut node: Node) -> (Option<Node>, Vec<(String, bool)>) {
+    let mut assigned = Vec::new();
+    loop {
+        if node == Node::Constant(false) {
+            return (None, assigned);
+        }
+
+        let mut units = Vec::new();
+        collect_units(&node, &mut units);
+        let Some((var, value)) = units.into_iter().next() else {
+            return (Some(node), assigned);
+        };
+
+        node = restrict(&node, &var, value);
+        assigned.push((var, value));
+    }
+}
+
+/// Literales que deben cumplirse para que la expresión sea verdadera.
+fn collect_units(node: &Node, units: &mut Vec<(String, bool)>) {
+    match node {
+        Node::Variable(name) => units.push((name.clone(), true)),
+        Node::Not(inner) => {
+            if let Node::Variable(name) = &**inner {
+                units.push((name.clone(), false));
+            }
+        }
+        Node::And(left, right) => {
+            collect_units(left, units);
+            collect_units(right, units);
+        }
+        _ => {}
+    }
+}
+
+fn first_variable(node: &Node) -> Option<String> {
+    match node {
+        Node::Variable(name) => Some(name.clone()),
+        Node::Constant(_) => None,
+        Node::Not(inner) => first_variable(inner),
+        Node::And(l, r)
+        | Node::Or(l, r)
+        | Node::Xor(l, r)
+        | Node::Nand(l, r)
+        | Node::Nor(l, r)
+        | Node::Implies(l, r)
+        | Node::Iff(l, r) => first_variable(l).or_else(|| first_variable(r)),
+    }
+}
+
+/// Sustituye `var` por `value` y propaga las constantes.
+fn restrict(node: &Node, var: &str, value: bool) -> Node {
+    use Node::Constant as C;
+
+    match node {
+        Node::Variable(name) if name == var => C(value),
+        Node::Variable(_) | Node::Constant(_) => node.clone(),
+        Node::Not(inner) => negate(restrict(inner, var, value)),
+        _ => {
+            let (l, r) = match node {
+                Node::And(l, r)
+                | Node::Or(l, r)
+                | Node::Xor(l, r)
+                | Node::Nand(l, r)
+                | Node::Nor(l, r)
+                | Node::Implies(l, r)
+                | Node::Iff(l, r) => (restrict(l, var, value), restrict(r, var, value)),
+                _ => unreachable!(),
+            };
+
+            match (node, l, r) {
+                (Node::And(..), C(false), _) | (Node::And(..), _, C(false)) => C(false),
+                (Node::And(..), C(true), x) | (Node::And(..), x, C(true)) => x,
+                (Node::And(..), l, r) => Node::And(Box::new(l), Box::new(r)),
+
+                (Node::Or(..), C(true), _) | (Node::Or(..), _, C(true)) => C(true),
+                (Node::Or(..), C(false), x) | (Node::Or(..), x, C(false)) => x,
+                (Node::Or(..), l, r) => Node::Or(Box::new(l), Box::new(r)),
+
+                (Node::Nand(..), C(false), _) | (Node::Nand(..), _, C(false)) => C(true),
+                (Node::Nand(..), C(true), x) | (Node::Nand(..), x, C(true)) => negate(x),
+                (Node::Nand(..), l, r) => Node::Nand(Box::new(l), Box::new(r)),
+
+                (Node::Nor(..), C(true), _) | (Node::Nor(..), _, C(true)) => C(false),
+                (Node::Nor(..), C(false), x) | (Node::Nor(..), x, C(false)) => negate(x),
+                (Node::Nor(..), l, r) => Node::Nor(Box::new(l), Box::new(r)),
+
+                (Node::Xor(..), C(b), x) | (Node::Xor(..), x, C(b)) => if b { negate(x) } else { x },
+                (Node::Xor(..), l, r) => Node::Xor(Box::new(l), Box::new(r)),
+
+                (Node::Iff(..), C(b), x) | (Node::Iff(..), x, C(b)) => if b { x } else { negate(x) },
+                (Node::Iff(..), l, r) => Node::Iff(Box::new(l), Box::new(r)),
+
+                (Node::Implies(..), C(false), _) | (Node::Implies(..), _, C(true)) => C(true),
+                (Node::Implies(..), C(true), x) => x,
+                (Node::Implies(..), x, C(false)) => negate(x),
+                (Node::Implies(..), l, r) => Node::Implies(Box::new(l), Box::new(r)),
+
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+fn negate(node: Node) -> Node {
+    match node {
+        Node::Constant(b) => Node::Constant(!b),
+        Node::Not(inner) => *inner,
+        other => Node::Not(Box::new(other)),
+    }
+}
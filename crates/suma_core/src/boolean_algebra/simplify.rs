@@ -0,0 +1,83 @@
+// src/boolean_algebra/simplify.rs
+//! Simplificación estructural del AST mediante leyes algebraicas locales.
+//!
+//! A diferencia de la minimización por tabla de verdad, no enumera asignaciones:
+//! reescribe cada nodo (doble negación, identidad, dominación, idempotencia y
+//! plegado de constantes) y repite hasta que el árbol deja de cambiar.
+//! Los operadores que no se pueden reducir se conservan tal cual.
+
+use crate::boolean_algebra::ast::Node;
+
+/// Aplica las reglas de reescritura hasta alcanzar un punto fijo.
+pub fn simplify(node: &Node) -> Node {
+    let mut current = node.clone();
+    loop {
+        let next = rewrite(&current);
+        if next == current {
+            return current;
+        }
+        current = next;
+    }
+}
+
+// Una pasada de abajo hacia arriba: primero los hijos, luego el nodo
+fn rewrite(node: &Node) -> Node {
+    match node {
+        Node::Variable(_) | Node::Constant(_) => node.clone(),
+        Node::Not(inner) => match rewrite(inner) {
+            Node::Constant(value) => Node::Constant(!value),
+            Node::Not(double) => *double,
+            inner => Node::Not(Box::new(inner)),
+        },
+        Node::And(left, right) => match (rewrite(left), rewrite(right)) {
+            (Node::Constant(false), _) | (_, Node::Constant(false)) => Node::Constant(false),
+            (Node::Constant(true), other) | (other, Node::Constant(true)) => other,
+            (l, r) if l == r => l,
+            (l, r) => Node::And(Box::new(l), Box::new(r)),
+        },
+        Node::Or(left, right) => match (rewrite(left), rewrite(right)) {
+            (Node::Constant(true), _) | (_, Node::Constant(true)) => Node::Constant(true),
+            (Node::Constant(false), other) | (other, Node::Constant(false)) => other,
+            (l, r) if l == r => l,
+            (l, r) => Node::Or(Box::new(l), Box::new(r)),
+        },
+        Node::Xor(left, right) => match (rewrite(left), rewrite(right)) {
+            (Node::Constant(a), Node::Constant(b)) => Node::Constant(a ^ b),
+            (Node::Constant(false), other) | (other, Node::Constant(false)) => other,
+            (Node::Constant(true), other) | (other, Node::Constant(true)) => negate(other),
+            (l, r) if l == r => Node::Constant(false),
+            (l, r) => Node::Xor(Box::new(l), Box::new(r)),
+        },
+        Node::Iff(left, right) => match (rewrite(left), rewrite(right)) {
+            (Node::Constant(a), Node::Constant(b)) => Node::Constant(a == b),
+            (Node::Constant(true), other) | (other, Node::Constant(true)) => other,
+            (Node::Constant(false), other) | (other, Node::Constant(false)) => negate(other),
+            (l, r) if l == r => Node::Constant(true),
+            (l, r) => Node::Iff(Box::new(l), Box::new(r)),
+        },
+        Node::Implies(left, right) => match (rewrite(left), rewrite(right)) {
+            (Node::Constant(false), _) | (_, Node::Constant(true)) => Node::Constant(true),
+            (Node::Constant(true), other) => other,
+            (other, Node::Constant(false)) => negate(other),
+            (l, r) if l == r => Node::Constant(true),
+            (l, r) => Node::Implies(Box::new(l), Box::new(r)),
+        },
+        Node::Nand(left, right) => match (rewrite(left), rewrite(right)) {
+            (Node::Constant(a), Node::Constant(b)) => Node::Constant(!(a && b)),
+            (l, r) => Node::Nand(Box::new(l), Box::new(r)),
+        },
+        Node::Nor(left, right) => match (rewrite(left), rewrite(right)) {
+            (Node::Constant(a), Node::Constant(b)) => Node::Constant(!(a || b)),
+            (l, r) => Node::Nor(Box::new(l), Box::new(r)),
+        },
+    }
+}
+
+// Negación que no apila `~~`
+fn negate(node: Node) -> Node {
+    match node {
+        Node::Constant(value) => Node::Constant(!value),
+        Node::Not(inner) => *inner,
+        other => Node::Not(Box::new(other)),
+    }
+}
@@ -0,0 +1,193 @@
+// src/boolean_algebra/universal.rs
+//! Reescritura del AST usando una sola compuerta universal (NAND o NOR).
+//!
+//! Se usan las sustituciones de libro de texto, cuidando que el árbol crezca de
+//! forma lineal (el AST no comparte subárboles, así que cada copia cuenta):
+//! - `NOT x` se escribe `x NAND x` solo si `x` es una hoja; con una subexpresión
+//!   se usa `x NAND 1` (o `x NOR 0`) para no duplicarla.
+//! - Negar un nodo que ya es una negación devuelve directamente `x`.
+//! - Las cadenas de XOR (y de ↔, también asociativo) se reagrupan en un árbol
+//!   balanceado antes de traducirlas: cada XOR repite sus operandos, y en una
+//!   cadena `((A ^ B) ^ C) ^ ...` eso
+//!   crecería de forma exponencial con la cantidad de variables.
+
+use crate::boolean_algebra::ast::Node;
+
+/// Expresa `node` solo con compuertas NAND (las variables y constantes se mantienen).
+pub fn to_nand_only(node: &Node) -> Node {
+    match node {
+        Node::Variable(_) | Node::Constant(_) => node.clone(),
+        Node::Not(inner) => nand_not(to_nand_only(inner)),
+        Node::Nand(l, r) => Node::nand(to_nand_only(l), to_nand_only(r)),
+        Node::And(l, r) => nand_and(to_nand_only(l), to_nand_only(r)),
+        Node::Or(l, r) => nand_or(to_nand_only(l), to_nand_only(r)),
+        Node::Nor(l, r) => nand_not(nand_or(to_nand_only(l), to_nand_only(r))),
+        // A → B = A NAND ~B
+        Node::Implies(l, r) => Node::nand(to_nand_only(l), nand_not(to_nand_only(r))),
+        Node::Xor(..) => balanced_chain(node, to_nand_only, nand_xor),
+        Node::Iff(..) => balanced_chain(node, to_nand_only, |l, r| nand_not(nand_xor(l, r))),
+    }
+}
+
+/// Expresa `node` solo con compuertas NOR (las variables y constantes se mantienen).
+pub fn to_nor_only(node: &Node) -> Node {
+    match node {
+        Node::Variable(_) | Node::Constant(_) => node.clone(),
+        Node::Not(inner) => nor_not(to_nor_only(inner)),
+        Node::Nor(l, r) => Node::nor(to_nor_only(l), to_nor_only(r)),
+        Node::Or(l, r) => nor_or(to_nor_only(l), to_nor_only(r)),
+        Node::And(l, r) => nor_and(to_nor_only(l), to_nor_only(r)),
+        Node::Nand(l, r) => nor_not(nor_and(to_nor_only(l), to_nor_only(r))),
+        // A → B = ~A OR B
+        Node::Implies(l, r) => nor_or(nor_not(to_nor_only(l)), to_nor_only(r)),
+        Node::Iff(..) => balanced_chain(node, to_nor_only, nor_xnor),
+        Node::Xor(..) => balanced_chain(node, to_nor_only, |l, r| nor_not(nor_xnor(l, r))),
+    }
+}
+
+/// Traduce una cadena de XOR o de ↔ (asociativos) como un árbol balanceado de sus operandos.
+fn balanced_chain(node: &Node, translate: fn(&Node) -> Node, combine: impl Fn(Node, Node) -> Node + Copy) -> Node {
+    let mut operands = Vec::new();
+    collect_chain_operands(node, std::mem::discriminant(node), &mut operands);
+    let translated: Vec<Node> = operands.into_iter().map(translate).collect();
+    build_balanced(translated, combine)
+}
+
+fn collect_chain_operands<'a>(node: &'a Node, kind: std::mem::Discriminant<Node>, operands: &mut Vec<&'a Node>) {
+    match node {
+        Node::Xor(l, r) | Node::Iff(l, r) if std::mem::discriminant(node) == kind => {
+            collect_chain_operands(l, kind, operands);
+            collect_chain_operands(r, kind, operands);
+        }
+        other => operands.push(other),
+    }
+}
+
+fn build_balanced(mut nodes: Vec<Node>, combine: impl Fn(Node, Node) -> Node + Copy) -> Node {
+    if nodes.len() == 1 {
+        return nodes.pop().unwrap();
+    }
+    let right = nodes.split_off(nodes.len() / 2);
+    combine(build_balanced(nodes, combine), build_balanced(right, combine))
+}
+
+fn is_leaf(node: &Node) -> bool {
+    matches!(node, Node::Variable(_) | Node::Constant(_))
+}
+
+// --- Construcciones con NAND ---
+
+fn nand_not(node: Node) -> Node {
+    match node {
+        Node::Nand(l, r) if l == r || *r == Node::Constant(true) => *l,
+        leaf if is_leaf(&leaf) => Node::nand(leaf.clone(), leaf),
+        other => Node::nand(other, Node::constant(true)),
+    }
+}
+
+fn nand_and(left: Node, right: Node) -> Node {
+    nand_not(Node::nand(left, right))
+}
+
+fn nand_or(left: Node, right: Node) -> Node {
+    Node::nand(nand_not(left), nand_not(right))
+}
+
+// Forma de 4 compuertas: t = A NAND B; (A NAND t) NAND (B NAND t)
+fn nand_xor(left: Node, right: Node) -> Node {
+    let t = Node::nand(left.clone(), right.clone());
+    Node::nand(Node::nand(left, t.clone()), Node::nand(right, t))
+}
+
+// --- Construcciones con NOR (duales de las anteriores) ---
+
+fn nor_not(node: Node) -> Node {
+    match node {
+        Node::Nor(l, r) if l == r || *r == Node::Constant(false) => *l,
+        leaf if is_leaf(&leaf) => Node::nor(leaf.clone(), leaf),
+        other => Node::nor(other, Node::constant(false)),
+    }
+}
+
+fn nor_or(left: Node, right: Node) -> Node {
+    nor_not(Node::nor(left, right))
+}
+
+fn nor_and(left: Node, right: Node) -> Node {
+    Node::nor(nor_not(left), nor_not(right))
+}
+
+// Forma de 4 compuertas: t = A NOR B; (A NOR t) NOR (B NOR t) = A ↔ B
+fn nor_xnor(left: Node, right: Node) -> Node {
+    let t = Node::nor(left.clone(), right.clone());
+    Node::nor(Node::nor(left, t.clone()), Node::nor(right, t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uses_only(node: &Node, gate: fn(&Node) -> bool) -> bool {
+        match node {
+            Node::Variable(_) | Node::Constant(_) => true,
+            Node::Nand(l, r) | Node::Nor(l, r) if gate(node) => uses_only(l, gate) && uses_only(r, gate),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_basic_substitutions() {
+        let a = || Node::var("A");
+        let b = || Node::var("B");
+
+        assert_eq!(to_nand_only(&Node::not(a())), Node::nand(a(), a()));
+        assert_eq!(to_nand_only(&Node::and(a(), b())), nand_not(Node::nand(a(), b())));
+        assert_eq!(to_nor_only(&Node::or(a(), b())), nor_not(Node::nor(a(), b())));
+        // Sin dobles negaciones: ~~A vuelve a ser A
+        assert_eq!(to_nand_only(&Node::not(Node::not(a()))), a());
+        assert_eq!(to_nor_only(&Node::not(Node::not(a()))), a());
+    }
+
+    #[test]
+    fn test_single_gate_type() {
+        let expr = Node::implies(
+            Node::xor(Node::var("A"), Node::not(Node::var("B"))),
+            Node::iff(Node::var("C"), Node::and(Node::var("A"), Node::constant(true))),
+        );
+        assert!(uses_only(&to_nand_only(&expr), |n| matches!(n, Node::Nand(..))));
+        assert!(uses_only(&to_nor_only(&expr), |n| matches!(n, Node::Nor(..))));
+    }
+
+    fn chain(count: usize, op: fn(Node, Node) -> Node) -> Node {
+        (1..count).fold(Node::var("x0"), |acc, i| op(acc, Node::var(&format!("x{}", i))))
+    }
+
+    fn same_truth_table(a: &Node, b: &Node, count: usize) -> bool {
+        let names: Vec<String> = (0..count).map(|i| format!("x{}", i)).collect();
+        (0..1u32 << count).all(|mask| {
+            let values = names.iter().enumerate()
+                .map(|(i, name)| (name.as_str(), mask & (1 << i) != 0))
+                .collect();
+            a.evaluate(&values) == b.evaluate(&values)
+        })
+    }
+
+    #[test]
+    fn test_output_size_stays_bounded() {
+        // Sin compartir subárboles, 16 variables llegaban a ~1M (AND) y ~430M (XOR) caracteres
+        for (expr, limit) in [(chain(16, Node::and), 1_000), (chain(16, Node::xor), 20_000), (chain(16, Node::iff), 20_000)] {
+            let nand = to_nand_only(&expr).to_string();
+            let nor = to_nor_only(&expr).to_string();
+            assert!(nand.len() < limit, "NAND: {} caracteres", nand.len());
+            assert!(nor.len() < limit, "NOR: {} caracteres", nor.len());
+        }
+    }
+
+    #[test]
+    fn test_rewrite_preserves_truth_table() {
+        for expr in [chain(6, Node::and), chain(6, Node::or), chain(6, Node::xor), chain(6, Node::iff)] {
+            assert!(same_truth_table(&expr, &to_nand_only(&expr), 6), "{}", expr);
+            assert!(same_truth_table(&expr, &to_nor_only(&expr), 6), "{}", expr);
+        }
+    }
+}
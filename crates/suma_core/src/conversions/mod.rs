@@ -1,3 +1,4 @@
 pub mod number_converter;
 
-pub use number_converter::model::NumberConverter;
\ No newline at end of file
+pub use number_converter::model::{Ieee754Parts, NumberConverter};
+pub use number_converter::error::ConversionError;
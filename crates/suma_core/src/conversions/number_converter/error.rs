@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum ConversionError {
+    #[error("Base inválida: {0} (debe estar entre 2 y 36)")]
+    InvalidRadix(u32),
+
+    #[error("La cadena no puede estar vacía")]
+    Empty,
+
+    #[error("Dígito '{digit}' inválido en base {radix}")]
+    InvalidDigit { digit: char, radix: u32 },
+
+    #[error("El valor no cabe en 64 bits")]
+    Overflow,
+
+    #[error("Ancho de bits inválido: {0} (debe estar entre 1 y 64)")]
+    InvalidBitWidth(u32),
+
+    #[error("{value} no cabe en {bits} bits con complemento a dos")]
+    DoesNotFit { value: i64, bits: u32 },
+
+    #[error("{0} no tiene representación como fracción")]
+    NotFinite(f64),
+
+    #[error("{0} está fuera del rango de los números romanos (1 a 3999)")]
+    RomanOutOfRange(u32),
+
+    #[error("Número romano mal formado: '{0}'")]
+    InvalidRoman(String),
+}
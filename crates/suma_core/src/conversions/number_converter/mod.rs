@@ -1 +1,4 @@
 pub mod model;
+pub mod error;
+
+pub use error::ConversionError;
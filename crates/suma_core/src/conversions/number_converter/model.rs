@@ -1,3 +1,36 @@
+use num_rational::Ratio;
+use super::error::ConversionError;
+
+/// Campos de bits de un `f64` según IEEE-754 (binary64).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ieee754Parts {
+    /// 1 bit: 0 positivo, 1 negativo.
+    pub sign: u8,
+    /// 11 bits, con sesgo 1023.
+    pub exponent: u16,
+    /// 52 bits de la fracción (sin el 1 implícito).
+    pub mantissa: u64,
+}
+
+impl Ieee754Parts {
+    /// Exponente real (sin sesgo). Para subnormales es -1022.
+    pub fn unbiased_exponent(&self) -> i32 {
+        if self.exponent == 0 { -1022 } else { self.exponent as i32 - 1023 }
+    }
+
+    /// Los 64 bits agrupados como `signo exponente mantisa`.
+    pub fn to_bit_string(&self) -> String {
+        format!("{:01b} {:011b} {:052b}", self.sign, self.exponent, self.mantissa)
+    }
+}
+
+/// Símbolos romanos con las formas sustractivas, de mayor a menor.
+const ROMAN_SYMBOLS: [(u32, &str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"),
+    (100, "C"), (90, "XC"), (50, "L"), (40, "XL"),
+    (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+];
+
 #[derive(Debug, Clone)]
 pub struct NumberConverter {
     pub value: i64,
@@ -33,6 +66,170 @@ impl NumberConverter {
         letters
     }
 
+    /// Representación de `value` en cualquier base entre 2 y 36 (dígitos `0-9a-z`).
+    pub fn to_base(value: u64, radix: u32) -> Result<String, ConversionError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ConversionError::InvalidRadix(radix));
+        }
+        if value == 0 {
+            return Ok(String::from("0"));
+        }
+
+        let mut digits = Vec::new();
+        let mut num = value;
+        while num > 0 {
+            let digit = (num % radix as u64) as u32;
+            digits.push(std::char::from_digit(digit, radix).unwrap_or('?'));
+            num /= radix as u64;
+        }
+        Ok(digits.iter().rev().collect())
+    }
+
+    /// Interpreta `s` como un número en base `radix` (2 a 36, sin distinguir mayúsculas).
+    pub fn from_base(s: &str, radix: u32) -> Result<u64, ConversionError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ConversionError::InvalidRadix(radix));
+        }
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+
+        s.chars().try_fold(0u64, |acc, c| {
+            let digit = c.to_digit(radix).ok_or(ConversionError::InvalidDigit { digit: c, radix })?;
+            acc.checked_mul(radix as u64)
+                .and_then(|acc| acc.checked_add(digit as u64))
+                .ok_or(ConversionError::Overflow)
+        })
+    }
+
+    /// Representación binaria de ancho fijo en complemento a dos.
+    /// Falla si `value` no está en el rango `[-2^(bits-1), 2^(bits-1) - 1]`.
+    pub fn twos_complement(value: i64, bits: u32) -> Result<String, ConversionError> {
+        if !(1..=64).contains(&bits) {
+            return Err(ConversionError::InvalidBitWidth(bits));
+        }
+        let min = i64::MIN >> (64 - bits);
+        let max = i64::MAX >> (64 - bits);
+        if value < min || value > max {
+            return Err(ConversionError::DoesNotFit { value, bits });
+        }
+
+        let mask = u64::MAX >> (64 - bits);
+        Ok(format!("{:0width$b}", value as u64 & mask, width = bits as usize))
+    }
+
+    /// Descompone un `f64` en sus campos de signo, exponente y mantisa.
+    pub fn ieee754_breakdown(value: f64) -> Ieee754Parts {
+        let bits = value.to_bits();
+        Ieee754Parts {
+            sign: (bits >> 63) as u8,
+            exponent: ((bits >> 52) & 0x7FF) as u16,
+            mantissa: bits & ((1u64 << 52) - 1),
+        }
+    }
+
+    /// Mejor aproximación racional de `value` con denominador a lo sumo `max_denominator`,
+    /// por fracciones continuas (convergentes y semiconvergentes). Queda en términos mínimos
+    /// con el signo en el numerador.
+    pub fn to_fraction(value: f64, max_denominator: u64) -> Result<(i64, i64), ConversionError> {
+        if !value.is_finite() {
+            return Err(ConversionError::NotFinite(value));
+        }
+        if value.abs() >= i64::MAX as f64 {
+            return Err(ConversionError::Overflow);
+        }
+        let max_den = max_denominator.clamp(1, i64::MAX as u64) as i128;
+
+        // (p0/q0, p1/q1): los dos últimos convergentes
+        let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1i128, 1i128, 0i128);
+        let mut x = value.abs();
+        loop {
+            let a = x.floor() as i128;
+            let q2 = a.checked_mul(q1).and_then(|v| v.checked_add(q0)).unwrap_or(i128::MAX);
+            let p2 = a.checked_mul(p1).and_then(|v| v.checked_add(p0)).unwrap_or(i128::MAX);
+            if q2 > max_den || p2 > i64::MAX as i128 {
+                // Semiconvergente: el mayor paso intermedio que respeta las cotas
+                let k = ((max_den - q0) / q1).min((i64::MAX as i128 - p0) / p1.max(1));
+                let (ps, qs) = (p0 + k * p1, q0 + k * q1);
+                let target = value.abs();
+                if (ps as f64 / qs as f64 - target).abs() < (p1 as f64 / q1 as f64 - target).abs() {
+                    p1 = ps;
+                    q1 = qs;
+                }
+                break;
+            }
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+
+            let frac = x - a as f64;
+            if frac == 0.0 {
+                break;
+            }
+            x = 1.0 / frac;
+        }
+
+        let numerator = if value < 0.0 { -p1 } else { p1 };
+        Self::simplify_fraction(numerator as i64, q1 as i64)
+    }
+
+    /// Reduce `num/den` a términos mínimos con el denominador positivo.
+    /// Un denominador nulo se devuelve sin cambios. Se normaliza en `i128` porque
+    /// cambiar el signo de `i64::MIN` desborda; si el resultado no cabe en `i64`
+    /// (ej: `i64::MIN / -1`) devuelve `ConversionError::Overflow`.
+    pub fn simplify_fraction(num: i64, den: i64) -> Result<(i64, i64), ConversionError> {
+        if den == 0 {
+            return Ok((num, den));
+        }
+        let ratio = Ratio::new(num as i128, den as i128);
+        let narrow = |v: i128| i64::try_from(v).map_err(|_| ConversionError::Overflow);
+        Ok((narrow(*ratio.numer())?, narrow(*ratio.denom())?))
+    }
+
+    /// Número romano en notación sustractiva estándar, para 1 a 3999.
+    pub fn to_roman(n: u32) -> Result<String, ConversionError> {
+        if !(1..=3999).contains(&n) {
+            return Err(ConversionError::RomanOutOfRange(n));
+        }
+
+        let mut remaining = n;
+        let mut roman = String::new();
+        for &(value, symbol) in &ROMAN_SYMBOLS {
+            while remaining >= value {
+                roman.push_str(symbol);
+                remaining -= value;
+            }
+        }
+        Ok(roman)
+    }
+
+    /// Valor de un número romano bien formado (sin distinguir mayúsculas).
+    /// Rechaza formas no canónicas como `IIII`, `VX` o `IC`.
+    pub fn from_roman(s: &str) -> Result<u32, ConversionError> {
+        let upper = s.trim().to_uppercase();
+        let invalid = || ConversionError::InvalidRoman(s.to_string());
+        if upper.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut value = 0;
+        let mut rest = upper.as_str();
+        for &(symbol_value, symbol) in &ROMAN_SYMBOLS {
+            while let Some(tail) = rest.strip_prefix(symbol) {
+                value += symbol_value;
+                rest = tail;
+            }
+        }
+        if !rest.is_empty() {
+            return Err(invalid());
+        }
+
+        // La lectura voraz acepta repeticiones como IIII: solo vale si es la forma canónica
+        match Self::to_roman(value) {
+            Ok(canonical) if canonical == upper => Ok(value),
+            _ => Err(invalid()),
+        }
+    }
+
     // Métodos que agregan al historial (modifican estado)
     pub fn to_binary(&mut self) -> String {
         let binary = self.as_binary();
@@ -61,4 +258,137 @@ impl NumberConverter {
     pub fn history_cloned(&self) -> Vec<String> {
         self.history.clone()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_base() {
+        assert_eq!(NumberConverter::to_base(255, 16).unwrap(), "ff");
+        assert_eq!(NumberConverter::to_base(255, 2).unwrap(), "11111111");
+        assert_eq!(NumberConverter::to_base(144, 12).unwrap(), "100");
+        assert_eq!(NumberConverter::to_base(35, 36).unwrap(), "z");
+        assert_eq!(NumberConverter::to_base(0, 8).unwrap(), "0");
+        assert_eq!(NumberConverter::to_base(u64::MAX, 36).unwrap(), "3w5e11264sgsf");
+        assert_eq!(NumberConverter::to_base(10, 37), Err(ConversionError::InvalidRadix(37)));
+    }
+
+    #[test]
+    fn test_from_base() {
+        assert_eq!(NumberConverter::from_base("FF", 16), Ok(255));
+        assert_eq!(NumberConverter::from_base("b0", 12), Ok(132));
+        assert_eq!(NumberConverter::from_base("3w5e11264sgsf", 36), Ok(u64::MAX));
+        assert_eq!(NumberConverter::from_base("", 10), Err(ConversionError::Empty));
+        assert_eq!(
+            NumberConverter::from_base("102", 2),
+            Err(ConversionError::InvalidDigit { digit: '2', radix: 2 })
+        );
+        assert_eq!(NumberConverter::from_base("3w5e11264sgsg", 36), Err(ConversionError::Overflow));
+        assert_eq!(NumberConverter::from_base("1", 1), Err(ConversionError::InvalidRadix(1)));
+    }
+
+    #[test]
+    fn test_twos_complement() {
+        assert_eq!(NumberConverter::twos_complement(5, 8).unwrap(), "00000101");
+        assert_eq!(NumberConverter::twos_complement(-1, 8).unwrap(), "11111111");
+        assert_eq!(NumberConverter::twos_complement(-128, 8).unwrap(), "10000000");
+        assert_eq!(NumberConverter::twos_complement(127, 8).unwrap(), "01111111");
+        assert_eq!(NumberConverter::twos_complement(i64::MIN, 64).unwrap(), format!("1{}", "0".repeat(63)));
+
+        assert_eq!(NumberConverter::twos_complement(128, 8), Err(ConversionError::DoesNotFit { value: 128, bits: 8 }));
+        assert_eq!(NumberConverter::twos_complement(-129, 8), Err(ConversionError::DoesNotFit { value: -129, bits: 8 }));
+        assert_eq!(NumberConverter::twos_complement(0, 0), Err(ConversionError::InvalidBitWidth(0)));
+    }
+
+    #[test]
+    fn test_ieee754_breakdown() {
+        // -6.25 = -1.5625 · 2^2
+        let parts = NumberConverter::ieee754_breakdown(-6.25);
+        assert_eq!(parts.sign, 1);
+        assert_eq!(parts.exponent, 1025);
+        assert_eq!(parts.unbiased_exponent(), 2);
+        assert_eq!(parts.mantissa, 0x9_0000_0000_0000);
+
+        let one = NumberConverter::ieee754_breakdown(1.0);
+        assert_eq!((one.sign, one.exponent, one.mantissa), (0, 1023, 0));
+        assert_eq!(one.to_bit_string(), format!("0 01111111111 {}", "0".repeat(52)));
+
+        let subnormal = NumberConverter::ieee754_breakdown(f64::from_bits(1));
+        assert_eq!((subnormal.exponent, subnormal.mantissa), (0, 1));
+        assert_eq!(subnormal.unbiased_exponent(), -1022);
+    }
+
+    #[test]
+    fn test_to_fraction() {
+        assert_eq!(NumberConverter::to_fraction(0.75, 100), Ok((3, 4)));
+        assert_eq!(NumberConverter::to_fraction(-0.125, 1000), Ok((-1, 8)));
+        assert_eq!(NumberConverter::to_fraction(1.0 / 3.0, 1000), Ok((1, 3)));
+        assert_eq!(NumberConverter::to_fraction(0.1, 1_000_000), Ok((1, 10)));
+        assert_eq!(NumberConverter::to_fraction(5.0, 10), Ok((5, 1)));
+        // Aproximaciones clásicas de pi: 22/7 y 355/113
+        assert_eq!(NumberConverter::to_fraction(std::f64::consts::PI, 10), Ok((22, 7)));
+        assert_eq!(NumberConverter::to_fraction(std::f64::consts::PI, 1000), Ok((355, 113)));
+        // Con cota 100 el siguiente convergente (333/106) no entra, pero sí el semiconvergente 311/99
+        assert_eq!(NumberConverter::to_fraction(std::f64::consts::PI, 100), Ok((311, 99)));
+        assert!(matches!(NumberConverter::to_fraction(f64::NAN, 10), Err(ConversionError::NotFinite(_))));
+        assert_eq!(NumberConverter::to_fraction(1e20, 10), Err(ConversionError::Overflow));
+        // El numerador también debe caber en i64
+        let (num, den) = NumberConverter::to_fraction(1e18 + 0.5, u64::MAX).unwrap();
+        assert_eq!(num as f64 / den as f64, 1e18);
+    }
+
+    #[test]
+    fn test_simplify_fraction() {
+        assert_eq!(NumberConverter::simplify_fraction(6, 8), Ok((3, 4)));
+        assert_eq!(NumberConverter::simplify_fraction(3, -9), Ok((-1, 3)));
+        assert_eq!(NumberConverter::simplify_fraction(0, 5), Ok((0, 1)));
+        assert_eq!(NumberConverter::simplify_fraction(4, 0), Ok((4, 0)));
+    }
+
+    #[test]
+    fn test_simplify_fraction_extremes() {
+        assert_eq!(NumberConverter::simplify_fraction(i64::MIN, -1), Err(ConversionError::Overflow));
+        // -5 / 2^63: el denominador positivo no cabe en i64
+        assert_eq!(NumberConverter::simplify_fraction(5, i64::MIN), Err(ConversionError::Overflow));
+        assert_eq!(NumberConverter::simplify_fraction(6, i64::MIN), Ok((-3, 1 << 62)));
+        assert_eq!(NumberConverter::simplify_fraction(i64::MIN, 2), Ok((i64::MIN / 2, 1)));
+        assert_eq!(NumberConverter::simplify_fraction(i64::MIN, i64::MIN), Ok((1, 1)));
+    }
+
+    #[test]
+    fn test_roman_numerals() {
+        assert_eq!(NumberConverter::to_roman(1994).unwrap(), "MCMXCIV");
+        assert_eq!(NumberConverter::to_roman(3999).unwrap(), "MMMCMXCIX");
+        assert_eq!(NumberConverter::to_roman(4).unwrap(), "IV");
+        assert_eq!(NumberConverter::to_roman(0), Err(ConversionError::RomanOutOfRange(0)));
+        assert_eq!(NumberConverter::to_roman(4000), Err(ConversionError::RomanOutOfRange(4000)));
+
+        assert_eq!(NumberConverter::from_roman("MCMXCIV"), Ok(1994));
+        assert_eq!(NumberConverter::from_roman("xlii"), Ok(42));
+        for bad in ["IIII", "VX", "IC", "MMMM", "VV", "IIV", "ABC", ""] {
+            assert_eq!(
+                NumberConverter::from_roman(bad),
+                Err(ConversionError::InvalidRoman(bad.to_string())),
+                "{}",
+                bad
+            );
+        }
+
+        for n in 1..=3999 {
+            let roman = NumberConverter::to_roman(n).unwrap();
+            assert_eq!(NumberConverter::from_roman(&roman), Ok(n));
+        }
+    }
+
+    #[test]
+    fn test_base_round_trip() {
+        for radix in 2..=36 {
+            for value in [1, 7, 1000, 123_456_789] {
+                let text = NumberConverter::to_base(value, radix).unwrap();
+                assert_eq!(NumberConverter::from_base(&text, radix), Ok(value));
+            }
+        }
+    }
+}
@@ -48,13 +48,12 @@ where
         closed_set.insert(current.clone());
 
         for neighbor in graph.neighbors(current.clone()) {
-            if closed_set.contains(&neighbor) {
-                continue;
-            }
-
             if let Some(edge_w) = graph.edge_weight(current.clone(), neighbor.clone()) {
                 let tentative_g = g_score[&current] + edge_w;
                 if tentative_g < *g_score.get(&neighbor).unwrap_or(&G::EdgeData::inf()) {
+                    // Con heurísticas admisibles pero inconsistentes un nodo cerrado
+                    // puede mejorar: se reabre para no devolver un camino subóptimo.
+                    closed_set.remove(&neighbor);
                     came_from.insert(neighbor.clone(), current.clone());
                     g_score.insert(neighbor.clone(), tentative_g.clone());
                     let f_score = tentative_g + heuristic(&neighbor, &goal);
@@ -117,13 +116,12 @@ where
         closed_set.insert(current.clone());
 
         for neighbor in graph.neighbors(current.clone()) {
-            if closed_set.contains(&neighbor) {
-                continue;
-            }
-
             if let Some(edge_w) = graph.edge_weight(current.clone(), neighbor.clone()) {
                 let tentative_g = g_score[&current] + edge_w;
                 if tentative_g < *g_score.get(&neighbor).unwrap_or(&G::EdgeData::inf()) {
+                    // Con heurísticas admisibles pero inconsistentes un nodo cerrado
+                    // puede mejorar: se reabre para no devolver un camino subóptimo.
+                    closed_set.remove(&neighbor);
                     came_from.insert(neighbor.clone(), current.clone());
                     g_score.insert(neighbor.clone(), tentative_g.clone());
                     let f_score = tentative_g + heuristic(&neighbor, &goal);
@@ -137,6 +135,93 @@ where
 }
 
 
+/// Resultado detallado de `a_star_search`.
+#[derive(Debug, Clone)]
+pub struct AStarResult<N, W> {
+    /// Camino encontrado y su costo, si el objetivo es alcanzable.
+    pub path: Option<(Vec<N>, W)>,
+    /// Nodos expandidos (sacados de la frontera y procesados).
+    pub expanded: usize,
+    /// Orden en que se expandieron los nodos.
+    pub traversal_order: Vec<N>,
+    /// Veces que un nodo ya cerrado se reabrió al encontrar un camino más barato.
+    /// Con una heurística consistente siempre es 0.
+    pub reopened: usize,
+}
+
+impl<N, W> AStarResult<N, W> {
+    /// Indica si la heurística se comportó como consistente en esta búsqueda.
+    pub fn heuristic_consistent(&self) -> bool {
+        self.reopened == 0
+    }
+}
+
+/// Versión unificada de A*: además del camino reporta nodos expandidos y reaperturas.
+/// Los nodos cerrados se reabren si aparece un `g` menor, así que el camino es óptimo
+/// con cualquier heurística admisible. Con `early_exit = false` la búsqueda sigue hasta
+/// vaciar la frontera en lugar de detenerse al expandir el objetivo.
+pub fn a_star_search<G, F>(
+    graph: &G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    heuristic: F,
+    early_exit: bool,
+) -> AStarResult<G::NodeId, G::EdgeData>
+where
+    G: WeightedGraph,
+    G::EdgeData: Weight,
+    G::NodeId: Clone + Eq + std::hash::Hash + Ord,
+    F: Fn(&G::NodeId, &G::NodeId) -> G::EdgeData,
+{
+    let mut open_set = BinaryHeap::new();
+    let mut came_from: HashMap<G::NodeId, G::NodeId> = HashMap::new();
+    let mut g_score: HashMap<G::NodeId, G::EdgeData> = HashMap::new();
+    let mut closed_set: HashSet<G::NodeId> = HashSet::new();
+    let mut traversal_order = Vec::new();
+    let mut reopened = 0;
+
+    g_score.insert(start, G::EdgeData::zero());
+    open_set.push((Reverse(heuristic(&start, &goal)), start));
+
+    while let Some((Reverse(_f), current)) = open_set.pop() {
+        // Entradas viejas de nodos ya cerrados
+        if !closed_set.insert(current) {
+            continue;
+        }
+        traversal_order.push(current);
+
+        if early_exit && current == goal {
+            break;
+        }
+
+        for neighbor in graph.neighbors(current) {
+            if let Some(edge_w) = graph.edge_weight(current, neighbor) {
+                let tentative_g = g_score[&current] + edge_w;
+                if tentative_g < *g_score.get(&neighbor).unwrap_or(&G::EdgeData::inf()) {
+                    if closed_set.remove(&neighbor) {
+                        reopened += 1;
+                    }
+                    came_from.insert(neighbor, current);
+                    g_score.insert(neighbor, tentative_g);
+                    open_set.push((Reverse(tentative_g + heuristic(&neighbor, &goal)), neighbor));
+                }
+            }
+        }
+    }
+
+    let path = g_score.get(&goal).map(|&cost| {
+        let mut path = vec![goal];
+        let mut node = goal;
+        while let Some(&prev) = came_from.get(&node) {
+            path.push(prev);
+            node = prev;
+        }
+        path.reverse();
+        (path, cost)
+    });
+
+    AStarResult { path, expanded: traversal_order.len(), traversal_order, reopened }
+}
 
 #[cfg(test)]
 mod tests {
@@ -267,4 +352,68 @@ struct Point {
         assert!(traversal_order.contains(&nodes[3]));
     }
 
+    // S -1- A -3- C -3- G,  S -2- B -1- C
+    // h(B) = 4 es admisible (d(B, G) = 4) pero no consistente: h(S) = 0 < 2 + 4.
+    // A* expande C por A (g = 4) antes de descubrir el camino por B (g = 3).
+    fn setup_inconsistent() -> (UndirectedWeightedGraph<&'static str, i32>, impl Fn(&usize, &usize) -> i32) {
+        let mut graph = UndirectedWeightedGraph::new();
+        let s = graph.base.add_node("S");
+        let a = graph.base.add_node("A");
+        let b = graph.base.add_node("B");
+        let c = graph.base.add_node("C");
+        let g = graph.base.add_node("G");
+        graph.add_edge_id(s, a, 1);
+        graph.add_edge_id(s, b, 2);
+        graph.add_edge_id(a, c, 3);
+        graph.add_edge_id(b, c, 1);
+        graph.add_edge_id(c, g, 3);
+
+        let heuristic = move |n: &usize, _goal: &usize| if *n == b { 4 } else { 0 };
+        (graph, heuristic)
+    }
+
+    #[test]
+    fn test_a_star_search_detects_reopen() {
+        let (graph, heuristic) = setup_inconsistent();
+        let result = a_star_search(&graph, 0, 4, heuristic, true);
+
+        assert!(result.reopened > 0);
+        assert!(!result.heuristic_consistent());
+        assert_eq!(result.path, Some((vec![0, 2, 3, 4], 6)));
+        assert_eq!(result.expanded, result.traversal_order.len());
+    }
+
+    #[test]
+    fn test_a_star_search_consistent_heuristic() {
+        let (graph, nodes) = setup_graph_int();
+        let result = a_star_search(&graph, nodes[0], nodes[3], |_: &usize, _: &usize| 0, true);
+
+        assert!(result.heuristic_consistent());
+        assert_eq!(result.path, Some((vec![nodes[0], nodes[1], nodes[2], nodes[3]], 4)));
+
+        // Sin salida temprana se expande todo lo alcanzable
+        let full = a_star_search(&graph, nodes[0], nodes[3], |_: &usize, _: &usize| 0, false);
+        assert_eq!(full.expanded, 4);
+        assert_eq!(full.path, result.path);
+
+        // Objetivo inalcanzable: sin camino, pero con conteo
+        let mut isolated = graph.clone();
+        let lonely = isolated.base.add_node("E");
+        let none = a_star_search(&isolated, nodes[0], lonely, |_: &usize, _: &usize| 0, true);
+        assert!(none.path.is_none());
+        assert_eq!(none.expanded, 4);
+    }
+
+    #[test]
+    fn test_a_star_reopens_closed_nodes() {
+        // Regresión: antes se ignoraba C al estar cerrado y se devolvía S-A-C-G (7)
+        let (graph, heuristic) = setup_inconsistent();
+        let (path, cost) = a_star_algorithm(&graph, 0, 4, &heuristic).unwrap();
+        assert_eq!(cost, 6);
+        assert_eq!(path, vec![0, 2, 3, 4]);
+
+        let (path, cost, _) = a_star_traversal(&graph, 0, 4, &heuristic).unwrap();
+        assert_eq!(cost, 6);
+        assert_eq!(path, vec![0, 2, 3, 4]);
+    }
 }
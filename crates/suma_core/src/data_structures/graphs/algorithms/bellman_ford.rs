@@ -0,0 +1,142 @@
+use crate::data_structures::graphs::{WeightedGraph, weighted::Weight};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use num_traits::Zero;
+
+/// Ciclo de peso negativo alcanzable desde el origen. `cycle` lista los nodos en
+/// el sentido de las aristas (el último vuelve al primero).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle<N> {
+    pub cycle: Vec<N>,
+}
+
+impl<N: fmt::Debug> fmt::Display for NegativeCycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ciclo de peso negativo: {:?}", self.cycle)
+    }
+}
+
+impl<N: fmt::Debug> std::error::Error for NegativeCycle<N> {}
+
+/// Caminos mínimos desde `source` admitiendo pesos negativos.
+///
+/// Relaja todas las aristas V-1 veces; si en la pasada V todavía mejora alguna
+/// distancia hay un ciclo negativo. Devuelve distancias y predecesores con la
+/// misma forma que `dijkstra_tree` (los nodos inalcanzables quedan en `inf()`).
+pub fn bellman_ford<G>(
+    graph: &G,
+    source: G::NodeId,
+) -> Result<(HashMap<G::NodeId, G::EdgeData>, HashMap<G::NodeId, G::NodeId>), NegativeCycle<G::NodeId>>
+where
+    G: WeightedGraph,
+    G::EdgeData: Weight,
+    G::NodeId: Clone + Eq + Ord,
+{
+    let nodes = graph.nodes();
+    let mut distances: HashMap<G::NodeId, G::EdgeData> = nodes
+        .iter()
+        .map(|&node| (node, if node == source { G::EdgeData::zero() } else { G::EdgeData::inf() }))
+        .collect();
+    let mut previous: HashMap<G::NodeId, G::NodeId> = HashMap::new();
+
+    // Vecinos + peso: en los grafos no dirigidos cada arista se recorre en ambos sentidos
+    let arcs: Vec<(G::NodeId, G::NodeId, G::EdgeData)> = nodes
+        .iter()
+        .flat_map(|&from| {
+            graph.neighbors(from)
+                .into_iter()
+                .filter_map(move |to| graph.edge_weight(from, to).map(|w| (from, to, w)))
+        })
+        .collect();
+
+    for pass in 0..nodes.len() {
+        let mut last_relaxed = None;
+        for &(from, to, weight) in &arcs {
+            let current = distances[&from];
+            // Sin sumar a `inf()`: en enteros desbordaría
+            if current == G::EdgeData::inf() {
+                continue;
+            }
+            let candidate = current + weight;
+            if candidate < distances[&to] {
+                distances.insert(to, candidate);
+                previous.insert(to, from);
+                last_relaxed = Some(to);
+            }
+        }
+
+        match last_relaxed {
+            None => return Ok((distances, previous)),
+            Some(node) if pass + 1 == nodes.len() => {
+                return Err(NegativeCycle { cycle: extract_cycle(&previous, node, nodes.len()) });
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok((distances, previous))
+}
+
+// Retrocede V pasos (con eso seguro se está dentro del ciclo) y lo recorre
+fn extract_cycle<N: Copy + Eq + std::hash::Hash>(previous: &HashMap<N, N>, start: N, node_count: usize) -> Vec<N> {
+    let mut node = start;
+    for _ in 0..node_count {
+        node = previous[&node];
+    }
+
+    let mut cycle = vec![node];
+    let mut seen = HashSet::from([node]);
+    let mut current = previous[&node];
+    while seen.insert(current) {
+        cycle.push(current);
+        current = previous[&current];
+    }
+    cycle.reverse();
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::graphs::DirectedWeightedGraph;
+
+    #[test]
+    fn test_negative_edges_without_cycle() {
+        let (mut graph, ids) = DirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("s", "a", 4),
+            ("s", "b", 5),
+            ("b", "a", -3),
+            ("a", "t", 2),
+        ]);
+        let isolated = graph.add_node("z");
+
+        let (distances, parents) = bellman_ford(&graph, ids["s"]).unwrap();
+        assert_eq!(distances[&ids["a"]], 2);
+        assert_eq!(distances[&ids["t"]], 4);
+        assert_eq!(distances[&isolated], i32::MAX);
+        assert_eq!(parents[&ids["a"]], ids["b"]);
+        assert_eq!(parents[&ids["t"]], ids["a"]);
+    }
+
+    #[test]
+    fn test_negative_cycle_is_reported() {
+        // Arbitraje: a -> b -> c -> a suma -1
+        let (graph, ids) = DirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("s", "a", 1),
+            ("a", "b", 2),
+            ("b", "c", -4),
+            ("c", "a", 1),
+        ]);
+
+        let err = bellman_ford(&graph, ids["s"]).unwrap_err();
+        let mut cycle = err.cycle.clone();
+        cycle.sort();
+        let mut expected = vec![ids["a"], ids["b"], ids["c"]];
+        expected.sort();
+        assert_eq!(cycle, expected);
+
+        // En el sentido de las aristas
+        let position = err.cycle.iter().position(|&n| n == ids["a"]).unwrap();
+        assert_eq!(err.cycle[(position + 1) % 3], ids["b"]);
+    }
+}
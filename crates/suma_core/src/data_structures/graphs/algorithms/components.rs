@@ -0,0 +1,123 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::data_structures::graphs::GraphBase;
+
+/// Componentes conexas por inundación BFS sobre `neighbors`.
+/// Cada componente lista sus nodos en el orden en que se alcanzaron.
+pub fn connected_components<G>(graph: &G) -> Vec<Vec<G::NodeId>>
+where
+    G: GraphBase,
+{
+    let mut visited = HashSet::new();
+    let mut components = Vec::new();
+
+    for start in graph.nodes() {
+        if !visited.insert(start) {
+            continue;
+        }
+
+        let mut component = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            component.push(current);
+            for neighbor in graph.neighbors(current) {
+                if visited.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        components.push(component);
+    }
+
+    components
+}
+
+/// Bipartición del grafo por 2-coloreo BFS. Devuelve las dos clases de color,
+/// o `None` si hay un ciclo impar (incluido un bucle sobre un nodo).
+pub fn is_bipartite<G>(graph: &G) -> Option<(HashSet<G::NodeId>, HashSet<G::NodeId>)>
+where
+    G: GraphBase,
+{
+    let mut color: HashMap<G::NodeId, bool> = HashMap::new();
+
+    for start in graph.nodes() {
+        if color.contains_key(&start) {
+            continue;
+        }
+        color.insert(start, false);
+
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            let side = color[&current];
+            for neighbor in graph.neighbors(current) {
+                match color.get(&neighbor) {
+                    Some(&other) if other == side => return None,
+                    Some(_) => {}
+                    None => {
+                        color.insert(neighbor, !side);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut left = HashSet::new();
+    let mut right = HashSet::new();
+    for (node, side) in color {
+        if side {
+            right.insert(node);
+        } else {
+            left.insert(node);
+        }
+    }
+    Some((left, right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::graphs::UndirectedWeightedGraph;
+
+    #[test]
+    fn test_connected_components() {
+        let (mut graph, ids) = UndirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("A", "B", 1),
+            ("B", "C", 1),
+            ("D", "E", 1),
+        ]);
+        let isolated = graph.add_node("F");
+
+        let mut components: Vec<HashSet<usize>> = connected_components(&graph)
+            .into_iter()
+            .map(|c| c.into_iter().collect())
+            .collect();
+        components.sort_by_key(|c| std::cmp::Reverse(c.len()));
+
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[0], HashSet::from([ids["A"], ids["B"], ids["C"]]));
+        assert_eq!(components[1], HashSet::from([ids["D"], ids["E"]]));
+        assert_eq!(components[2], HashSet::from([isolated]));
+    }
+
+    #[test]
+    fn test_is_bipartite() {
+        // Ciclo par: bipartito
+        let (square, ids) = UndirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("A", "B", 1),
+            ("B", "C", 1),
+            ("C", "D", 1),
+            ("D", "A", 1),
+        ]);
+        let (left, right) = is_bipartite(&square).unwrap();
+        let (ac, bd) = (HashSet::from([ids["A"], ids["C"]]), HashSet::from([ids["B"], ids["D"]]));
+        assert!((left == ac && right == bd) || (left == bd && right == ac));
+
+        // Triángulo: ciclo impar
+        let (triangle, _) = UndirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("A", "B", 1),
+            ("B", "C", 1),
+            ("C", "A", 1),
+        ]);
+        assert!(is_bipartite(&triangle).is_none());
+    }
+}
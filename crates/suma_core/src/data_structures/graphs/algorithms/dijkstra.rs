@@ -0,0 +1,261 @@
+use crate::data_structures::graphs::{WeightedGraph, weighted::Weight};
+use std::collections::{BinaryHeap, HashMap};
+use std::cmp::Reverse;
+use num_traits::{Num, Zero, Bounded};
+use ordered_float::OrderedFloat;
+
+pub fn dijkstra_algorithm<G>(graph: &G, source: G::NodeId) -> HashMap<G::NodeId, G::EdgeData>
+where
+    G: WeightedGraph,
+    G::EdgeData: Weight,
+    G::NodeId: Clone + Eq + Ord,
+{
+    let mut distances = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    for node in graph.nodes() {
+        if node == source {
+            distances.insert(node.clone(), G::EdgeData::zero());
+            heap.push((Reverse(G::EdgeData::zero()), node.clone()));
+        } else {
+            distances.insert(node.clone(), G::EdgeData::inf());
+        }
+    }
+
+    while let Some((Reverse(current_dist), node)) = heap.pop() {
+        // Si este valor no es el más reciente, lo saltamos
+        if current_dist > distances[&node] {
+            continue;
+        }
+
+        for neighbor in graph.neighbors(node.clone()) {
+            if let Some(weight) = graph.edge_weight(node.clone(), neighbor.clone()) {
+                let new_dist = current_dist + weight;
+
+                if new_dist < distances[&neighbor] {
+                    distances.insert(neighbor.clone(), new_dist.clone());
+                    heap.push((Reverse(new_dist), neighbor));
+                }
+            }
+        }
+    }
+
+    distances
+}
+
+/// Camino mínimo de `start` a `goal`: secuencia de nodos y costo total, con la
+/// misma firma que `a_star_algorithm` (sin heurística). Devuelve `None` si `goal`
+/// no es alcanzable.
+pub fn dijkstra_path<G>(
+    graph: &G,
+    start: G::NodeId,
+    goal: G::NodeId,
+) -> Option<(Vec<G::NodeId>, G::EdgeData)>
+where
+    G: WeightedGraph,
+    G::EdgeData: Weight,
+    G::NodeId: Clone + Eq + Ord,
+{
+    let mut distances = HashMap::new();
+    let mut came_from: HashMap<G::NodeId, G::NodeId> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    // Inicialización
+    for node in graph.nodes() {
+        distances.insert(node, G::EdgeData::inf());
+    }
+    distances.insert(start, G::EdgeData::zero());
+    heap.push((Reverse(G::EdgeData::zero()), start));
+
+    while let Some((Reverse(current_dist), node)) = heap.pop() {
+        if node == goal {
+            // Reconstrucción del camino
+            let mut path = vec![goal];
+            let mut current = goal;
+            while let Some(&prev) = came_from.get(&current) {
+                path.push(prev);
+                current = prev;
+            }
+            path.reverse();
+            return Some((path, current_dist));
+        }
+
+        if current_dist > distances[&node] {
+            continue;
+        }
+
+        for neighbor in graph.neighbors(node) {
+            if let Some(weight) = graph.edge_weight(node, neighbor) {
+                let new_dist = current_dist + weight;
+
+                if new_dist < distances[&neighbor] {
+                    distances.insert(neighbor, new_dist);
+                    came_from.insert(neighbor, node);
+                    heap.push((Reverse(new_dist), neighbor));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Árbol de caminos mínimos desde `source`. Devuelve las distancias (igual que
+/// `dijkstra_algorithm`) y el mapa de predecesores: cada nodo alcanzable distinto de
+/// `source` apunta a su padre en el árbol, así que el árbol puede reconstruirse
+/// (o visualizarse) como un grafo dirigido padre -> hijo.
+pub fn dijkstra_tree<G>(
+    graph: &G,
+    source: G::NodeId,
+) -> (HashMap<G::NodeId, G::EdgeData>, HashMap<G::NodeId, G::NodeId>)
+where
+    G: WeightedGraph,
+    G::EdgeData: Weight,
+    G::NodeId: Clone + Eq + Ord,
+{
+    let mut distances = HashMap::new();
+    let mut previous: HashMap<G::NodeId, G::NodeId> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    for node in graph.nodes() {
+        if node == source {
+            distances.insert(node, G::EdgeData::zero());
+            heap.push((Reverse(G::EdgeData::zero()), node));
+        } else {
+            distances.insert(node, G::EdgeData::inf());
+        }
+    }
+
+    while let Some((Reverse(current_dist), node)) = heap.pop() {
+        if current_dist > distances[&node] {
+            continue;
+        }
+
+        for neighbor in graph.neighbors(node) {
+            if let Some(weight) = graph.edge_weight(node, neighbor) {
+                let new_dist = current_dist + weight;
+
+                if new_dist < distances[&neighbor] {
+                    distances.insert(neighbor, new_dist);
+                    previous.insert(neighbor, node);
+                    heap.push((Reverse(new_dist), neighbor));
+                }
+            }
+        }
+    }
+
+    (distances, previous)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::data_structures::graphs::UndirectedWeightedGraph;
+
+    #[test]
+    fn test_dijkstra_algorithm() {
+        use ordered_float::OrderedFloat;
+
+        type EdgeData = OrderedFloat<f64>;
+        let mut graph: UndirectedWeightedGraph<i32, EdgeData> = UndirectedWeightedGraph::new();
+
+        graph.add_edge_id(1, 2, EdgeData::from(10.0));
+        graph.add_edge_id(1, 3, EdgeData::from(5.0));
+        graph.add_edge_id(2, 3, EdgeData::from(2.0));
+        graph.add_edge_id(1, 4, EdgeData::from(10.0));
+
+        let distances = dijkstra_algorithm(&graph, 3);
+
+        assert_eq!(distances.get(&3), Some(&OrderedFloat(0.0)));
+        assert_eq!(distances.get(&1), Some(&OrderedFloat(5.0)));
+        assert_eq!(distances.get(&2), Some(&OrderedFloat(2.0)));
+        assert_eq!(distances.get(&4), Some(&OrderedFloat(15.0)));
+    }
+
+
+    #[test]
+    fn test_dijkstra_path_basic() {
+        type EdgeData = OrderedFloat<f64>;
+
+        let mut graph: UndirectedWeightedGraph<i32, EdgeData> = UndirectedWeightedGraph::new();
+
+        // Grafo:
+        // 1 --10-- 2
+        // 1 -- 5-- 3
+        // 3 -- 2-- 2
+        // 1 --10-- 4
+
+        graph.add_edge_id(1, 2, EdgeData::from(10.0));
+        graph.add_edge_id(1, 3, EdgeData::from(5.0));
+        graph.add_edge_id(3, 2, EdgeData::from(2.0));
+        graph.add_edge_id(1, 4, EdgeData::from(10.0));
+
+        // Shortest path de 1 → 2 es: 1 → 3 → 2
+        // Distancia = 5.0 + 2.0 = 7.0
+
+        let result = dijkstra_path(&graph, 1, 2)
+            .expect("Path should exist");
+
+        let (path, dist) = result;
+
+        assert_eq!(path, vec![1, 3, 2]);
+        assert_eq!(dist, EdgeData::from(7.0));
+    }
+
+    #[test]
+    fn test_dijkstra_path_edge_cases() {
+        use crate::data_structures::graphs::DirectedWeightedGraph;
+
+        let (mut graph, ids) = DirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("s", "a", 1),
+            ("a", "t", 1),
+            ("t", "s", 1),
+        ]);
+        let isolated = graph.add_node("z");
+
+        // Origen = destino: camino trivial de costo cero
+        assert_eq!(dijkstra_path(&graph, ids["a"], ids["a"]), Some((vec![ids["a"]], 0)));
+        // El ciclo t -> s no confunde la reconstrucción
+        assert_eq!(dijkstra_path(&graph, ids["a"], ids["s"]), Some((vec![ids["a"], ids["t"], ids["s"]], 2)));
+        // Inalcanzable
+        assert_eq!(dijkstra_path(&graph, ids["s"], isolated), None);
+        assert_eq!(dijkstra_path(&graph, isolated, ids["s"]), None);
+    }
+
+    #[test]
+    fn test_dijkstra_tree() {
+        use crate::data_structures::graphs::DirectedWeightedGraph;
+
+        // 0 -4-> 1, 0 -1-> 2, 2 -2-> 1, 1 -5-> 3, 2 -8-> 3, 4 aislado
+        let (graph, ids) = DirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("s", "a", 4),
+            ("s", "b", 1),
+            ("b", "a", 2),
+            ("a", "t", 5),
+            ("b", "t", 8),
+        ]);
+        let mut graph = graph;
+        let isolated = graph.add_node("z");
+
+        let (distances, parents) = dijkstra_tree(&graph, ids["s"]);
+
+        assert_eq!(distances[&ids["s"]], 0);
+        assert_eq!(distances[&ids["a"]], 3);
+        assert_eq!(distances[&ids["b"]], 1);
+        assert_eq!(distances[&ids["t"]], 8);
+        assert_eq!(distances[&isolated], i32::MAX);
+
+        // El árbol: s -> b -> a -> t
+        assert_eq!(parents.len(), 3);
+        assert_eq!(parents[&ids["b"]], ids["s"]);
+        assert_eq!(parents[&ids["a"]], ids["b"]);
+        assert_eq!(parents[&ids["t"]], ids["a"]);
+        assert!(!parents.contains_key(&ids["s"]));
+        assert!(!parents.contains_key(&isolated));
+
+        // El alias antiguo sigue exportando las mismas funciones
+        let (old_distances, _) = super::super::djikstra::dijkstra_tree(&graph, ids["s"]);
+        assert_eq!(old_distances, distances);
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::{HashMap, HashSet};
+use crate::data_structures::graphs::GraphBase;
+
+/// Comprueba por fuerza bruta (backtracking) si existe una biyección entre los nodos
+/// de `a` y `b` que preserve la adyacencia dada por `neighbors`. Sirve para ambos
+/// tipos de grafo: en los dirigidos se respeta el sentido de las aristas.
+///
+/// Antes de probar permutaciones se comparan las secuencias de grados, y solo se
+/// emparejan nodos con el mismo grado. Aun así el costo es factorial: pensado para
+/// grafos pequeños (~10 nodos), típicamente en tests.
+pub fn is_isomorphic<A, B>(a: &A, b: &B) -> bool
+where
+    A: GraphBase,
+    B: GraphBase,
+{
+    let nodes_a = a.nodes();
+    let nodes_b = b.nodes();
+    if nodes_a.len() != nodes_b.len() {
+        return false;
+    }
+
+    let adj_a: HashMap<A::NodeId, HashSet<A::NodeId>> = nodes_a.iter()
+        .map(|&n| (n, a.neighbors(n).into_iter().collect()))
+        .collect();
+    let adj_b: HashMap<B::NodeId, HashSet<B::NodeId>> = nodes_b.iter()
+        .map(|&n| (n, b.neighbors(n).into_iter().collect()))
+        .collect();
+
+    // Poda: secuencias de grados distintas => no isomorfos
+    let mut degrees_a: Vec<usize> = adj_a.values().map(|s| s.len()).collect();
+    let mut degrees_b: Vec<usize> = adj_b.values().map(|s| s.len()).collect();
+    degrees_a.sort_unstable();
+    degrees_b.sort_unstable();
+    if degrees_a != degrees_b {
+        return false;
+    }
+
+    // Primero los nodos de mayor grado: fallan antes y podan más
+    let mut order = nodes_a;
+    order.sort_by_key(|n| std::cmp::Reverse(adj_a[n].len()));
+
+    let mut mapping: HashMap<A::NodeId, B::NodeId> = HashMap::new();
+    let mut used: HashSet<B::NodeId> = HashSet::new();
+    extend_mapping(&order, &nodes_b, &adj_a, &adj_b, &mut mapping, &mut used)
+}
+
+fn extend_mapping<NA, NB>(
+    order: &[NA],
+    candidates: &[NB],
+    adj_a: &HashMap<NA, HashSet<NA>>,
+    adj_b: &HashMap<NB, HashSet<NB>>,
+    mapping: &mut HashMap<NA, NB>,
+    used: &mut HashSet<NB>,
+) -> bool
+where
+    NA: Copy + Eq + std::hash::Hash,
+    NB: Copy + Eq + std::hash::Hash,
+{
+    let Some((&node, rest)) = order.split_first() else {
+        return true;
+    };
+
+    for &target in candidates {
+        if used.contains(&target) || adj_a[&node].len() != adj_b[&target].len() {
+            continue;
+        }
+
+        // La adyacencia con los nodos ya asignados debe coincidir en ambos sentidos
+        let consistent = mapping.iter().all(|(&m_a, &m_b)| {
+            adj_a[&node].contains(&m_a) == adj_b[&target].contains(&m_b)
+                && adj_a[&m_a].contains(&node) == adj_b[&m_b].contains(&target)
+        }) && adj_a[&node].contains(&node) == adj_b[&target].contains(&target);
+
+        if consistent {
+            mapping.insert(node, target);
+            used.insert(target);
+            if extend_mapping(rest, candidates, adj_a, adj_b, mapping, used) {
+                return true;
+            }
+            mapping.remove(&node);
+            used.remove(&target);
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::graphs::{DirectedGraph, UndirectedSimpleGraph};
+
+    fn undirected(n: usize, edges: &[(usize, usize)]) -> UndirectedSimpleGraph<usize> {
+        let mut graph = UndirectedSimpleGraph::new();
+        for i in 0..n { graph.add_node(i); }
+        for &(a, b) in edges { graph.add_edge(a, b); }
+        graph
+    }
+
+    #[test]
+    fn test_structurally_equal() {
+        let a = undirected(4, &[(0, 1), (1, 2), (2, 3)]);
+        let b = undirected(4, &[(2, 3), (0, 1), (2, 1)]);
+        let c = undirected(4, &[(0, 1), (1, 2), (0, 3)]);
+
+        assert!(a.structurally_equal(&b));
+        assert!(!a.structurally_equal(&c));
+    }
+
+    #[test]
+    fn test_relabeled_graphs_are_isomorphic() {
+        // Camino 0-1-2-3 con una cuerda 0-2, y la misma forma con otras etiquetas
+        let a = undirected(4, &[(0, 1), (1, 2), (2, 3), (0, 2)]);
+        let b = undirected(4, &[(3, 2), (2, 0), (0, 1), (3, 0)]);
+
+        assert!(!a.structurally_equal(&b));
+        assert!(is_isomorphic(&a, &b));
+        assert!(is_isomorphic(&a, &a));
+    }
+
+    #[test]
+    fn test_non_isomorphic_same_node_count() {
+        // Estrella vs camino: 4 nodos y 3 aristas, pero distinta secuencia de grados
+        let star = undirected(4, &[(0, 1), (0, 2), (0, 3)]);
+        let path = undirected(4, &[(0, 1), (1, 2), (2, 3)]);
+        assert!(!is_isomorphic(&star, &path));
+
+        // Misma secuencia de grados (todos grado 2) pero C6 vs dos triángulos
+        let cycle6 = undirected(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+        let triangles = undirected(6, &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+        assert!(!is_isomorphic(&cycle6, &triangles));
+    }
+
+    #[test]
+    fn test_directed_isomorphism_respects_direction() {
+        let mut a: DirectedGraph<i32> = DirectedGraph::new();
+        let mut b: DirectedGraph<i32> = DirectedGraph::new();
+        for i in 0..3 { a.add_node(i); b.add_node(i); }
+        // a: 0 -> 1 -> 2,  b: 2 -> 0 -> 1 (mismo camino reetiquetado)
+        a.add_directed_edge(0, 1);
+        a.add_directed_edge(1, 2);
+        b.add_directed_edge(2, 0);
+        b.add_directed_edge(0, 1);
+        assert!(is_isomorphic(&a, &b));
+
+        // c: 0 -> 1 <- 2 (mismos grados de salida no, pero distinta forma)
+        let mut c: DirectedGraph<i32> = DirectedGraph::new();
+        for i in 0..3 { c.add_node(i); }
+        c.add_directed_edge(0, 1);
+        c.add_directed_edge(2, 1);
+        assert!(!is_isomorphic(&a, &c));
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+use num_traits::Zero;
+use crate::data_structures::graphs::{WeightedGraph, graph_base::GraphBase, weighted::Weight};
+use super::dijkstra::dijkstra_path;
+
+/// Vista de un grafo con nodos y aristas ocultos, usada por Yen para
+/// calcular caminos desvío sin clonar ni modificar el grafo original.
+struct FilteredGraph<'a, G: GraphBase> {
+    graph: &'a G,
+    removed_nodes: HashSet<G::NodeId>,
+    removed_edges: HashSet<(G::NodeId, G::NodeId)>,
+}
+
+impl<'a, G: GraphBase> FilteredGraph<'a, G> {
+    fn allows(&self, from: G::NodeId, to: G::NodeId) -> bool {
+        !self.removed_nodes.contains(&from)
+            && !self.removed_nodes.contains(&to)
+            && !self.removed_edges.contains(&(from, to))
+    }
+}
+
+impl<'a, G: GraphBase> GraphBase for FilteredGraph<'a, G> {
+    type NodeId = G::NodeId;
+    type NodeData = G::NodeData;
+    type EdgeData = G::EdgeData;
+
+    fn nodes(&self) -> Vec<Self::NodeId> {
+        self.graph.nodes().into_iter().filter(|n| !self.removed_nodes.contains(n)).collect()
+    }
+
+    fn edges(&self) -> Vec<(Self::NodeId, Self::NodeId)> {
+        self.graph.edges().into_iter().filter(|&(a, b)| self.allows(a, b)).collect()
+    }
+
+    fn node_data(&self, id: Self::NodeId) -> Option<&Self::NodeData> {
+        if self.removed_nodes.contains(&id) { None } else { self.graph.node_data(id) }
+    }
+
+    fn edge_data(&self, from: Self::NodeId, to: Self::NodeId) -> Option<&Self::EdgeData> {
+        if self.allows(from, to) { self.graph.edge_data(from, to) } else { None }
+    }
+
+    fn neighbors(&self, node: Self::NodeId) -> Vec<Self::NodeId> {
+        self.graph.neighbors(node).into_iter().filter(|&n| self.allows(node, n)).collect()
+    }
+}
+
+impl<'a, G> WeightedGraph for FilteredGraph<'a, G>
+where
+    G: WeightedGraph,
+    G::EdgeData: Weight,
+{
+    fn edge_weight(&self, from: Self::NodeId, to: Self::NodeId) -> Option<Self::EdgeData> {
+        if self.allows(from, to) { self.graph.edge_weight(from, to) } else { None }
+    }
+}
+
+/// Los `k` caminos simples más cortos entre `start` y `goal` (algoritmo de Yen),
+/// ordenados por peso total. Cada desvío se calcula con `dijkstra_path`.
+/// Devuelve menos de `k` caminos si el grafo no tiene tantos.
+pub fn k_shortest_paths<G>(
+    graph: &G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    k: usize,
+) -> Vec<(Vec<G::NodeId>, G::EdgeData)>
+where
+    G: WeightedGraph,
+    G::EdgeData: Weight,
+    G::NodeId: Clone + Eq + Ord,
+{
+    let mut found: Vec<(Vec<G::NodeId>, G::EdgeData)> = Vec::new();
+    if k == 0 {
+        return found;
+    }
+    match dijkstra_path(graph, start, goal) {
+        Some(first) => found.push(first),
+        None => return found,
+    }
+
+    let mut candidates: Vec<(Vec<G::NodeId>, G::EdgeData)> = Vec::new();
+
+    while found.len() < k {
+        let previous = found.last().unwrap().0.clone();
+
+        for i in 0..previous.len().saturating_sub(1) {
+            let spur = previous[i];
+            let root = &previous[..=i];
+
+            // Ocultamos las aristas que ya usaron otros caminos con la misma raíz
+            // y los nodos de la raíz (salvo el de desvío) para mantener el camino simple.
+            let mut view = FilteredGraph {
+                graph,
+                removed_nodes: root[..i].iter().copied().collect(),
+                removed_edges: HashSet::new(),
+            };
+            for (path, _) in &found {
+                if path.len() > i + 1 && &path[..=i] == root {
+                    view.removed_edges.insert((path[i], path[i + 1]));
+                }
+            }
+
+            if let Some((spur_path, spur_cost)) = dijkstra_path(&view, spur, goal) {
+                let root_cost = path_cost(graph, root);
+                let mut total_path = root[..i].to_vec();
+                total_path.extend(spur_path);
+
+                let known = found.iter().chain(candidates.iter()).any(|(p, _)| *p == total_path);
+                if !known {
+                    candidates.push((total_path, root_cost + spur_cost));
+                }
+            }
+        }
+
+        if candidates.is_empty() {
+            break;
+        }
+        // Mejor candidato: menor peso y, a igual peso, menos saltos
+        let best = (0..candidates.len())
+            .min_by_key(|&idx| (candidates[idx].1, candidates[idx].0.len()))
+            .unwrap();
+        found.push(candidates.swap_remove(best));
+    }
+
+    found
+}
+
+fn path_cost<G>(graph: &G, path: &[G::NodeId]) -> G::EdgeData
+where
+    G: WeightedGraph,
+    G::EdgeData: Weight,
+{
+    path.windows(2)
+        .filter_map(|w| graph.edge_weight(w[0], w[1]))
+        .fold(G::EdgeData::zero(), |acc, w| acc + w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::graphs::UndirectedWeightedGraph;
+
+    // Versión no dirigida del ejemplo clásico de Yen (C=1, D=2, E=3, F=4, G=5, H=6)
+    fn setup_graph() -> UndirectedWeightedGraph<i32, i32> {
+        let mut graph: UndirectedWeightedGraph<i32, i32> = UndirectedWeightedGraph::new();
+        graph.add_edge_id(1, 2, 3);
+        graph.add_edge_id(1, 3, 2);
+        graph.add_edge_id(2, 4, 4);
+        graph.add_edge_id(3, 2, 1);
+        graph.add_edge_id(3, 4, 2);
+        graph.add_edge_id(3, 5, 3);
+        graph.add_edge_id(4, 5, 2);
+        graph.add_edge_id(4, 6, 1);
+        graph.add_edge_id(5, 6, 2);
+        graph
+    }
+
+    #[test]
+    fn test_k_shortest_paths_first_two() {
+        let graph = setup_graph();
+        let paths = k_shortest_paths(&graph, 1, 6, 3);
+
+        assert_eq!(paths.len(), 3);
+        assert_eq!(paths[0], (vec![1, 3, 4, 6], 5));
+        // Empate en 7: primero el de menos saltos
+        assert_eq!(paths[1], (vec![1, 3, 5, 6], 7));
+        assert_eq!(paths[2], (vec![1, 2, 3, 4, 6], 7));
+
+        // Ordenados por peso y todos simples
+        for window in paths.windows(2) {
+            assert!(window[0].1 <= window[1].1);
+        }
+        for (path, cost) in &paths {
+            let unique: HashSet<_> = path.iter().collect();
+            assert_eq!(unique.len(), path.len(), "Camino con ciclo: {:?}", path);
+            assert_eq!(graph.path_weight(path), Some(*cost));
+        }
+    }
+
+    #[test]
+    fn test_k_shortest_paths_fewer_than_k() {
+        let mut graph: UndirectedWeightedGraph<i32, i32> = UndirectedWeightedGraph::new();
+        graph.add_edge_id(1, 2, 1);
+        graph.add_edge_id(2, 3, 1);
+
+        let paths = k_shortest_paths(&graph, 1, 3, 5);
+        assert_eq!(paths, vec![(vec![1, 2, 3], 2)]);
+        assert!(k_shortest_paths(&graph, 1, 3, 0).is_empty());
+    }
+}
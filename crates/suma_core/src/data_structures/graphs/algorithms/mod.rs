@@ -1,9 +1,23 @@
-pub mod djikstra;
+pub mod dijkstra;
+/// Nombre antiguo (con la errata) del módulo, se mantiene por compatibilidad.
+pub mod djikstra {
+    pub use super::dijkstra::*;
+}
 pub mod search;
 pub mod a_star;
 pub mod sort;
+pub mod k_shortest;
+pub mod isomorphism;
+pub mod mst;
+pub mod bellman_ford;
+pub mod components;
 
 pub use sort::*;
-pub use djikstra::*;
+pub use dijkstra::*;
 pub use search::*;
-pub use a_star::*;
\ No newline at end of file
+pub use a_star::*;
+pub use k_shortest::*;
+pub use isomorphism::*;
+pub use mst::*;
+pub use bellman_ford::*;
+pub use components::*;
\ No newline at end of file
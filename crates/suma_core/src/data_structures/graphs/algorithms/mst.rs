@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use crate::data_structures::graphs::{UndirectedGraph, WeightedGraph, weighted::Weight};
+
+/// Árbol (o bosque, si el grafo no es conexo) de expansión mínima por Kruskal.
+/// Devuelve las aristas elegidas `(a, b, peso)` en orden creciente de peso.
+pub fn minimum_spanning_tree<G>(graph: &G) -> Vec<(G::NodeId, G::NodeId, G::EdgeData)>
+where
+    G: UndirectedGraph + WeightedGraph,
+    G::EdgeData: Weight,
+    G::NodeId: Ord,
+{
+    let mut edges: Vec<(G::EdgeData, G::NodeId, G::NodeId)> = graph.edges()
+        .into_iter()
+        .filter(|(a, b)| a != b)
+        .filter_map(|(a, b)| graph.edge_weight(a, b).map(|w| (w, a, b)))
+        .collect();
+    // Empates por id para que el resultado sea determinista
+    edges.sort();
+
+    let mut parent: HashMap<G::NodeId, G::NodeId> = graph.nodes().into_iter().map(|n| (n, n)).collect();
+
+    fn find<N: Copy + Eq + std::hash::Hash>(parent: &mut HashMap<N, N>, node: N) -> N {
+        let mut root = node;
+        while parent[&root] != root {
+            root = parent[&root];
+        }
+        // Compresión de caminos
+        let mut current = node;
+        while current != root {
+            let next = parent[&current];
+            parent.insert(current, root);
+            current = next;
+        }
+        root
+    }
+
+    let mut tree = Vec::new();
+    for (weight, a, b) in edges {
+        let root_a = find(&mut parent, a);
+        let root_b = find(&mut parent, b);
+        if root_a != root_b {
+            parent.insert(root_a, root_b);
+            tree.push((a, b, weight));
+        }
+    }
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::graphs::UndirectedWeightedGraph;
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let (graph, ids) = UndirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("A", "B", 4),
+            ("A", "C", 1),
+            ("B", "C", 2),
+            ("B", "D", 5),
+            ("C", "D", 8),
+            ("D", "E", 3),
+        ]);
+
+        let tree = minimum_spanning_tree(&graph);
+        let total: i32 = tree.iter().map(|(_, _, w)| *w).sum();
+
+        assert_eq!(tree.len(), 4);
+        assert_eq!(total, 1 + 2 + 3 + 5);
+        assert!(!tree.iter().any(|&(a, b, _)| (a, b) == (ids["C"], ids["D"]) || (a, b) == (ids["D"], ids["C"])));
+    }
+
+    #[test]
+    fn test_minimum_spanning_forest() {
+        let (graph, _) = UndirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("A", "B", 1),
+            ("C", "D", 2),
+        ]);
+
+        let tree = minimum_spanning_tree(&graph);
+        assert_eq!(tree.len(), 2);
+    }
+}
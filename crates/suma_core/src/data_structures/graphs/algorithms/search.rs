@@ -173,10 +173,78 @@ where
     ( visited, traversal_order)
 }
 
+/// Recorrido BFS perezoso desde un nodo: entrega los nodos en orden de niveles.
+/// Cada nodo se expande recién cuando se pide, así que `.take(n)` o `.find(..)`
+/// evitan recorrer el resto del grafo.
+pub struct BfsIter<'a, G: GraphBase> {
+    graph: &'a G,
+    queue: std::collections::VecDeque<G::NodeId>,
+    visited: HashSet<G::NodeId>,
+}
+
+impl<'a, G: GraphBase> Iterator for BfsIter<'a, G> {
+    type Item = G::NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.queue.pop_front()?;
+        for neighbor in self.graph.neighbors(current) {
+            if self.visited.insert(neighbor) {
+                self.queue.push_back(neighbor);
+            }
+        }
+        Some(current)
+    }
+}
+
+/// Crea un iterador BFS sobre cualquier `GraphBase`, usando `neighbors`.
+pub fn bfs_iter<G: GraphBase>(graph: &G, start: G::NodeId) -> BfsIter<'_, G> {
+    let mut visited = HashSet::new();
+    visited.insert(start);
+    BfsIter { graph, queue: std::collections::VecDeque::from([start]), visited }
+}
+
+/// Recorrido DFS perezoso (preorden): baja por el primer vecino antes de pasar al siguiente.
+pub struct DfsIter<'a, G: GraphBase> {
+    graph: &'a G,
+    stack: Vec<G::NodeId>,
+    visited: HashSet<G::NodeId>,
+}
+
+impl<'a, G: GraphBase> Iterator for DfsIter<'a, G> {
+    type Item = G::NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(current) = self.stack.pop() {
+            // Un nodo puede estar apilado varias veces; solo cuenta la primera visita
+            if !self.visited.insert(current) {
+                continue;
+            }
+            // Apilamos en orden inverso para explorar primero el primer vecino
+            for neighbor in self.graph.neighbors(current).into_iter().rev() {
+                if !self.visited.contains(&neighbor) {
+                    self.stack.push(neighbor);
+                }
+            }
+            return Some(current);
+        }
+        None
+    }
+}
+
+/// Crea un iterador DFS sobre cualquier `GraphBase`, usando `neighbors`.
+pub fn dfs_iter<G: GraphBase>(graph: &G, start: G::NodeId) -> DfsIter<'_, G> {
+    DfsIter { graph, stack: vec![start], visited: HashSet::new() }
+}
+
+/// Indica si `goal` es alcanzable desde `start`. Corta en cuanto lo encuentra.
+pub fn is_reachable<G: GraphBase>(graph: &G, start: G::NodeId, goal: G::NodeId) -> bool {
+    bfs_iter(graph, start).any(|node| node == goal)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::data_structures::graphs::BaseGraph;
+    use crate::data_structures::graphs::{BaseGraph, DirectedGraph};
 
     fn setup_graph() -> (BaseGraph<&'static str, i32>, Vec<usize>) {
         let mut graph: BaseGraph<&str, i32> = BaseGraph::new();
@@ -236,5 +304,52 @@ mod tests {
 
         assert_eq!(has_cycle, true);
     }
+
+    // Árbol dirigido con vecinos en orden de inserción:
+    //        0
+    //      /   \
+    //     1     2
+    //    / \     \
+    //   3   4     5
+    fn setup_tree() -> DirectedGraph<i32> {
+        let mut graph = DirectedGraph::new();
+        for i in 0..6 { graph.add_node(i); }
+        graph.add_directed_edge(0, 1);
+        graph.add_directed_edge(0, 2);
+        graph.add_directed_edge(1, 3);
+        graph.add_directed_edge(1, 4);
+        graph.add_directed_edge(2, 5);
+        graph
+    }
+
+    #[test]
+    fn test_bfs_iter_level_order() {
+        let graph = setup_tree();
+        let order: Vec<usize> = bfs_iter(&graph, 0).collect();
+        assert_eq!(order, vec![0, 1, 2, 3, 4, 5]);
+
+        // Perezoso: solo los primeros niveles
+        let first: Vec<usize> = bfs_iter(&graph, 0).take(3).collect();
+        assert_eq!(first, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_dfs_iter_depth_order() {
+        let mut graph = setup_tree();
+        // Arista cruzada: 5 ya no debe repetirse
+        graph.add_directed_edge(4, 5);
+        let order: Vec<usize> = dfs_iter(&graph, 0).collect();
+        assert_eq!(order, vec![0, 1, 3, 4, 5, 2]);
+    }
+
+    #[test]
+    fn test_reachability() {
+        let graph = setup_tree();
+        assert!(is_reachable(&graph, 0, 5));
+        assert!(is_reachable(&graph, 1, 4));
+        assert!(!is_reachable(&graph, 1, 5));
+        assert!(!is_reachable(&graph, 3, 0), "Las aristas son dirigidas");
+        assert!(is_reachable(&graph, 2, 2));
+    }
 }
 
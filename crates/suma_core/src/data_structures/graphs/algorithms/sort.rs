@@ -1,40 +1,64 @@
 use crate::data_structures::graphs::{Directed, GraphBase};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 
-pub fn topological_sort<G:Directed>(graph : &G) -> Result<Vec<G::NodeId>, &'static str>
-{
-    use std::collections::HashSet;
-    let mut visited = HashSet::new();
-    let mut stack = Vec::new();
-    let mut on_stack = HashSet::new(); // para detección de ciclos
-
-    fn dfs<G: Directed>(
-        node: G::NodeId,
-        graph: &G,
-        visited: &mut HashSet<G::NodeId>,
-        stack: &mut Vec<G::NodeId>,
-        on_stack: &mut HashSet<G::NodeId>,
-    ) -> Result<(), &'static str> {
-        if on_stack.contains(&node) {
-            return Err("Cycle detected");
+/// El grafo no es acíclico. `node` pertenece a uno de los ciclos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleError<N> {
+    pub node: N,
+}
+
+impl<N: fmt::Debug> fmt::Display for CycleError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "El grafo tiene un ciclo que pasa por el nodo {:?}", self.node)
+    }
+}
+
+impl<N: fmt::Debug> std::error::Error for CycleError<N> {}
+
+/// Orden topológico por el algoritmo de Kahn: se extraen repetidamente los nodos
+/// sin aristas entrantes pendientes. Si quedan nodos sin extraer, hay un ciclo.
+pub fn topological_sort<G: Directed>(graph: &G) -> Result<Vec<G::NodeId>, CycleError<G::NodeId>> {
+    let nodes = graph.nodes();
+    let mut in_degree: HashMap<G::NodeId, usize> = nodes.iter().map(|&n| (n, 0)).collect();
+    let mut predecessors: HashMap<G::NodeId, Vec<G::NodeId>> = HashMap::new();
+    for &node in &nodes {
+        for next in graph.neighbors(node) {
+            *in_degree.entry(next).or_insert(0) += 1;
+            predecessors.entry(next).or_default().push(node);
         }
-        if !visited.contains(&node) {
-            visited.insert(node);
-            on_stack.insert(node);
-            for neighbor in graph.neighbors(node) {
-                dfs(neighbor, graph, visited, stack, on_stack)?;
+    }
+
+    let mut queue: VecDeque<G::NodeId> = nodes.iter().copied().filter(|n| in_degree[n] == 0).collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for next in graph.neighbors(node) {
+            let degree = in_degree.get_mut(&next).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push_back(next);
             }
-            stack.push(node);
-            on_stack.remove(&node);
         }
-        Ok(())
     }
 
-    for node in graph.nodes() {
-        dfs(node, graph, &mut visited, &mut stack, &mut on_stack)?;
+    if order.len() == in_degree.len() {
+        return Ok(order);
     }
 
-    stack.reverse();
-    Ok(stack)
+    // Cada nodo restante tiene un predecesor restante: retrocediendo se termina
+    // repitiendo un nodo, y ese nodo está en un ciclo.
+    let mut node = *in_degree.iter().find(|(_, d)| **d > 0).unwrap().0;
+    let mut seen = HashSet::new();
+    while seen.insert(node) {
+        node = predecessors[&node].iter().copied().find(|p| in_degree[p] > 0).unwrap();
+    }
+    Err(CycleError { node })
+}
+
+/// Indica si el grafo dirigido contiene algún ciclo.
+pub fn has_cycle<G: Directed>(graph: &G) -> bool {
+    topological_sort(graph).is_err()
 }
 
 #[cfg(test)]
@@ -72,4 +96,41 @@ mod tests {
         let result = topological_sort(&graph);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_cycle_error_reports_node_on_cycle() {
+        // a -> b -> c -> b,  c -> d: a y d no están en el ciclo
+        let mut graph: DirectedGraph<&str> = DirectedGraph::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+        let c = graph.add_node("C");
+        let d = graph.add_node("D");
+
+        graph.add_directed_edge(a, b);
+        graph.add_directed_edge(b, c);
+        graph.add_directed_edge(c, b);
+        graph.add_directed_edge(c, d);
+
+        let err = topological_sort(&graph).unwrap_err();
+        assert!(err.node == b || err.node == c, "{}", err);
+        assert!(has_cycle(&graph));
+    }
+
+    #[test]
+    fn test_kahn_respects_every_edge() {
+        let mut graph: DirectedGraph<&str> = DirectedGraph::new();
+        let ids: Vec<usize> = ["A", "B", "C", "D", "E"].iter().map(|n| graph.add_node(n)).collect();
+        let edges = [(0, 2), (1, 2), (2, 3), (1, 4), (4, 3)];
+        for (from, to) in edges {
+            graph.add_directed_edge(ids[from], ids[to]);
+        }
+
+        let order = topological_sort(&graph).unwrap();
+        let position = |n: usize| order.iter().position(|&x| x == n).unwrap();
+        assert_eq!(order.len(), 5);
+        for (from, to) in edges {
+            assert!(position(ids[from]) < position(ids[to]));
+        }
+        assert!(!has_cycle(&graph));
+    }
 }
\ No newline at end of file
@@ -9,6 +9,18 @@ pub struct BaseGraph<T, E> {
     pub next_id: usize,
 }
 
+impl<T: std::fmt::Display, E> BaseGraph<T, E> {
+    /// Etiqueta de cada nodo (su dato), ordenados por id: los exportadores
+    /// las declaran para que la visualización no muestre solo ids internos.
+    pub(crate) fn node_labels(&self) -> Vec<(usize, String)> {
+        let mut labels: Vec<(usize, String)> = self.nodes.iter()
+            .map(|(&id, data)| (id, data.to_string()))
+            .collect();
+        labels.sort_by_key(|(id, _)| *id);
+        labels
+    }
+}
+
 impl<T, E> BaseGraph<T, E> {
     pub fn new() -> Self {
         Self {
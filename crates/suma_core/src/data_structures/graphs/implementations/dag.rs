@@ -96,7 +96,9 @@ impl<T> ToDot for DAG<T> {
 }
 
 impl<T> DAG<T> where T: Hash + Eq {
+    /// Siempre falso mientras las aristas se agreguen con `add_edge`; se calcula
+    /// con el mismo detector que `DirectedGraph::has_cycle`.
     pub fn has_cycle(&self) -> bool {
-        self.graph.has_cycle()
+        crate::data_structures::graphs::algorithms::sort::has_cycle(self)
     }
 }
\ No newline at end of file
@@ -24,17 +24,9 @@ impl<T> DirectedGraph<T> {
         self.adjacency.entry(from).or_insert_with(Vec::new).push(to);
     }
 
+    /// Indica si el grafo contiene algún ciclo (ver `algorithms::sort::has_cycle`).
     pub fn has_cycle(&self) -> bool {
-        use crate::data_structures::graphs::algorithms::search::dfs_cycle;
-        use std::collections::HashSet;
-
-        for &node in self.nodes().iter() {
-            let mut visited = HashSet::new();
-            if dfs_cycle(self, node, node, &mut visited) {
-                return true;
-            }
-        }
-        false
+        crate::data_structures::graphs::algorithms::sort::has_cycle(self)
     }
 
     pub fn add_node(&mut self, data: T) -> usize {
@@ -335,4 +327,56 @@ mod tests {
         assert_eq!(graph.edges().len(), 1);
         assert_eq!(graph.nodes().len(), 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_contains_node_and_edge() {
+        let mut graph = DirectedGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+
+        graph.add_directed_edge(a, b);
+        graph.add_directed_edge(b, c);
+
+        assert!(graph.contains_node(a));
+        assert!(!graph.contains_node(99));
+        assert!(graph.contains_edge(a, b));
+        assert!(!graph.contains_edge(b, a)); // Dirigido: no hay arista inversa
+
+        graph.remove_edge(a, b);
+        assert!(!graph.contains_edge(a, b));
+
+        graph.remove_node(c);
+        assert!(!graph.contains_node(c));
+        assert!(!graph.contains_edge(b, c));
+    }
+
+    #[test]
+    fn test_has_cycle_matches_topological_sort() {
+        use crate::data_structures::graphs::{algorithms::sort, dag::DAG};
+
+        // Diamante a -> b -> d, a -> c -> d: acíclico aunque d tenga dos caminos
+        let mut graph = DirectedGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_directed_edge(a, b);
+        graph.add_directed_edge(a, c);
+        graph.add_directed_edge(b, d);
+        graph.add_directed_edge(c, d);
+        assert!(!graph.has_cycle());
+        assert_eq!(graph.has_cycle(), sort::has_cycle(&graph));
+
+        graph.add_directed_edge(d, a);
+        assert!(graph.has_cycle());
+        assert_eq!(graph.has_cycle(), sort::has_cycle(&graph));
+
+        let mut dag = DAG::new();
+        let x = dag.add_node("x");
+        let y = dag.add_node("y");
+        assert!(dag.add_edge(x, y).is_ok());
+        assert!(dag.add_edge(y, x).is_err());
+        assert!(!dag.has_cycle());
+    }
+}
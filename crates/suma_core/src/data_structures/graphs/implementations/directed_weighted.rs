@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use ordered_float::OrderedFloat;
+use crate::data_structures::graphs::{BaseGraph, Directed, GraphBase};
+use crate::data_structures::graphs::traits::WeightedGraph;
+use crate::data_structures::graphs::weighted::{IntoWeight, Weight};
+use crate::formatting::error::ExportError;
+use crate::formatting::visualizable::{ToDot, ToMermaid, ToPlantUml};
+
+#[derive(Debug, Clone)]
+pub struct DirectedWeightedGraph<N, E: Weight = OrderedFloat<f64>> {
+    pub base: BaseGraph<N, E>,
+    pub adjacency: HashMap<usize, Vec<usize>>,
+}
+
+impl<N, E: Weight> DirectedWeightedGraph<N, E> {
+    pub fn new() -> Self {
+        Self {
+            base: BaseGraph::new(),
+            adjacency: HashMap::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, data: N) -> usize {
+        self.base.add_node(data)
+    }
+
+    pub fn add_edge_id<W>(&mut self, from: usize, to: usize, weight: W)
+    where W: IntoWeight<E>
+    {
+        let successors = self.adjacency.entry(from).or_insert_with(Vec::new);
+        if !successors.contains(&to) {
+            successors.push(to);
+        }
+        self.base.add_edge(from, to, weight.into_weight());
+    }
+
+    pub fn add_edge<W>(&mut self, from: N, to: N, weight: W)
+    where
+        W: IntoWeight<E>,
+        N: PartialEq,
+    {
+        let from_id = self.base.get_or_add_node(from);
+        let to_id = self.base.get_or_add_node(to);
+
+        self.add_edge_id(from_id, to_id, weight);
+    }
+
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> Option<E> {
+        let removed = self.base.remove_edge(from, to);
+        if removed.is_some() {
+            if let Some(neighbors) = self.adjacency.get_mut(&from) {
+                neighbors.retain(|&x| x != to);
+            }
+        }
+        removed
+    }
+
+    /// Construye el grafo a partir de una lista de aristas `(origen, destino, peso)`,
+    /// creando los nodos a medida que aparecen. Devuelve también el mapa dato -> id.
+    pub fn from_edges<W>(edges: &[(N, N, W)]) -> (Self, HashMap<N, usize>)
+    where
+        W: IntoWeight<E> + Copy,
+        N: Clone + Eq + Hash,
+    {
+        let mut graph = Self::new();
+        let mut ids: HashMap<N, usize> = HashMap::new();
+
+        for (from, to, weight) in edges {
+            let from_id = *ids.entry(from.clone()).or_insert_with(|| graph.base.add_node(from.clone()));
+            let to_id = *ids.entry(to.clone()).or_insert_with(|| graph.base.add_node(to.clone()));
+            graph.add_edge_id(from_id, to_id, *weight);
+        }
+
+        (graph, ids)
+    }
+
+    pub fn path_weight(&self, path: &[usize]) -> Option<E> {
+        let mut total = E::zero();
+        for window in path.windows(2) {
+            total = total + *self.base.edges.get(&(window[0], window[1]))?;
+        }
+        Some(total)
+    }
+}
+
+impl<N, E: Weight> GraphBase for DirectedWeightedGraph<N, E> {
+    type NodeId = usize;
+    type NodeData = N;
+    type EdgeData = E;
+
+    fn nodes(&self) -> Vec<usize> {
+        self.base.nodes.keys().cloned().collect()
+    }
+
+    fn edges(&self) -> Vec<(usize, usize)> {
+        self.base.edges.keys().cloned().collect()
+    }
+
+    fn node_data(&self, id: usize) -> Option<&N> {
+        self.base.nodes.get(&id)
+    }
+
+    fn edge_data(&self, from: usize, to: usize) -> Option<&E> {
+        self.base.edges.get(&(from, to))
+    }
+
+    fn neighbors(&self, node: Self::NodeId) -> Vec<Self::NodeId> {
+        self.successors(node)
+    }
+}
+
+impl<N, E: Weight> Directed for DirectedWeightedGraph<N, E> {
+    fn predecessors(&self, node: usize) -> Vec<usize> {
+        self.adjacency
+            .iter()
+            .filter(|(_, neighbors)| neighbors.contains(&node))
+            .map(|(&from, _)| from)
+            .collect()
+    }
+
+    fn successors(&self, node: usize) -> Vec<usize> {
+        self.adjacency.get(&node).cloned().unwrap_or_default()
+    }
+}
+
+impl<N, E: Weight> WeightedGraph for DirectedWeightedGraph<N, E> {
+    fn edge_weight(&self, from: usize, to: usize) -> Option<E> {
+        self.edge_data(from, to).copied()
+    }
+}
+
+impl<N: std::fmt::Display, E: Weight + std::fmt::Display> ToDot for DirectedWeightedGraph<N, E> {
+    fn to_dot(&self) -> Result<String, ExportError> {
+        let mut s = String::from("digraph G {\n");
+
+        for (id, label) in self.base.node_labels() {
+            s.push_str(&format!("  {} [label=\"{}\"];\n", id, label.replace('"', "\\\"")));
+        }
+        for (from, to) in self.edges() {
+            if let Some(weight) = self.edge_data(from, to) {
+                s.push_str(&format!("  {} -> {} [label=\"{}\"];\n", from, to, weight));
+            }
+        }
+
+        s.push('}');
+        Ok(s)
+    }
+}
+
+impl<N: std::fmt::Display, E: Weight + std::fmt::Display> ToMermaid for DirectedWeightedGraph<N, E> {
+    fn to_mermaid(&self) -> Result<String, ExportError> {
+        let mut s = String::from("graph TD\n");
+
+        for (id, label) in self.base.node_labels() {
+            s.push_str(&format!("  {}[\"{}\"]\n", id, label.replace('"', "#quot;")));
+        }
+        for (from, to) in self.edges() {
+            if let Some(weight) = self.edge_data(from, to) {
+                s.push_str(&format!("  {} -->|{}| {}\n", from, weight, to));
+            }
+        }
+
+        Ok(s)
+    }
+}
+
+impl<N: std::fmt::Display, E: Weight + std::fmt::Display> ToPlantUml for DirectedWeightedGraph<N, E> {
+    fn to_plantuml(&self) -> Result<String, ExportError> {
+        let mut s = String::from("@startuml\n");
+
+        // Los alias de PlantUML no pueden ser solo dígitos: n<id>
+        for (id, label) in self.base.node_labels() {
+            s.push_str(&format!("  rectangle \"{}\" as n{}\n", label.replace('"', "\\\""), id));
+        }
+        for (from, to) in self.edges() {
+            if let Some(weight) = self.edge_data(from, to) {
+                s.push_str(&format!("  n{} --> n{} : {}\n", from, to, weight));
+            }
+        }
+
+        s.push_str("@enduml");
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_edges() {
+        let (graph, ids) = DirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("A", "B", 4),
+            ("B", "C", 1),
+            ("A", "C", 7),
+            ("C", "A", 2),
+        ]);
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(graph.edge_weight(ids["A"], ids["B"]), Some(4));
+        assert_eq!(graph.edge_weight(ids["B"], ids["A"]), None);
+        assert_eq!(graph.successors(ids["A"]), vec![ids["B"], ids["C"]]);
+        assert_eq!(graph.path_weight(&[ids["A"], ids["B"], ids["C"], ids["A"]]), Some(7));
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut graph: DirectedWeightedGraph<&str, i32> = DirectedWeightedGraph::new();
+        graph.add_edge("A", "B", 3);
+        graph.add_edge("B", "A", 5);
+
+        assert_eq!(graph.remove_edge(0, 1), Some(3));
+        assert_eq!(graph.remove_edge(0, 1), None);
+        assert_eq!(graph.successors(0), Vec::<usize>::new());
+        assert_eq!(graph.edge_weight(1, 0), Some(5));
+    }
+
+    #[test]
+    fn test_exports_declare_node_labels() {
+        let (graph, _) = DirectedWeightedGraph::<&str, i32>::from_edges(&[("Lima", "Cusco", 3)]);
+
+        let dot = graph.to_dot().unwrap();
+        assert!(dot.contains("  0 [label=\"Lima\"];\n  1 [label=\"Cusco\"];"), "{}", dot);
+        assert!(dot.contains("0 -> 1 [label=\"3\"];"));
+
+        let uml = graph.to_plantuml().unwrap();
+        assert!(uml.contains("rectangle \"Cusco\" as n1"), "{}", uml);
+        assert!(uml.contains("n0 --> n1 : 3"));
+    }
+}
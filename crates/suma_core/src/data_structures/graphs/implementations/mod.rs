@@ -1,8 +1,12 @@
 pub mod base_graph;
 pub mod directed_simple;
 pub mod undirected_weighted;
+pub mod undirected_simple;
+pub mod directed_weighted;
 pub mod dag;
 
 pub use base_graph::BaseGraph;
 pub use directed_simple::DirectedGraph;
-pub use undirected_weighted::UndirectedWeightedGraph;
\ No newline at end of file
+pub use undirected_weighted::UndirectedWeightedGraph;
+pub use undirected_simple::UndirectedSimpleGraph;
+pub use directed_weighted::DirectedWeightedGraph;
@@ -0,0 +1,248 @@
+use std::collections::{HashMap, HashSet};
+use crate::data_structures::graphs::{
+    implementations::base_graph::BaseGraph,
+    traits::{GraphBase, UndirectedGraph}
+};
+use crate::formatting::error::ExportError;
+use crate::formatting::visualizable::{ToDot, ToMermaid, ToPlantUml};
+
+/// Grafo no dirigido y sin pesos. Cada arista se guarda en ambos sentidos,
+/// tanto en `base.edges` como en la adyacencia, para que la consulta sea simétrica.
+pub struct UndirectedSimpleGraph<T> {
+    base: BaseGraph<T, ()>,
+    pub adjacency: HashMap<usize, HashSet<usize>>,
+}
+
+impl<T> UndirectedSimpleGraph<T> {
+    pub fn new() -> Self {
+        Self {
+            base: BaseGraph::new(),
+            adjacency: HashMap::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, data: T) -> usize {
+        self.base.add_node(data)
+    }
+
+    pub fn add_edge(&mut self, a: usize, b: usize) {
+        self.base.add_edge(a, b, ());
+        self.base.add_edge(b, a, ());
+        self.adjacency.entry(a).or_insert_with(HashSet::new).insert(b);
+        self.adjacency.entry(b).or_insert_with(HashSet::new).insert(a);
+    }
+
+    pub fn remove_edge(&mut self, a: usize, b: usize) -> Option<()> {
+        let removed = self.base.remove_edge(a, b);
+        self.base.remove_edge(b, a);
+        if let Some(neighbors) = self.adjacency.get_mut(&a) {
+            neighbors.remove(&b);
+        }
+        if let Some(neighbors) = self.adjacency.get_mut(&b) {
+            neighbors.remove(&a);
+        }
+        removed
+    }
+
+    pub fn remove_node(&mut self, id: usize) -> Option<T> {
+        if let Some(neighbors) = self.adjacency.remove(&id) {
+            for n in neighbors {
+                if let Some(set) = self.adjacency.get_mut(&n) {
+                    set.remove(&id);
+                }
+            }
+        }
+        self.base.remove_node(id)
+    }
+}
+
+impl<T> GraphBase for UndirectedSimpleGraph<T> {
+    type NodeId = usize;
+    type NodeData = T;
+    type EdgeData = ();
+
+    fn nodes(&self) -> Vec<usize> {
+        self.base.nodes.keys().cloned().collect()
+    }
+
+    // Cada arista no dirigida se reporta una sola vez, como (menor, mayor)
+    fn edges(&self) -> Vec<(usize, usize)> {
+        self.base.edges
+            .keys()
+            .filter(|(a, b)| a <= b)
+            .cloned()
+            .collect()
+    }
+
+    fn node_data(&self, id: usize) -> Option<&T> {
+        self.base.nodes.get(&id)
+    }
+
+    fn edge_data(&self, from: usize, to: usize) -> Option<&()> {
+        self.base.edges.get(&(from, to))
+    }
+
+    fn neighbors(&self, node: usize) -> Vec<usize> {
+        self.adjacency
+            .get(&node)
+            .map(|set| set.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+impl<T> UndirectedGraph for UndirectedSimpleGraph<T> {}
+
+impl<T> ToDot for UndirectedSimpleGraph<T> {
+    fn to_dot(&self) -> Result<String, ExportError> {
+        let mut s = String::from("graph G {\n");
+
+        for (a, b) in self.edges() {
+            s.push_str(&format!("  {} -- {};\n", a, b));
+        }
+
+        s.push('}');
+        Ok(s)
+    }
+}
+
+impl<T> ToMermaid for UndirectedSimpleGraph<T> {
+    fn to_mermaid(&self) -> Result<String, ExportError> {
+        let mut s = String::from("graph TD\n");
+
+        for (a, b) in self.edges() {
+            s.push_str(&format!("  {} --- {}\n", a, b));
+        }
+
+        Ok(s)
+    }
+}
+
+impl<T> ToPlantUml for UndirectedSimpleGraph<T> {
+    fn to_plantuml(&self) -> Result<String, ExportError> {
+        let mut s = String::from("@startuml\n");
+
+        for (a, b) in self.edges() {
+            s.push_str(&format!("  {} -- {}\n", a, b));
+        }
+
+        s.push_str("@enduml");
+        Ok(s)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(mut v: Vec<usize>) -> Vec<usize> {
+        v.sort();
+        v
+    }
+
+    #[test]
+    fn test_new_graph() {
+        let graph: UndirectedSimpleGraph<i32> = UndirectedSimpleGraph::new();
+        assert!(graph.base.nodes.is_empty());
+        assert!(graph.base.edges.is_empty());
+        assert!(graph.adjacency.is_empty());
+    }
+
+    #[test]
+    fn test_add_node() {
+        let mut graph = UndirectedSimpleGraph::new();
+        let node_id = graph.add_node("test_data");
+
+        assert_eq!(node_id, 0);
+        assert_eq!(graph.node_data(node_id), Some(&"test_data"));
+        assert_eq!(graph.node_data(999), None);
+    }
+
+    #[test]
+    fn test_add_edge_is_symmetric() {
+        let mut graph = UndirectedSimpleGraph::new();
+        let node1 = graph.add_node("node1");
+        let node2 = graph.add_node("node2");
+
+        graph.add_edge(node1, node2);
+
+        assert_eq!(graph.edge_data(node1, node2), Some(&()));
+        assert_eq!(graph.edge_data(node2, node1), Some(&()));
+        assert_eq!(graph.neighbors(node1), vec![node2]);
+        assert_eq!(graph.neighbors(node2), vec![node1]);
+        // Una sola arista lógica
+        assert_eq!(graph.edges(), vec![(node1, node2)]);
+        assert_eq!(graph.edge_count(), 1);
+    }
+
+    #[test]
+    fn test_neighbors_and_degree() {
+        let mut graph = UndirectedSimpleGraph::new();
+        let nodes: Vec<usize> = (0..4).map(|i| graph.add_node(i)).collect();
+
+        graph.add_edge(nodes[0], nodes[1]);
+        graph.add_edge(nodes[0], nodes[2]);
+        graph.add_edge(nodes[2], nodes[3]);
+
+        assert_eq!(sorted(graph.neighbors(nodes[0])), vec![nodes[1], nodes[2]]);
+        assert_eq!(sorted(graph.neighbors(nodes[2])), vec![nodes[0], nodes[3]]);
+        assert_eq!(graph.degree(nodes[0]), 2);
+        assert_eq!(graph.degree(nodes[3]), 1);
+        assert_eq!(graph.neighbors(999), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_self_loop() {
+        let mut graph = UndirectedSimpleGraph::new();
+        let node1 = graph.add_node(1);
+
+        graph.add_edge(node1, node1);
+
+        assert_eq!(graph.neighbors(node1), vec![node1]);
+        assert_eq!(graph.edges(), vec![(node1, node1)]);
+    }
+
+    #[test]
+    fn test_remove_edge_and_node() {
+        let mut graph = UndirectedSimpleGraph::new();
+        let nodes: Vec<usize> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(nodes[0], nodes[1]);
+        graph.add_edge(nodes[1], nodes[2]);
+
+        // Se puede quitar en cualquier sentido
+        assert_eq!(graph.remove_edge(nodes[1], nodes[0]), Some(()));
+        assert_eq!(graph.edge_data(nodes[0], nodes[1]), None);
+        assert!(graph.neighbors(nodes[0]).is_empty());
+
+        assert_eq!(graph.remove_node(nodes[2]), Some(2));
+        assert!(graph.neighbors(nodes[1]).is_empty());
+        assert_eq!(graph.edge_count(), 0);
+        assert_eq!(graph.node_count(), 2);
+    }
+
+    #[test]
+    fn test_contains_node_and_edge() {
+        let mut graph = UndirectedSimpleGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        assert!(graph.contains_node(b));
+        assert!(!graph.contains_node(99));
+        assert!(graph.contains_edge(a, b));
+        assert!(graph.contains_edge(b, a)); // No dirigido: ambos sentidos
+        assert!(!graph.contains_edge(a, c));
+
+        graph.remove_edge(b, a);
+        assert!(!graph.contains_edge(a, b));
+        assert!(!graph.contains_edge(b, a));
+
+        graph.remove_node(c);
+        assert!(!graph.contains_node(c));
+        assert!(!graph.contains_edge(b, c));
+        assert!(!graph.contains_edge(c, b));
+    }
+}
@@ -1,7 +1,8 @@
 use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 use num_traits::{Num};
 use ordered_float::OrderedFloat;
-use crate::data_structures::graphs::{BaseGraph, Directed, GraphBase};
+use crate::data_structures::graphs::{BaseGraph, Directed, GraphBase, UndirectedGraph};
 use crate::data_structures::graphs::traits::WeightedGraph;
 use crate::data_structures::graphs::weighted::{IntoWeight, Weight};
 use crate::formatting::error::ExportError;
@@ -21,6 +22,54 @@ impl<N, E: Weight> UndirectedWeightedGraph<N, E> {
         }
     }
 
+    /// Cambia el peso de una arista existente (ambas entradas espejo de `base.edges`).
+    /// Devuelve el peso anterior, o error si la arista no existe: para crearla usar `add_edge_id`.
+    pub fn set_edge_weight<W>(&mut self, a: usize, b: usize, weight: W) -> Result<E, &'static str>
+    where W: IntoWeight<E>
+    {
+        let weight = weight.into_weight();
+        let previous = *self.base.edges.get(&(a, b)).ok_or("Edge not found")?;
+        self.base.edges.insert((a, b), weight);
+        self.base.edges.insert((b, a), weight);
+        Ok(previous)
+    }
+
+    /// Suma `delta` al peso de una arista existente. Devuelve el peso nuevo.
+    pub fn add_to_edge_weight<W>(&mut self, a: usize, b: usize, delta: W) -> Result<E, &'static str>
+    where W: IntoWeight<E>
+    {
+        let current = *self.base.edges.get(&(a, b)).ok_or("Edge not found")?;
+        let updated = current + delta.into_weight();
+        self.set_edge_weight(a, b, updated)?;
+        Ok(updated)
+    }
+
+    /// Elimina la arista `a -- b` (ambas entradas espejo y ambas listas de adyacencia).
+    /// Devuelve su peso, o `None` si no existía.
+    pub fn remove_edge(&mut self, a: usize, b: usize) -> Option<E> {
+        let removed = self.base.remove_edge(a, b);
+        self.base.remove_edge(b, a);
+        if let Some(neighbors) = self.adjacency.get_mut(&a) {
+            neighbors.remove(&b);
+        }
+        if let Some(neighbors) = self.adjacency.get_mut(&b) {
+            neighbors.remove(&a);
+        }
+        removed
+    }
+
+    /// Elimina el nodo junto con todas sus aristas incidentes.
+    pub fn remove_node(&mut self, id: usize) -> Option<N> {
+        if let Some(neighbors) = self.adjacency.remove(&id) {
+            for n in neighbors {
+                if let Some(set) = self.adjacency.get_mut(&n) {
+                    set.remove(&id);
+                }
+            }
+        }
+        self.base.remove_node(id)
+    }
+
     pub fn path_weight(&self, path: &[usize]) -> Option<E> {
         if path.len() < 2 {
             return Some(E::zero());
@@ -81,6 +130,8 @@ impl<N, E: Weight> GraphBase for UndirectedWeightedGraph<N, E> {
     }
 }
 
+impl<N, E: Weight> UndirectedGraph for UndirectedWeightedGraph<N, E> {}
+
 impl<N, E: Weight> WeightedGraph for UndirectedWeightedGraph<N, E> {
     fn edge_weight(&self, from: usize, to: usize) -> Option<E> {
         self.edge_data(from, to).copied()
@@ -129,6 +180,25 @@ impl<N: Default, E: Weight> UndirectedWeightedGraph<N, E> {
     }
 
 
+    /// Construye el grafo a partir de una lista de aristas `(a, b, peso)`, creando los
+    /// nodos a medida que aparecen. Devuelve también el mapa dato -> id de cada nodo.
+    pub fn from_edges<W>(edges: &[(N, N, W)]) -> (Self, HashMap<N, usize>)
+    where
+        W: IntoWeight<E> + Copy,
+        N: Clone + Eq + Hash,
+    {
+        let mut graph = Self::new();
+        let mut ids: HashMap<N, usize> = HashMap::new();
+
+        for (from, to, weight) in edges {
+            let from_id = *ids.entry(from.clone()).or_insert_with(|| graph.base.add_node(from.clone()));
+            let to_id = *ids.entry(to.clone()).or_insert_with(|| graph.base.add_node(to.clone()));
+            graph.add_weighted_edge(from_id, to_id, (*weight).into_weight());
+        }
+
+        (graph, ids)
+    }
+
     pub fn nodes(&self) -> Vec<usize> {
         self.base.nodes.keys().cloned().collect()
     }
@@ -147,10 +217,13 @@ impl<N: Default, E: Weight> UndirectedWeightedGraph<N, E> {
 
 }
 
-impl<N, E: Weight + std::fmt::Display> ToDot for UndirectedWeightedGraph<N, E> {
+impl<N: std::fmt::Display, E: Weight + std::fmt::Display> ToDot for UndirectedWeightedGraph<N, E> {
     fn to_dot(&self) -> Result<String, ExportError> {
         let mut s = String::from("graph G {\n");
 
+        for (id, label) in self.base.node_labels() {
+            s.push_str(&format!("  {} [label=\"{}\"];\n", id, label.replace('"', "\\\"")));
+        }
         for (from, to) in self.edges() {
             if let Some(weight) = self.edge_data(from, to) {
                 s.push_str(&format!("  {} -- {} [label=\"{}\"];\n", from, to, weight));
@@ -163,10 +236,13 @@ impl<N, E: Weight + std::fmt::Display> ToDot for UndirectedWeightedGraph<N, E> {
 }
 
 
-impl<N, E: Weight + std::fmt::Display> ToMermaid for UndirectedWeightedGraph<N, E> {
+impl<N: std::fmt::Display, E: Weight + std::fmt::Display> ToMermaid for UndirectedWeightedGraph<N, E> {
     fn to_mermaid(&self) -> Result<String, ExportError> {
         let mut s = String::from("graph TD\n");
 
+        for (id, label) in self.base.node_labels() {
+            s.push_str(&format!("  {}[\"{}\"]\n", id, label.replace('"', "#quot;")));
+        }
         for (from, to) in self.edges() {
             if let Some(weight) = self.edge_data(from, to) {
                 s.push_str(&format!("  {} ---|{}| {}\n", from, weight, to));
@@ -177,13 +253,17 @@ impl<N, E: Weight + std::fmt::Display> ToMermaid for UndirectedWeightedGraph<N,
     }
 }
 
-impl<N, E: Weight + std::fmt::Display> ToPlantUml for UndirectedWeightedGraph<N, E> {
+impl<N: std::fmt::Display, E: Weight + std::fmt::Display> ToPlantUml for UndirectedWeightedGraph<N, E> {
     fn to_plantuml(&self) -> Result<String, ExportError> {
         let mut s = String::from("@startuml\n");
 
+        // Los alias de PlantUML no pueden ser solo dígitos: n<id>
+        for (id, label) in self.base.node_labels() {
+            s.push_str(&format!("  rectangle \"{}\" as n{}\n", label.replace('"', "\\\""), id));
+        }
         for (from, to) in self.edges() {
             if let Some(weight) = self.edge_data(from, to) {
-                s.push_str(&format!("  {} -- {} : {}\n", from, to, weight));
+                s.push_str(&format!("  n{} -- n{} : {}\n", from, to, weight));
             }
         }
 
@@ -275,6 +355,70 @@ mod tests {
         assert_eq!(graph_f32.edge_weight(n1, n2), Some(1.5.into()));
     }
 
+    #[test]
+    fn test_set_and_increment_edge_weight() {
+        let mut graph: UndirectedWeightedGraph<&str, i32> = UndirectedWeightedGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge_id(a, b, 5);
+
+        assert_eq!(graph.set_edge_weight(b, a, 8), Ok(5));
+        assert_eq!(graph.edge_weight(a, b), Some(8));
+        assert_eq!(graph.edge_weight(b, a), Some(8));
+
+        assert_eq!(graph.add_to_edge_weight(a, b, -3), Ok(5));
+        assert_eq!(graph.edge_weight(a, b), Some(5));
+        assert_eq!(graph.edge_weight(b, a), Some(5));
+
+        // Arista inexistente: error y el grafo no cambia
+        assert!(graph.set_edge_weight(a, c, 1).is_err());
+        assert!(graph.add_to_edge_weight(c, b, 1).is_err());
+        assert_eq!(graph.edge_weight(a, c), None);
+        assert_eq!(graph.edge_count(), 1);
+    }
+
+    #[test]
+    fn test_remove_edge_and_node() {
+        let (mut graph, ids) = UndirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("A", "B", 4),
+            ("B", "C", 1),
+            ("A", "C", 7),
+        ]);
+        let (a, b, c) = (ids["A"], ids["B"], ids["C"]);
+
+        assert_eq!(graph.remove_edge(b, a), Some(4));
+        assert_eq!(graph.edge_weight(a, b), None);
+        assert_eq!(graph.edge_weight(b, a), None);
+        assert!(!graph.neighbors(a).contains(&b));
+        assert!(!graph.neighbors(b).contains(&a));
+        assert_eq!(graph.remove_edge(a, b), None);
+
+        assert_eq!(graph.remove_node(c), Some("C"));
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 0);
+        assert!(graph.neighbors(a).is_empty());
+        assert!(graph.neighbors(b).is_empty());
+        assert_eq!(graph.remove_node(c), None);
+    }
+
+    #[test]
+    fn test_from_edges() {
+        let (graph, ids) = UndirectedWeightedGraph::<&str, i32>::from_edges(&[
+            ("A", "B", 4),
+            ("B", "C", 1),
+            ("A", "C", 7),
+            ("C", "D", 2),
+        ]);
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(ids.len(), 4);
+        assert_eq!(graph.node_data(ids["C"]), Some(&"C"));
+        assert_eq!(graph.edge_weight(ids["B"], ids["A"]), Some(4));
+        assert_eq!(graph.edge_weight(ids["A"], ids["D"]), None);
+    }
+
     #[test]
     fn test_visualization() {
         let mut graph = UndirectedWeightedGraph::new();
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::hash::Hash;
 
 pub trait GraphBase {
@@ -22,6 +23,28 @@ pub trait GraphBase {
     fn has_node(&self, id: Self::NodeId) -> bool {
         self.node_data(id).is_some()
     }
+
+    // Pertenencia leyendo los mapas de cada implementación vía node_data/edge_data,
+    // sin recorrer nodes()/edges()
+    fn contains_node(&self, id: Self::NodeId) -> bool {
+        self.node_data(id).is_some()
+    }
+
+    fn contains_edge(&self, from: Self::NodeId, to: Self::NodeId) -> bool {
+        self.edge_data(from, to).is_some()
+    }
     
     fn neighbors(&self, node: Self::NodeId) -> Vec<Self::NodeId>;
+
+    /// Mismos ids de nodo y mismas aristas (no compara los datos de nodos ni aristas).
+    fn structurally_equal<O>(&self, other: &O) -> bool
+    where
+        O: GraphBase<NodeId = Self::NodeId>,
+    {
+        let nodes_a: HashSet<_> = self.nodes().into_iter().collect();
+        let nodes_b: HashSet<_> = other.nodes().into_iter().collect();
+        let edges_a: HashSet<_> = self.edges().into_iter().collect();
+        let edges_b: HashSet<_> = other.edges().into_iter().collect();
+        nodes_a == nodes_b && edges_a == edges_b
+    }
 }
\ No newline at end of file
@@ -11,13 +11,18 @@ struct AvlNode<T> {
 
 pub struct AvlTree<T> {
     root: Option<Box<AvlNode<T>>>,
+    size: usize,
 }
 impl<T: Ord + Clone + Display> AvlTree<T> {
     pub fn new() -> Self {
-        AvlTree { root: None }
+        AvlTree { root: None, size: 0 }
     }
 
     pub fn insert(&mut self, value: T) {
+        // Los duplicados se ignoran, así que solo cuentan los valores nuevos
+        if !self.contains(&value) {
+            self.size += 1;
+        }
         self.root = Some(Self::insert_node(self.root.take(), value));
     }
 
@@ -107,6 +112,47 @@ impl<T: Ord + Clone + Display> AvlTree<T> {
         node
     }
 
+    /* ============================
+     *       CONSULTAS PUNTUALES
+     * ============================ */
+    pub fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                std::cmp::Ordering::Less => &node.left,
+                std::cmp::Ordering::Greater => &node.right,
+                std::cmp::Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    /// Menor valor del árbol: el nodo más a la izquierda.
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some(&node.value)
+    }
+
+    /// Mayor valor del árbol: el nodo más a la derecha.
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some(&node.value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
     /* ============================
      *     IN-ORDER TRAVERSAL
      * ============================ */
@@ -127,6 +173,13 @@ impl<T: Ord + Clone + Display> AvlTree<T> {
         }
     }
 
+    /// Recorrido en orden perezoso, sin materializar el vector de `in_order`.
+    pub fn iter(&self) -> AvlIter<'_, T> {
+        let mut iter = AvlIter { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+
     /* ============================
      *     PRE-ORDER TRAVERSAL
      * ============================ */
@@ -265,6 +318,40 @@ impl<T: Ord + Clone + Display> AvlTree<T> {
     }
 }
 
+/// Iterador en orden sobre un `AvlTree`. La pila guarda el camino de nodos
+/// pendientes, así que ocupa O(altura) = O(log n).
+pub struct AvlIter<'a, T> {
+    stack: Vec<&'a AvlNode<T>>,
+}
+
+impl<'a, T> AvlIter<'a, T> {
+    fn push_left(&mut self, mut node: &'a Option<Box<AvlNode<T>>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = &current.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for AvlIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some(&node.value)
+    }
+}
+
+impl<'a, T: Ord + Clone + Display> IntoIterator for &'a AvlTree<T> {
+    type Item = &'a T;
+    type IntoIter = AvlIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 mod tests {
     use super::AvlTree;
 
@@ -278,4 +365,47 @@ mod tests {
         avl.insert(50);
         println!("{}", avl.print_tree())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_avl_point_queries() {
+        let mut avl = AvlTree::new();
+        assert!(avl.is_empty());
+        assert_eq!(avl.min(), None);
+        assert_eq!(avl.max(), None);
+
+        for value in [40, 10, 30, 50, 20, 30] {
+            avl.insert(value);
+        }
+
+        // El 30 repetido no se cuenta dos veces
+        assert_eq!(avl.len(), 5);
+        assert!(!avl.is_empty());
+        assert!(avl.contains(&20));
+        assert!(!avl.contains(&25));
+        assert_eq!(avl.min(), Some(&10));
+        assert_eq!(avl.max(), Some(&50));
+    }
+
+    #[test]
+    fn test_avl_iter_matches_in_order() {
+        let mut avl = AvlTree::new();
+        for value in [50, 20, 70, 10, 30, 60, 80, 25, 5] {
+            avl.insert(value);
+        }
+
+        let lazy: Vec<&i32> = avl.iter().collect();
+        assert_eq!(lazy, avl.in_order());
+
+        // Corta sin recorrer todo el árbol
+        assert_eq!(avl.iter().find(|&&v| v > 26), Some(&30));
+        assert_eq!(avl.iter().take(2).copied().collect::<Vec<_>>(), vec![5, 10]);
+
+        let mut total = 0;
+        for value in &avl {
+            total += value;
+        }
+        assert_eq!(total, 350);
+
+        assert_eq!(AvlTree::<i32>::new().iter().next(), None);
+    }
+}
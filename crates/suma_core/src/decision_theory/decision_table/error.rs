@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum DecisionError {
+    #[error("La tabla de decisión no tiene alternativas o estados")]
+    Empty,
+
+    #[error("La matriz de pagos es de {rows}x{cols}, pero hay {alternatives} alternativas y {states} estados")]
+    ShapeMismatch { rows: usize, cols: usize, alternatives: usize, states: usize },
+
+    #[error("Se esperaban {expected} probabilidades (una por estado), se recibieron {found}")]
+    ProbabilityCount { expected: usize, found: usize },
+
+    #[error("Las probabilidades deben ser no negativas y sumar 1 (suman {0})")]
+    InvalidProbabilities(f64),
+}
@@ -0,0 +1,5 @@
+pub mod model;
+pub mod error;
+
+pub use model::DecisionTable;
+pub use error::DecisionError;
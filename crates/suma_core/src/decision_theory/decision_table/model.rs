@@ -0,0 +1,190 @@
+use crate::linear_algebra::DenseMatrix;
+use super::error::DecisionError;
+
+/// Tolerancia al comprobar que las probabilidades de los estados suman 1.
+const PROBABILITY_TOLERANCE: f64 = 1e-9;
+
+/// Tabla de pagos para decisiones bajo incertidumbre: una fila por alternativa
+/// y una columna por estado de la naturaleza. Los pagos se maximizan.
+#[derive(Debug, Clone)]
+pub struct DecisionTable {
+    pub alternatives: Vec<String>,
+    pub states: Vec<String>,
+    pub payoffs: DenseMatrix<f64>,
+}
+
+impl DecisionTable {
+    pub fn new(
+        alternatives: Vec<String>,
+        states: Vec<String>,
+        payoffs: DenseMatrix<f64>,
+    ) -> Result<Self, DecisionError> {
+        if alternatives.is_empty() || states.is_empty() {
+            return Err(DecisionError::Empty);
+        }
+        if payoffs.rows != alternatives.len() || payoffs.cols != states.len() {
+            return Err(DecisionError::ShapeMismatch {
+                rows: payoffs.rows,
+                cols: payoffs.cols,
+                alternatives: alternatives.len(),
+                states: states.len(),
+            });
+        }
+        Ok(Self { alternatives, states, payoffs })
+    }
+
+    fn row(&self, alternative: usize) -> impl Iterator<Item = f64> + '_ {
+        (0..self.payoffs.cols).map(move |j| self.payoffs.get(alternative, j))
+    }
+
+    /// Alternativa con el mayor puntaje (la primera en caso de empate).
+    fn best_by(&self, score: impl Fn(usize) -> f64) -> (usize, f64) {
+        (0..self.payoffs.rows)
+            .map(|i| (i, score(i)))
+            .fold((0, f64::NEG_INFINITY), |best, (i, s)| if s > best.1 { (i, s) } else { best })
+    }
+
+    /// Valor esperado de cada alternativa con las probabilidades de los estados.
+    pub fn expected_value(&self, probs: &[f64]) -> Result<Vec<f64>, DecisionError> {
+        self.validate_probabilities(probs)?;
+        Ok((0..self.payoffs.rows)
+            .map(|i| self.row(i).zip(probs).map(|(payoff, p)| payoff * p).sum())
+            .collect())
+    }
+
+    /// Valor esperado de la información perfecta: el valor esperado conociendo de
+    /// antemano el estado (mejor pago de cada columna) menos el mejor valor esperado.
+    pub fn evpi(&self, probs: &[f64]) -> Result<f64, DecisionError> {
+        let best_expected = self.expected_value(probs)?.into_iter().fold(f64::NEG_INFINITY, f64::max);
+
+        let with_perfect_information: f64 = (0..self.payoffs.cols)
+            .map(|j| {
+                let best = (0..self.payoffs.rows).map(|i| self.payoffs.get(i, j)).fold(f64::NEG_INFINITY, f64::max);
+                best * probs[j]
+            })
+            .sum();
+
+        Ok(with_perfect_information - best_expected)
+    }
+
+    fn validate_probabilities(&self, probs: &[f64]) -> Result<(), DecisionError> {
+        if probs.len() != self.states.len() {
+            return Err(DecisionError::ProbabilityCount { expected: self.states.len(), found: probs.len() });
+        }
+        let sum: f64 = probs.iter().sum();
+        // NaN no cumple ninguna comparación: se rechaza explícitamente junto con ±inf
+        if probs.iter().any(|&p| !p.is_finite() || p < 0.0) || (sum - 1.0).abs() > PROBABILITY_TOLERANCE {
+            return Err(DecisionError::InvalidProbabilities(sum));
+        }
+        Ok(())
+    }
+
+    /// Criterio optimista: la alternativa con el mejor pago posible.
+    pub fn maximax(&self) -> (usize, f64) {
+        self.best_by(|i| self.row(i).fold(f64::NEG_INFINITY, f64::max))
+    }
+
+    /// Criterio pesimista (Wald): la alternativa cuyo peor pago es el mayor.
+    pub fn maximin(&self) -> (usize, f64) {
+        self.best_by(|i| self.row(i).fold(f64::INFINITY, f64::min))
+    }
+
+    /// Criterio de Savage: minimiza el arrepentimiento máximo. Devuelve ese arrepentimiento.
+    pub fn minimax_regret(&self) -> (usize, f64) {
+        let best_per_state: Vec<f64> = (0..self.payoffs.cols)
+            .map(|j| (0..self.payoffs.rows).map(|i| self.payoffs.get(i, j)).fold(f64::NEG_INFINITY, f64::max))
+            .collect();
+
+        let (index, neg_regret) = self.best_by(|i| {
+            let max_regret = self.row(i)
+                .zip(&best_per_state)
+                .map(|(payoff, best)| best - payoff)
+                .fold(0.0, f64::max);
+            -max_regret
+        });
+        (index, -neg_regret)
+    }
+
+    /// Criterio de Hurwicz: `alpha · máximo + (1 - alpha) · mínimo` de cada alternativa.
+    /// `alpha` es el coeficiente de optimismo y se acota a [0, 1].
+    pub fn hurwicz(&self, alpha: f64) -> (usize, f64) {
+        let alpha = alpha.clamp(0.0, 1.0);
+        self.best_by(|i| {
+            let max = self.row(i).fold(f64::NEG_INFINITY, f64::max);
+            let min = self.row(i).fold(f64::INFINITY, f64::min);
+            alpha * max + (1.0 - alpha) * min
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ejemplo clásico: construir una planta grande, pequeña o no construir,
+    /// con mercado favorable o desfavorable.
+    fn plant_table() -> DecisionTable {
+        let payoffs = DenseMatrix::from_rows(&[
+            vec![200_000.0, -180_000.0],
+            vec![100_000.0, -20_000.0],
+            vec![0.0, 0.0],
+        ]).unwrap();
+        DecisionTable::new(
+            vec!["Planta grande".into(), "Planta pequeña".into(), "No hacer nada".into()],
+            vec!["Favorable".into(), "Desfavorable".into()],
+            payoffs,
+        ).unwrap()
+    }
+
+    #[test]
+    fn test_uncertainty_criteria() {
+        let table = plant_table();
+        assert_eq!(table.maximax(), (0, 200_000.0));
+        assert_eq!(table.maximin(), (2, 0.0));
+        assert_eq!(table.minimax_regret(), (1, 100_000.0));
+        // alpha = 0.8: grande 124000, pequeña 76000, nada 0
+        let (index, value) = table.hurwicz(0.8);
+        assert_eq!(index, 0);
+        assert!((value - 124_000.0).abs() < 1e-9);
+        // Los extremos de alpha coinciden con maximin y maximax
+        assert_eq!(table.hurwicz(0.0), table.maximin());
+        assert_eq!(table.hurwicz(1.0), table.maximax());
+    }
+
+    #[test]
+    fn test_expected_value() {
+        let table = plant_table();
+        assert_eq!(table.expected_value(&[0.5, 0.5]), Ok(vec![10_000.0, 40_000.0, 0.0]));
+        // Las entradas sobrantes ya no se ignoran en silencio
+        assert_eq!(
+            table.expected_value(&[0.5, 0.5, 0.0]),
+            Err(DecisionError::ProbabilityCount { expected: 2, found: 3 })
+        );
+    }
+
+    #[test]
+    fn test_evpi() {
+        let table = plant_table();
+        // Con certeza: 0.5 · 200000 + 0.5 · 0 = 100000; mejor VME: 40000
+        assert!((table.evpi(&[0.5, 0.5]).unwrap() - 60_000.0).abs() < 1e-9);
+
+        assert_eq!(
+            table.evpi(&[1.0]),
+            Err(DecisionError::ProbabilityCount { expected: 2, found: 1 })
+        );
+        assert_eq!(table.evpi(&[0.6, 0.6]), Err(DecisionError::InvalidProbabilities(1.2)));
+        assert!(matches!(table.evpi(&[1.5, -0.5]), Err(DecisionError::InvalidProbabilities(_))));
+        assert!(matches!(table.evpi(&[f64::NAN, 1.0]), Err(DecisionError::InvalidProbabilities(_))));
+        assert!(matches!(table.evpi(&[f64::INFINITY, 0.0]), Err(DecisionError::InvalidProbabilities(_))));
+    }
+
+    #[test]
+    fn test_invalid_tables() {
+        let payoffs = DenseMatrix::from_rows(&[vec![1.0, 2.0]]).unwrap();
+        assert_eq!(
+            DecisionTable::new(vec!["a".into(), "b".into()], vec!["s1".into(), "s2".into()], payoffs.clone()).unwrap_err(),
+            DecisionError::ShapeMismatch { rows: 1, cols: 2, alternatives: 2, states: 2 }
+        );
+        assert_eq!(DecisionTable::new(vec![], vec!["s1".into()], payoffs).unwrap_err(), DecisionError::Empty);
+    }
+}
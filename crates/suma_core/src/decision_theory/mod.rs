@@ -1 +1,4 @@
-pub mod decision_tree;
\ No newline at end of file
+pub mod decision_tree;
+pub mod decision_table;
+
+pub use decision_table::{DecisionError, DecisionTable};
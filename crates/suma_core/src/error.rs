@@ -1,7 +1,12 @@
 use core::error;
 
+/// Error de alto nivel del crate. Cada módulo conserva su propio tipo de error y
+/// aquí se convierte con `From`, para que `?` funcione entre módulos.
 #[derive(Debug, thiserror::Error)]
 pub enum SumaError {
+    #[error(transparent)]
+    BooleanAlgebra(#[from] crate::boolean_algebra::BooleanAlgebraError),
+
     #[error(transparent)]
     Network(#[from] crate::networking::NetworkError),
 
@@ -11,9 +16,89 @@ pub enum SumaError {
     #[error(transparent)]
     SymbolicsEval(#[from] crate::symbolics::error::EvalError),
 
+    #[error(transparent)]
+    SymbolicsParse(#[from] crate::symbolics::error::ParseError),
+
     #[error(transparent)]
     LinearAlgebra(#[from] crate::linear_algebra::error::LinearAlgebraError),
 
     #[error(transparent)]
     Optimization(#[from] crate::optimization::error::OptimizationError),
-}
\ No newline at end of file
+
+    #[error(transparent)]
+    BayesianNetwork(#[from] crate::probability::bayes::BNError),
+
+    #[error(transparent)]
+    Conversion(#[from] crate::conversions::ConversionError),
+
+    #[error(transparent)]
+    Decision(#[from] crate::decision_theory::DecisionError),
+}
+
+// Los errores del simplex llegan envueltos en `OptimizationError`, igual que si
+// vinieran del módulo de optimización.
+impl From<crate::optimization::linear::error::LinearOptimizationError> for SumaError {
+    fn from(error: crate::optimization::linear::error::LinearOptimizationError) -> Self {
+        SumaError::Optimization(error.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::boolean_algebra::BooleanExpr;
+    use crate::linear_algebra::DenseMatrix;
+    use crate::linear_algebra::error::LinearAlgebraError;
+    use crate::optimization::linear::error::LinearOptimizationError;
+    use crate::conversions::NumberConverter;
+    use crate::decision_theory::DecisionTable;
+
+    fn parse_boolean(input: &str) -> Result<usize, SumaError> {
+        let expr = BooleanExpr::new(input)?;
+        Ok(expr.variables.len())
+    }
+
+    fn build_matrix(rows: &[Vec<f64>]) -> Result<usize, SumaError> {
+        let matrix = DenseMatrix::from_rows(rows)?;
+        Ok(matrix.rows)
+    }
+
+    #[test]
+    fn test_propagates_boolean_parse_error() {
+        assert_eq!(parse_boolean("A & B").unwrap(), 2);
+        let error = parse_boolean("").unwrap_err();
+        assert!(matches!(error, SumaError::BooleanAlgebra(_)));
+        assert_eq!(error.to_string(), "Error de análisis: Expresión vacía");
+    }
+
+    #[test]
+    fn test_propagates_matrix_dimension_error() {
+        let error = build_matrix(&[vec![1.0, 2.0], vec![3.0]]).unwrap_err();
+        assert!(matches!(error, SumaError::LinearAlgebra(LinearAlgebraError::DimensionMismatch { .. })));
+    }
+
+    fn to_fraction(value: f64) -> Result<(i64, i64), SumaError> {
+        Ok(NumberConverter::to_fraction(value, 1000)?)
+    }
+
+    fn decide(probs: &[f64]) -> Result<f64, SumaError> {
+        let payoffs = DenseMatrix::from_rows(&[vec![10.0, 0.0], vec![4.0, 4.0]])?;
+        let table = DecisionTable::new(vec!["a".into(), "b".into()], vec!["s1".into(), "s2".into()], payoffs)?;
+        Ok(table.evpi(probs)?)
+    }
+
+    #[test]
+    fn test_propagates_conversion_and_decision_errors() {
+        assert!(matches!(to_fraction(f64::NAN).unwrap_err(), SumaError::Conversion(_)));
+        assert!(matches!(decide(&[1.0]).unwrap_err(), SumaError::Decision(_)));
+
+        let error: SumaError = crate::probability::bayes::BNError::Cycle.into();
+        assert!(matches!(error, SumaError::BayesianNetwork(_)));
+    }
+
+    #[test]
+    fn test_linear_optimization_error_is_wrapped() {
+        let error: SumaError = LinearOptimizationError::Infeasible.into();
+        assert!(matches!(error, SumaError::Optimization(_)));
+    }
+}
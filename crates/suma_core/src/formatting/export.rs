@@ -1,8 +1,46 @@
-use csv::Writer;
+use std::io::Write;
+use csv::{Writer, WriterBuilder};
+use rust_xlsxwriter::Workbook;
 use serde::Serialize;
-use serde_json::{to_string_pretty, to_value, Value};
+use serde_json::{to_string, to_string_pretty, to_value, Value};
 use crate::formatting::error::ExportError;
-use crate::formatting::utils::{build_hierarchical_excel, build_hierarchical_markdown, build_markdown_table};
+pub use crate::formatting::options::{Alignment, ExcelOptions, ExportOptions};
+use crate::formatting::utils::{add_excel_sheets, build_csv_table, build_csv_table_with, build_hierarchical_excel, build_hierarchical_excel_with, build_html_table, build_latex_table, build_hierarchical_markdown, build_markdown_table_with, build_yaml, flatten_value, has_nested_objects};
+
+/// Libro Excel con varias hojas, una por cada llamada a `add_sheet`.
+/// Permite juntar en un mismo archivo datos de distinto tipo.
+pub struct ExcelWorkbook {
+    workbook: Workbook,
+}
+
+impl Default for ExcelWorkbook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExcelWorkbook {
+    pub fn new() -> Self {
+        Self { workbook: Workbook::new() }
+    }
+
+    /// Agrega `data` como una hoja nueva (más las de sus objetos anidados).
+    /// Falla si el nombre de la hoja ya existe o no es válido para Excel.
+    pub fn add_sheet<T: Serialize>(&mut self, data: &T, options: &ExcelOptions) -> Result<&mut Self, ExportError> {
+        // rust_xlsxwriter solo detecta los nombres repetidos al guardar
+        if self.workbook.worksheet_from_name(&options.sheet_name).is_ok() {
+            return Err(ExportError::ExcelError(format!("La hoja '{}' ya existe", options.sheet_name)));
+        }
+        let value = to_value(data)?;
+        add_excel_sheets(&mut self.workbook, &value, options)?;
+        Ok(self)
+    }
+
+    pub fn save(&mut self, path: &str) -> Result<(), ExportError> {
+        self.workbook.save(path)?;
+        Ok(())
+    }
+}
 
 /// Trait que proporciona métodos para exportar datos serializable a diferentes formatos.
 pub trait Exportable: Serialize {
@@ -13,31 +51,126 @@ pub trait Exportable: Serialize {
         Ok(to_string_pretty(self)?)
     }
 
+    /// Exporta el objeto a JSON Lines (NDJSON): un objeto JSON compacto por línea.
+    /// Si el valor no es un array, se emite una única línea.
+    fn to_ndjson(&self) -> Result<String, ExportError> {
+        let value = to_value(self)?;
+        let mut output = String::new();
+        match value {
+            Value::Array(arr) => {
+                for item in &arr {
+                    output.push_str(&to_string(item)?);
+                    output.push('\n');
+                }
+            }
+            other => {
+                output.push_str(&to_string(&other)?);
+                output.push('\n');
+            }
+        }
+        Ok(output)
+    }
+
     /// Exporta el objeto a una cadena CSV.
+    /// Los objetos anidados se aplanan en columnas punteadas (`address.city`).
     fn to_csv(&self) -> Result<String, ExportError> {
-        let mut wtr = Writer::from_writer(vec![]);
+        self.to_csv_with(b',')
+    }
+
+    /// Exporta el objeto a CSV con el separador indicado (p. ej. `b';'`).
+    /// Los objetos anidados se aplanan en columnas punteadas (`address.city`).
+    fn to_csv_with(&self, delimiter: u8) -> Result<String, ExportError> {
+        let value = to_value(self)?;
+        if has_nested_objects(&value) {
+            return match flatten_value(&value) {
+                Value::Array(arr) => build_csv_table_with(&arr, delimiter),
+                other => build_csv_table_with(&[other], delimiter),
+            };
+        }
+
+        let mut wtr = WriterBuilder::new().delimiter(delimiter).from_writer(vec![]);
         wtr.serialize(self)?;
         let data = wtr.into_inner()?;
         Ok(String::from_utf8(data)?)
     }
 
+    /// Exporta el objeto a CSV según las opciones dadas.
+    /// Sin opciones activas es equivalente a `to_csv`.
+    fn to_csv_with_options(&self, options: &ExportOptions) -> Result<String, ExportError> {
+        if !options.flatten_nested {
+            return self.to_csv();
+        }
+        let value = flatten_value(&to_value(self)?);
+        match value {
+            Value::Array(arr) => build_csv_table(&arr),
+            other => build_csv_table(&[other]),
+        }
+    }
+
     /// Exporta el objeto a una tabla Markdown (versión plana - compatibilidad).
     fn to_markdown(&self) -> Result<String, ExportError> {
-        let value = to_value(self)?;
+        self.to_markdown_with_options(&ExportOptions::default())
+    }
+
+    /// Exporta el objeto a una tabla Markdown según las opciones dadas:
+    /// aplanado de objetos anidados, alineación de columnas y decimales.
+    fn to_markdown_with_options(&self, options: &ExportOptions) -> Result<String, ExportError> {
+        let mut value = to_value(self)?;
+        if options.flatten_nested {
+            value = flatten_value(&value);
+        }
         match value {
-            Value::Array(arr) if !arr.is_empty() => build_markdown_table(&arr),
-            Value::Array(arr) if arr.is_empty() => Ok(String::from("# Empty Data")),
-            Value::Object(obj) => build_markdown_table(&[Value::Object(obj)]),
+            Value::Array(arr) if !arr.is_empty() => build_markdown_table_with(&arr, options),
+            Value::Array(_) => Ok(String::from("# Empty Data")),
+            Value::Object(obj) => build_markdown_table_with(&[Value::Object(obj)], options),
             _ => Ok(String::from("# Data\n\nNo se puede convertir a tabla markdown")),
         }
     }
 
+    /// Exporta el objeto a YAML en estilo bloque.
+    /// Las claves de los objetos salen en orden alfabético, como en las tablas.
+    fn to_yaml(&self) -> Result<String, ExportError> {
+        Ok(build_yaml(&to_value(self)?))
+    }
+
+    /// Exporta el objeto a una tabla HTML (`<table>` con `<thead>` y `<tbody>`).
+    fn to_html(&self) -> Result<String, ExportError> {
+        let value = to_value(self)?;
+        match value {
+            Value::Array(arr) => build_html_table(&arr),
+            Value::Object(obj) => build_html_table(&[Value::Object(obj)]),
+            _ => Ok(String::from("<p>No se puede convertir a tabla HTML</p>\n")),
+        }
+    }
+
+    /// Exporta el objeto a un entorno `tabular` de LaTeX, escapando los caracteres especiales.
+    fn to_latex_table(&self) -> Result<String, ExportError> {
+        let value = to_value(self)?;
+        match value {
+            Value::Array(arr) => build_latex_table(&arr),
+            Value::Object(obj) => build_latex_table(&[Value::Object(obj)]),
+            _ => Ok(String::from("% No se puede convertir a tabla LaTeX\n")),
+        }
+    }
+
     /// Exporta el objeto a un archivo Excel (versión plana - compatibilidad).
     fn to_excel(&self, path: &str) -> Result<(), ExportError> where Self: Sized {
         let value = serde_json::to_value(self)?;
         build_hierarchical_excel(&value, path, "Data")
     }
 
+    /// Exporta el objeto a un archivo Excel con `sheet_name` como hoja principal.
+    /// Los objetos anidados van a hojas propias con nombre `"{sheet_name} - {campo}"`.
+    fn to_excel_sheets(&self, path: &str, sheet_name: &str) -> Result<(), ExportError> where Self: Sized {
+        self.to_excel_with_options(path, &ExcelOptions::new().sheet_name(sheet_name))
+    }
+
+    /// Exporta el objeto a un archivo Excel con nombre de hoja y formato numérico a medida.
+    fn to_excel_with_options(&self, path: &str, options: &ExcelOptions) -> Result<(), ExportError> where Self: Sized {
+        let value = serde_json::to_value(self)?;
+        build_hierarchical_excel_with(&value, path, options)
+    }
+
     // ==================== MÉTODOS JERÁRQUICOS NUEVOS ====================
 
     /// Exporta el objeto a Markdown con estructura jerárquica
@@ -50,4 +183,22 @@ pub trait Exportable: Serialize {
 
 // Utils functions
 
-impl<T> Exportable for T where T: Serialize {}
\ No newline at end of file
+impl<T> Exportable for T where T: Serialize {}
+
+/// Exporta registros a CSV de forma incremental, escribiendo uno a la vez en `w`.
+/// A diferencia de `to_csv`, no construye el documento completo en memoria.
+pub fn export_csv_to_writer<'a, T, W>(
+    iter: impl Iterator<Item = &'a T>,
+    w: W,
+) -> Result<(), ExportError>
+where
+    T: Serialize + 'a,
+    W: Write,
+{
+    let mut wtr = Writer::from_writer(w);
+    for record in iter {
+        wtr.serialize(record)?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
\ No newline at end of file
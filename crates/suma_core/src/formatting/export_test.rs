@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use serde::Serialize;
-    use crate::formatting::export::Exportable;
+    use crate::formatting::export::{export_csv_to_writer, Alignment, ExcelOptions, ExcelWorkbook, ExportOptions, Exportable};
     
     use std::fs;
 
@@ -69,6 +69,183 @@ mod tests {
         assert!(json_str.contains("null"));
     }
 
+    // Tests de NDJSON
+    #[test]
+    fn test_ndjson_export_array() {
+        let users: Vec<User> = (0..5)
+            .map(|i| User {
+                name: format!("User_{}", i),
+                age: 20 + i,
+                active: i % 2 == 0,
+                salary: None,
+            })
+            .collect();
+
+        let ndjson = users.to_ndjson().unwrap();
+        let lines: Vec<&str> = ndjson.lines().collect();
+        assert_eq!(lines.len(), users.len());
+
+        for (i, line) in lines.iter().enumerate() {
+            let parsed: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(parsed["name"], format!("User_{}", i));
+        }
+    }
+
+    #[test]
+    fn test_ndjson_export_single_object() {
+        let user = User {
+            name: "Alice".to_string(),
+            age: 30,
+            active: true,
+            salary: Some(50000.0),
+        };
+
+        let ndjson = user.to_ndjson().unwrap();
+        assert_eq!(ndjson.lines().count(), 1);
+        assert!(serde_json::from_str::<serde_json::Value>(ndjson.trim_end()).is_ok());
+    }
+
+    #[derive(Serialize)]
+    struct Address {
+        city: String,
+        zip: String,
+    }
+
+    #[derive(Serialize)]
+    struct Customer {
+        name: String,
+        address: Address,
+    }
+
+    fn sample_customers() -> Vec<Customer> {
+        vec![
+            Customer {
+                name: "Alice".to_string(),
+                address: Address { city: "Managua".to_string(), zip: "11001".to_string() },
+            },
+            Customer {
+                name: "Bob".to_string(),
+                address: Address { city: "León".to_string(), zip: "21000".to_string() },
+            },
+        ]
+    }
+
+    #[test]
+    fn test_markdown_flattened_nested_struct() {
+        let customers = sample_customers();
+
+        // Comportamiento por defecto: el objeto anidado no se expande
+        let plain = customers.to_markdown().unwrap();
+        assert!(!plain.contains("address.city"));
+
+        let md = customers.to_markdown_with_options(&ExportOptions::flattened()).unwrap();
+        assert!(md.contains("| address.city | address.zip | name |"));
+        assert!(md.contains("| Managua | 11001 | Alice |"));
+    }
+
+    #[test]
+    fn test_markdown_flattened_with_alignment() {
+        // El aplanado y el formato de columnas se combinan en las mismas opciones
+        let options = ExportOptions::flattened().align("address.zip", Alignment::Right);
+        let md = sample_customers().to_markdown_with_options(&options).unwrap();
+        let lines: Vec<&str> = md.lines().collect();
+        assert_eq!(lines[0], "| address.city | address.zip | name |");
+        assert_eq!(lines[1], "|---|---:|---|");
+        assert_eq!(lines[2], "| Managua | 11001 | Alice |");
+    }
+
+    #[test]
+    fn test_csv_flattened_nested_struct() {
+        let customers = sample_customers();
+
+        let csv_str = customers.to_csv_with_options(&ExportOptions::flattened()).unwrap();
+        let lines: Vec<&str> = csv_str.lines().collect();
+        assert_eq!(lines[0], "address.city,address.zip,name");
+        assert_eq!(lines[1], "Managua,11001,Alice");
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_csv_nested_struct_flattens_by_default() {
+        let customers = sample_customers();
+
+        let csv_str = customers.to_csv().unwrap();
+        assert_eq!(csv_str.lines().next(), Some("address.city,address.zip,name"));
+        assert_eq!(csv_str, customers.to_csv_with_options(&ExportOptions::flattened()).unwrap());
+    }
+
+    #[test]
+    fn test_csv_custom_delimiter() {
+        let users = vec![User {
+            name: "Alice; Jr.".to_string(),
+            age: 30,
+            active: true,
+            salary: Some(1234.5),
+        }];
+
+        // Sin anidamiento se mantiene el orden de los campos del struct
+        let csv_str = users.to_csv_with(b';').unwrap();
+        let lines: Vec<&str> = csv_str.lines().collect();
+        assert_eq!(lines[0], "name;age;active;salary");
+        assert_eq!(lines[1], "\"Alice; Jr.\";30;true;1234.5");
+
+        let nested = sample_customers().to_csv_with(b';').unwrap();
+        assert_eq!(nested.lines().nth(1), Some("Managua;11001;Alice"));
+    }
+
+    #[test]
+    fn test_markdown_inferred_alignment() {
+        let users = vec![User {
+            name: "Alice".to_string(),
+            age: 30,
+            active: true,
+            salary: Some(50000.5),
+        }];
+
+        let md = users.to_markdown_with_options(&ExportOptions::new().infer_alignment()).unwrap();
+        let lines: Vec<&str> = md.lines().collect();
+
+        // Columnas ordenadas: active | age | name | salary
+        assert_eq!(lines[0], "| active | age | name | salary |");
+        assert_eq!(lines[1], "|:---:|---:|:---|---:|");
+    }
+
+    #[test]
+    fn test_markdown_explicit_alignment_overrides_inferred() {
+        let users = vec![User {
+            name: "Alice".to_string(),
+            age: 30,
+            active: true,
+            salary: None,
+        }];
+
+        let options = ExportOptions::new()
+            .infer_alignment()
+            .align("age", Alignment::Center);
+        let md = users.to_markdown_with_options(&options).unwrap();
+
+        // salary solo tiene nulls: alineación a la izquierda por defecto
+        assert!(md.contains("|:---:|:---:|:---|:---|"));
+    }
+
+    #[test]
+    fn test_markdown_decimal_places() {
+        let users = vec![User {
+            name: "Bob".to_string(),
+            age: 41,
+            active: false,
+            salary: Some(1234.56789),
+        }];
+
+        let md = users.to_markdown_with_options(&ExportOptions::new().decimal_places(2)).unwrap();
+        assert!(md.contains("| 1234.57 |"));
+        assert!(!md.contains("1234.56789"));
+        // Los enteros no se ven afectados
+        assert!(md.contains("| 41 |"));
+        // Sin alineación solicitada se mantiene el separador plano
+        assert!(md.contains("|---|---|---|---|"));
+    }
+
     // Tests de CSV
     #[test]
     fn test_csv_export() {
@@ -174,6 +351,102 @@ mod tests {
         assert!(md_str.contains("Empty Data"));
     }
 
+    // Tests de HTML
+    #[test]
+    fn test_yaml_export() {
+        let users = vec![
+            User { name: "Alice \"A\"".to_string(), age: 30, active: true, salary: Some(1234.5) },
+            User { name: "Bob".to_string(), age: 25, active: false, salary: None },
+        ];
+
+        let yaml = users.to_yaml().unwrap();
+        assert_eq!(
+            yaml,
+            "- active: true\n  age: 30\n  name: \"Alice \\\"A\\\"\"\n  salary: 1234.5\n\
+             - active: false\n  age: 25\n  name: \"Bob\"\n  salary: null\n"
+        );
+
+        let nested = serde_json::json!({"true": 0.8, "matrix": [[1, 2], []], "empty": {}});
+        assert_eq!(
+            nested.to_yaml().unwrap(),
+            "empty: {}\nmatrix:\n  - - 1\n    - 2\n  - []\n\"true\": 0.8\n"
+        );
+    }
+
+    #[test]
+    fn test_html_export() {
+        let users = vec![
+            User {
+                name: "Charlie <admin>".to_string(),
+                age: 35,
+                active: true,
+                salary: Some(60000.0),
+            },
+            User {
+                name: "Diana & co".to_string(),
+                age: 28,
+                active: false,
+                salary: None,
+            },
+        ];
+
+        let html = users.to_html().unwrap();
+        assert!(html.starts_with("<table>\n  <thead>\n"));
+        assert!(html.contains("<tr><th>active</th><th>age</th><th>name</th><th>salary</th></tr>"));
+        assert!(html.contains("<tr><td>true</td><td>35</td><td>Charlie &lt;admin&gt;</td><td>60000.0</td></tr>"));
+        assert!(html.contains("<tr><td>false</td><td>28</td><td>Diana &amp; co</td><td>null</td></tr>"));
+        assert!(html.trim_end().ends_with("</tbody>\n</table>"));
+    }
+
+    #[test]
+    fn test_html_single_object_and_empty() {
+        let user = User {
+            name: "Eve".to_string(),
+            age: 40,
+            active: true,
+            salary: Some(70000.0),
+        };
+        assert_eq!(user.to_html().unwrap().matches("<tr>").count(), 2);
+
+        let users: Vec<User> = vec![];
+        assert!(users.to_html().unwrap().contains("Empty Data"));
+    }
+
+    // Tests de LaTeX
+    #[test]
+    fn test_latex_table_export() {
+        let products = vec![
+            Product {
+                id: 1,
+                name: "Tornillo_M3 #10".to_string(),
+                price: 0.5,
+                category: "Ferretería & más".to_string(),
+                in_stock: true,
+            },
+            Product {
+                id: 2,
+                name: "Descuento 20%".to_string(),
+                price: 12.0,
+                category: "Ofertas".to_string(),
+                in_stock: false,
+            },
+        ];
+
+        let latex = products.to_latex_table().unwrap();
+        let expected = "\\begin{tabular}{|l|r|c|l|r|}\n\
+                        \\hline\n\
+                        category & id & in\\_stock & name & price \\\\\n\
+                        \\hline\n\
+                        Ferretería \\& más & 1 & true & Tornillo\\_M3 \\#10 & 0.5 \\\\\n\
+                        Ofertas & 2 & false & Descuento 20\\% & 12.0 \\\\\n\
+                        \\hline\n\
+                        \\end{tabular}\n";
+        assert_eq!(latex, expected);
+
+        let empty: Vec<Product> = vec![];
+        assert!(empty.to_latex_table().unwrap().contains("Empty Data"));
+    }
+
     #[test]
     fn test_markdown_with_mixed_data() {
         let data = MixedData {
@@ -288,6 +561,67 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_excel_named_sheet_with_number_format() {
+        let products = vec![
+            Product {
+                id: 1,
+                name: "Laptop".to_string(),
+                price: 12345.67,
+                category: "Electronics".to_string(),
+                in_stock: true,
+            },
+        ];
+
+        let path = "test_named_sheet.xlsx";
+        let options = ExcelOptions::new()
+            .sheet_name("Inventario")
+            .decimal_places(2)
+            .column_format("id", "0");
+        assert_eq!(options.number_format.as_deref(), Some("0.00"));
+
+        let result = products.to_excel_with_options(path, &options);
+        let _ = fs::remove_file(path);
+        assert!(result.is_ok());
+
+        let path = "test_excel_sheets.xlsx";
+        let result = products.to_excel_sheets(path, "Productos");
+        let _ = fs::remove_file(path);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_excel_workbook_multiple_sheets() {
+        let products = vec![Product {
+            id: 1,
+            name: "Mouse".to_string(),
+            price: 25.5,
+            category: "Electronics".to_string(),
+            in_stock: true,
+        }];
+        let users = vec![User {
+            name: "Alice".to_string(),
+            age: 30,
+            active: true,
+            salary: Some(50000.0),
+        }];
+
+        let mut workbook = ExcelWorkbook::new();
+        workbook
+            .add_sheet(&products, &ExcelOptions::new().sheet_name("Productos").number_format("#,##0.00"))
+            .unwrap()
+            .add_sheet(&users, &ExcelOptions::new().sheet_name("Usuarios"))
+            .unwrap();
+
+        // Un nombre repetido es un error de Excel
+        assert!(workbook.add_sheet(&users, &ExcelOptions::new().sheet_name("Usuarios")).is_err());
+
+        let path = "test_workbook_sheets.xlsx";
+        let result = workbook.save(path);
+        let _ = fs::remove_file(path);
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_excel_export_empty_array() {
         let empty_users: Vec<User> = vec![];
@@ -417,6 +751,27 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_csv_streaming_export() {
+        let users: Vec<User> = (0..10_000)
+            .map(|i| User {
+                name: format!("User_{}", i),
+                age: 20 + (i % 40),
+                active: i % 2 == 0,
+                salary: if i % 3 == 0 { None } else { Some(30000.0 + i as f64) },
+            })
+            .collect();
+
+        let mut buffer: Vec<u8> = Vec::new();
+        export_csv_to_writer(users.iter(), &mut buffer).unwrap();
+
+        let csv_str = String::from_utf8(buffer).unwrap();
+        // Cabecera + un registro por usuario
+        assert_eq!(csv_str.lines().count(), 10_001);
+        assert!(csv_str.starts_with("name,age,active,salary"));
+        assert!(csv_str.contains("User_9999"));
+    }
 }
 
 // tests/export_hierarchical_tests.rs
@@ -427,7 +782,7 @@ mod export_hierarchical_tests {use std::collections::HashMap;
     use serde_json::Value;
     use serde_json::map::Map;
     use tempfile::NamedTempFile;
-    use crate::formatting::export::Exportable;
+    use crate::formatting::export::{ExportOptions, Exportable};
     use crate::formatting::utils::build_markdown_table;
 
     // Structs de prueba para testing
@@ -865,6 +1220,60 @@ mod export_hierarchical_tests {use std::collections::HashMap;
         assert!(headers.contains(&"value"));
     }
 
+    fn heterogeneous_rows() -> Vec<Value> {
+        vec![
+            serde_json::json!({"name": "Alice", "score": 9.5}),
+            serde_json::json!({"age": 30, "name": "Bob"}),
+            serde_json::json!({"city": "Managua", "score": 7.0}),
+        ]
+    }
+
+    #[test]
+    fn test_get_unique_headers_first_seen_order() {
+        let data = heterogeneous_rows();
+
+        // Orden de primera aparición, no alfabético
+        for _ in 0..5 {
+            let headers = crate::formatting::utils::get_unique_headers(&data);
+            assert_eq!(headers, vec!["name", "score", "age", "city"]);
+        }
+    }
+
+    #[derive(Serialize)]
+    struct Reading {
+        zone: String,
+        value: f64,
+        active: bool,
+    }
+
+    #[test]
+    fn test_struct_fields_sorted_within_object() {
+        // Dentro de un objeto las claves salen en orden alfabético, no en el de
+        // declaración (serde_json sin `preserve_order`); entre filas, primera aparición
+        let readings = vec![Reading { zone: "A".to_string(), value: 1.5, active: true }];
+        let md = readings.to_markdown().unwrap();
+        assert_eq!(md.lines().next().unwrap(), "| active | value | zone |");
+
+        let mut rows = vec![serde_json::to_value(&readings[0]).unwrap()];
+        rows.insert(0, serde_json::json!({"zone": "B", "extra": 1}));
+        let headers = crate::formatting::utils::get_unique_headers(&rows);
+        assert_eq!(headers, vec!["extra", "zone", "active", "value"]);
+    }
+
+    #[test]
+    fn test_heterogeneous_column_order_markdown_and_csv() {
+        let data = heterogeneous_rows();
+
+        let md = data.to_markdown().unwrap();
+        assert_eq!(md.lines().next().unwrap(), "| name | score | age | city |");
+        assert!(md.contains("| Bob |  | 30 |  |"));
+
+        let csv_str = data.to_csv_with_options(&ExportOptions::flattened()).unwrap();
+        let lines: Vec<&str> = csv_str.lines().collect();
+        assert_eq!(lines[0], "name,score,age,city");
+        assert_eq!(lines[3], ",7.0,,Managua");
+    }
+
     #[test]
     fn test_value_to_string_readable() {
         // String
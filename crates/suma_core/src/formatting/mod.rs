@@ -1,6 +1,7 @@
 pub mod export;
 pub mod macros;
 pub mod error;
+pub mod options;
 mod export_test;
 mod utils;
 pub mod visualizable;
\ No newline at end of file
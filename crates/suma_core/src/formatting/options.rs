@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+/// Opciones de exportación para los formatos tabulares (CSV y Markdown).
+/// La alineación y los decimales solo afectan a Markdown.
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Aplana los objetos anidados en columnas con nombres punteados (`address.city`).
+    pub flatten_nested: bool,
+    /// Infiere la alineación por tipo: números a la derecha, booleanos centrados, resto a la izquierda.
+    pub infer_alignment: bool,
+    /// Alineación explícita por columna; tiene prioridad sobre la inferida.
+    pub column_alignment: HashMap<String, Alignment>,
+    /// Número de decimales para valores flotantes (`None` mantiene la precisión completa).
+    pub decimal_places: Option<usize>,
+}
+
+impl ExportOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn flattened() -> Self {
+        Self::new().flatten_nested()
+    }
+
+    pub fn flatten_nested(mut self) -> Self {
+        self.flatten_nested = true;
+        self
+    }
+
+    pub fn infer_alignment(mut self) -> Self {
+        self.infer_alignment = true;
+        self
+    }
+
+    pub fn align(mut self, column: &str, alignment: Alignment) -> Self {
+        self.column_alignment.insert(column.to_string(), alignment);
+        self
+    }
+
+    pub fn decimal_places(mut self, places: usize) -> Self {
+        self.decimal_places = Some(places);
+        self
+    }
+}
+
+/// Alineación de una columna Markdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+impl Alignment {
+    /// Marcador de la fila separadora (`:---`, `:---:`, `---:`).
+    pub fn marker(&self) -> &'static str {
+        match self {
+            Alignment::Left => ":---",
+            Alignment::Center => ":---:",
+            Alignment::Right => "---:",
+        }
+    }
+}
+
+/// Opciones de exportación a Excel: nombre de la hoja y formato de los números.
+#[derive(Debug, Clone)]
+pub struct ExcelOptions {
+    /// Nombre de la hoja principal; las hojas de objetos anidados se nombran `"{hoja} - {campo}"`.
+    pub sheet_name: String,
+    /// Formato numérico de Excel para todas las celdas numéricas (p. ej. `"#,##0.00"`).
+    pub number_format: Option<String>,
+    /// Formato numérico por columna; tiene prioridad sobre `number_format`.
+    pub column_formats: HashMap<String, String>,
+}
+
+impl Default for ExcelOptions {
+    fn default() -> Self {
+        Self {
+            sheet_name: String::from("Data"),
+            number_format: None,
+            column_formats: HashMap::new(),
+        }
+    }
+}
+
+impl ExcelOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sheet_name(mut self, name: &str) -> Self {
+        self.sheet_name = name.to_string();
+        self
+    }
+
+    pub fn number_format(mut self, format: &str) -> Self {
+        self.number_format = Some(format.to_string());
+        self
+    }
+
+    /// Atajo para `number_format` con `places` decimales fijos (`0.00` para 2).
+    pub fn decimal_places(self, places: usize) -> Self {
+        let format = if places == 0 { String::from("0") } else { format!("0.{}", "0".repeat(places)) };
+        self.number_format(&format)
+    }
+
+    pub fn column_format(mut self, column: &str, format: &str) -> Self {
+        self.column_formats.insert(column.to_string(), format.to_string());
+        self
+    }
+}
@@ -2,18 +2,26 @@ use std::collections::HashSet;
 use rust_xlsxwriter::{Format, FormatBorder, Workbook, Worksheet, XlsxError};
 use serde_json::Value;
 use crate::formatting::error::ExportError;
-
+use crate::formatting::options::{Alignment, ExcelOptions, ExportOptions};
+
+/// Unión de las claves de un array de objetos, en orden de primera aparición.
+/// Es el orden de columnas compartido por los exportadores Excel, Markdown y CSV.
+///
+/// El orden de primera aparición es entre filas: dentro de cada objeto las claves
+/// llegan en orden alfabético, porque `serde_json` se compila sin `preserve_order`
+/// y `to_value` no conserva el orden de declaración de los campos de un struct.
 pub fn get_unique_headers(arr: &[Value]) -> Vec<&str> {
-    let mut headers = HashSet::new();
+    let mut seen = HashSet::new();
+    let mut headers = Vec::new();
     for item in arr {
         if let Value::Object(obj) = item {
             for key in obj.keys() {
-                headers.insert(key.as_str());
+                if seen.insert(key.as_str()) {
+                    headers.push(key.as_str());
+                }
             }
         }
     }
-    let mut headers: Vec<&str> = headers.into_iter().collect();
-    headers.sort();
     headers
 }
 
@@ -55,6 +63,58 @@ pub fn value_to_string_readable(value: &Value) -> String {
     }
 }
 
+// ==================== APLANADO DE OBJETOS ANIDADOS ====================
+
+/// Aplana un objeto anidado usando nombres de columna punteados:
+/// `{"address": {"city": "X"}}` -> `{"address.city": "X"}`.
+pub fn flatten_object(obj: &serde_json::Map<String, Value>) -> serde_json::Map<String, Value> {
+    let mut flat = serde_json::Map::new();
+    flatten_into(obj, "", &mut flat);
+    flat
+}
+
+fn flatten_into(obj: &serde_json::Map<String, Value>, prefix: &str, out: &mut serde_json::Map<String, Value>) {
+    for (key, value) in obj {
+        let column = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            Value::Object(nested) if !nested.is_empty() => flatten_into(nested, &column, out),
+            _ => {
+                out.insert(column, value.clone());
+            }
+        }
+    }
+}
+
+/// Verdadero si algún objeto (o algún objeto de un array raíz) tiene un objeto anidado,
+/// es decir, si hace falta aplanarlo para escribirlo como tabla.
+pub fn has_nested_objects(value: &Value) -> bool {
+    let object_has_nested = |item: &Value| match item {
+        Value::Object(obj) => obj.values().any(|v| matches!(v, Value::Object(nested) if !nested.is_empty())),
+        _ => false,
+    };
+    match value {
+        Value::Array(arr) => arr.iter().any(object_has_nested),
+        other => object_has_nested(other),
+    }
+}
+
+/// Aplana un valor: los objetos (y los objetos dentro de un array raíz) se aplanan,
+/// el resto se devuelve sin cambios.
+pub fn flatten_value(value: &Value) -> Value {
+    match value {
+        Value::Object(obj) => Value::Object(flatten_object(obj)),
+        Value::Array(arr) => Value::Array(arr.iter().map(|item| match item {
+            Value::Object(obj) => Value::Object(flatten_object(obj)),
+            other => other.clone(),
+        }).collect()),
+        other => other.clone(),
+    }
+}
+
 // ==================== IMPLEMENTACIÓN JERÁRQUICA MARKDOWN ====================
 
 pub fn build_hierarchical_markdown(
@@ -148,9 +208,28 @@ pub fn build_hierarchical_excel(
     value: &Value,
     path: &str,
     sheet_name: &str,
+) -> Result<(), ExportError>  {
+    build_hierarchical_excel_with(value, path, &ExcelOptions::new().sheet_name(sheet_name))
+}
+
+pub fn build_hierarchical_excel_with(
+    value: &Value,
+    path: &str,
+    options: &ExcelOptions,
 ) -> Result<(), ExportError>  {
     let mut workbook = Workbook::new();
+    add_excel_sheets(&mut workbook, value, options)?;
+    workbook.save(path)?;
+    Ok(())
+}
 
+/// Agrega al libro las hojas de `value` (una principal con el nombre de las opciones
+/// y una por cada objeto anidado), sin guardarlo.
+pub fn add_excel_sheets(
+    workbook: &mut Workbook,
+    value: &Value,
+    options: &ExcelOptions,
+) -> Result<(), ExportError>  {
     // Crear formatos
     let header_format = Format::new()
         .set_bold()
@@ -168,18 +247,27 @@ pub fn build_hierarchical_excel(
     let mut sheet_count = 0;
     process_value_for_excel(
         value,
-        &mut workbook,
+        workbook,
         &mut sheet_count,
-        sheet_name,
+        &options.sheet_name,
         &header_format,
         &cell_format,
         &title_format,
+        options,
     )?;
 
-    workbook.save(path)?;
     Ok(())
 }
 
+/// Formato de celda para una columna: el de la columna si lo hay, si no el numérico global.
+/// Solo afecta a los números; el resto de valores ignora el formato numérico.
+fn column_cell_format(options: &ExcelOptions, column: &str, cell_format: &Format) -> Format {
+    match options.column_formats.get(column).or(options.number_format.as_ref()) {
+        Some(num_format) => cell_format.clone().set_num_format(num_format),
+        None => cell_format.clone(),
+    }
+}
+
 pub fn process_value_for_excel(
     value: &Value,
     workbook: &mut Workbook,
@@ -188,6 +276,7 @@ pub fn process_value_for_excel(
     header_format: &Format,
     cell_format: &Format,
     title_format: &Format,
+    options: &ExcelOptions,
 ) -> Result<(), XlsxError> {
     match value {
         Value::Object(obj) => {
@@ -214,7 +303,8 @@ pub fn process_value_for_excel(
 
                 for (key, value) in flat_fields {
                     worksheet.write_string(row, 0, key)?;
-                    write_value_to_excel_cell(&mut worksheet, row, 1, value, cell_format)?;
+                    let format = column_cell_format(options, key, cell_format);
+                    write_value_to_excel_cell(&mut worksheet, row, 1, value, &format)?;
                     row += 1;
                 }
                 row += 1; // Espacio extra
@@ -241,6 +331,9 @@ pub fn process_value_for_excel(
                             row += 1;
 
                             // Escribir datos
+                            let formats: Vec<Format> = headers.iter()
+                                .map(|h| column_cell_format(options, h, cell_format))
+                                .collect();
                             for (item_row, item) in arr.iter().enumerate() {
                                 if let Value::Object(item_obj) = item {
                                     for (col, header) in headers.iter().enumerate() {
@@ -250,7 +343,7 @@ pub fn process_value_for_excel(
                                                 row + item_row as u32,
                                                 col as u16,
                                                 value,
-                                                cell_format,
+                                                &formats[col],
                                             )?;
                                         }
                                     }
@@ -259,9 +352,10 @@ pub fn process_value_for_excel(
                             row += arr.len() as u32 + 1;
                         } else {
                             // Array de valores simples
+                            let format = column_cell_format(options, key, cell_format);
                             for (i, item) in arr.iter().enumerate() {
                                 worksheet.write_string(row + i as u32, 0, &format!("[{}]", i + 1))?;
-                                write_value_to_excel_cell(&mut worksheet, row + i as u32, 1, item, cell_format)?;
+                                write_value_to_excel_cell(&mut worksheet, row + i as u32, 1, item, &format)?;
                             }
                             row += arr.len() as u32 + 1;
                         }
@@ -296,6 +390,7 @@ pub fn process_value_for_excel(
                     header_format,
                     cell_format,
                     title_format,
+                    options,
                 )?;
             }
         }
@@ -305,13 +400,14 @@ pub fn process_value_for_excel(
 
             if !arr.is_empty() {
                 if let Some(Value::Object(_)) = arr.first() {
-                    write_array_to_excel_worksheet(&arr, &mut worksheet, header_format, cell_format)?;
+                    write_array_to_excel_worksheet_with(&arr, &mut worksheet, header_format, cell_format, options)?;
                 } else {
                     // Array de valores simples
                     worksheet.write_string_with_format(0, 0, "Items", header_format)?;
+                    let format = column_cell_format(options, "Items", cell_format);
                     for (i, item) in arr.iter().enumerate() {
                         worksheet.write_number(i as u32 + 1, 0, (i + 1) as f64)?;
-                        write_value_to_excel_cell(&mut worksheet, i as u32 + 1, 1, item, cell_format)?;
+                        write_value_to_excel_cell(&mut worksheet, i as u32 + 1, 1, item, &format)?;
                     }
                 }
             } else {
@@ -323,7 +419,8 @@ pub fn process_value_for_excel(
         _ => {
             // Valor simple
             let mut worksheet = workbook.add_worksheet().set_name(sheet_name)?;
-            write_value_to_excel_cell(&mut worksheet, 0, 0, value, cell_format)?;
+            let format = column_cell_format(options, sheet_name, cell_format);
+            write_value_to_excel_cell(&mut worksheet, 0, 0, value, &format)?;
             worksheet.autofit();
         }
     }
@@ -334,23 +431,49 @@ pub fn process_value_for_excel(
 // ==================== MÉTODOS HELPER MEJORADOS ====================
 
 pub fn build_markdown_table(data: &[Value]) -> Result<String, ExportError> {
+    build_markdown_table_with(data, &ExportOptions::default())
+}
+
+/// Alineación inferida para una columna según los valores presentes.
+fn infer_column_alignment(data: &[Value], header: &str) -> Alignment {
+    let mut values = data.iter()
+        .filter_map(|item| item.get(header))
+        .filter(|v| !v.is_null())
+        .peekable();
+
+    if values.peek().is_none() {
+        return Alignment::Left;
+    }
+
+    let values: Vec<&Value> = values.collect();
+    if values.iter().all(|v| v.is_number()) {
+        Alignment::Right
+    } else if values.iter().all(|v| v.is_boolean()) {
+        Alignment::Center
+    } else {
+        Alignment::Left
+    }
+}
+
+/// Formatea una celda aplicando el número de decimales a los flotantes.
+fn format_markdown_cell(value: &Value, decimal_places: Option<usize>) -> String {
+    match (value, decimal_places) {
+        (Value::Number(n), Some(places)) if n.is_f64() => {
+            format!("{:.*}", places, n.as_f64().unwrap_or_default())
+        }
+        _ => value_to_string_readable(value),
+    }
+}
+
+pub fn build_markdown_table_with(data: &[Value], options: &ExportOptions) -> Result<String, ExportError> {
     if data.is_empty() {
         return Ok(String::from("# Empty Data\n\nNo hay datos para mostrar"));
     }
 
     let mut output = String::new();
-    let mut headers: Vec<&str> = vec![];
 
     // Obtener todos los headers únicos
-    for item in data {
-        if let Value::Object(obj) = item {
-            for key in obj.keys() {
-                if !headers.contains(&key.as_str()) {
-                    headers.push(key);
-                }
-            }
-        }
-    }
+    let headers = get_unique_headers(data);
 
     if headers.is_empty() {
         return Ok(String::from("# No valid data\n\nNo se encontraron objetos válidos"));
@@ -359,15 +482,22 @@ pub fn build_markdown_table(data: &[Value]) -> Result<String, ExportError> {
     // Header de la tabla
     output.push_str(&format!("| {} |\n", headers.join(" | ")));
 
-    // Separador
-    output.push_str(&format!("|{}|\n", vec!["---"; headers.len()].join("|")));
+    // Separador (con marcadores de alineación si se solicitan)
+    let separators: Vec<&str> = headers.iter()
+        .map(|&h| match options.column_alignment.get(h) {
+            Some(alignment) => alignment.marker(),
+            None if options.infer_alignment => infer_column_alignment(data, h).marker(),
+            None => "---",
+        })
+        .collect();
+    output.push_str(&format!("|{}|\n", separators.join("|")));
 
     // Filas de datos
     for item in data {
         if let Value::Object(obj) = item {
             let row: Vec<String> = headers.iter()
                 .map(|&h| obj.get(h)
-                    .map(|v| value_to_string_readable(v))
+                    .map(|v| format_markdown_cell(v, options.decimal_places))
                     .unwrap_or_else(|| String::from("")))
                 .collect();
             output.push_str(&format!("| {} |\n", row.join(" | ")));
@@ -377,12 +507,222 @@ pub fn build_markdown_table(data: &[Value]) -> Result<String, ExportError> {
     Ok(output)
 }
 
+/// Escapa los caracteres especiales de HTML en el texto de una celda.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Construye una tabla HTML (`<thead>` con las claves, una fila de `<tbody>` por objeto).
+/// Mismo orden de columnas y formato de celdas que `build_markdown_table`.
+pub fn build_html_table(data: &[Value]) -> Result<String, ExportError> {
+    if data.is_empty() {
+        return Ok(String::from("<p>Empty Data</p>\n"));
+    }
+
+    let headers = get_unique_headers(data);
+    if headers.is_empty() {
+        return Ok(String::from("<p>No se encontraron objetos válidos</p>\n"));
+    }
+
+    let mut output = String::from("<table>\n  <thead>\n    <tr>");
+    for header in &headers {
+        output.push_str(&format!("<th>{}</th>", escape_html(header)));
+    }
+    output.push_str("</tr>\n  </thead>\n  <tbody>\n");
+
+    for item in data {
+        if let Value::Object(obj) = item {
+            output.push_str("    <tr>");
+            for &h in &headers {
+                let cell = obj.get(h).map(value_to_string_readable).unwrap_or_default();
+                output.push_str(&format!("<td>{}</td>", escape_html(&cell)));
+            }
+            output.push_str("</tr>\n");
+        }
+    }
+
+    output.push_str("  </tbody>\n</table>\n");
+    Ok(output)
+}
+
+/// Escapa los caracteres especiales de LaTeX en el texto de una celda.
+fn escape_latex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '_' | '#' | '$' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\\' => escaped.push_str(r"\textbackslash{}"),
+            '~' => escaped.push_str(r"\textasciitilde{}"),
+            '^' => escaped.push_str(r"\textasciicircum{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Construye un entorno `tabular` de LaTeX con una columna por clave.
+/// La alineación de cada columna se infiere como en Markdown (números a la derecha).
+pub fn build_latex_table(data: &[Value]) -> Result<String, ExportError> {
+    if data.is_empty() {
+        return Ok(String::from("% Empty Data\n"));
+    }
+
+    let headers = get_unique_headers(data);
+    if headers.is_empty() {
+        return Ok(String::from("% No se encontraron objetos válidos\n"));
+    }
+
+    let spec: String = headers.iter()
+        .map(|&h| match infer_column_alignment(data, h) {
+            Alignment::Left => "l|",
+            Alignment::Center => "c|",
+            Alignment::Right => "r|",
+        })
+        .collect();
+
+    let mut output = format!("\\begin{{tabular}}{{|{}}}\n\\hline\n", spec);
+    let header_row: Vec<String> = headers.iter().map(|h| escape_latex(h)).collect();
+    output.push_str(&format!("{} \\\\\n\\hline\n", header_row.join(" & ")));
+
+    for item in data {
+        if let Value::Object(obj) = item {
+            let row: Vec<String> = headers.iter()
+                .map(|&h| escape_latex(&obj.get(h).map(value_to_string_readable).unwrap_or_default()))
+                .collect();
+            output.push_str(&format!("{} \\\\\n", row.join(" & ")));
+        }
+    }
+
+    output.push_str("\\hline\n\\end{tabular}\n");
+    Ok(output)
+}
+
+// ==================== IMPLEMENTACIÓN YAML ====================
+
+/// Serializa un valor en YAML de estilo bloque (`clave: valor`, `- elemento`).
+/// Las cadenas van siempre entre comillas dobles con el escape de JSON, que YAML acepta tal cual.
+pub fn build_yaml(value: &Value) -> String {
+    let mut output = String::new();
+    write_yaml(value, 0, &mut output);
+    output
+}
+
+fn write_yaml(value: &Value, indent: usize, out: &mut String) {
+    let pad = "  ".repeat(indent);
+    match value {
+        Value::Object(obj) if !obj.is_empty() => {
+            for (key, item) in obj {
+                if is_yaml_block(item) {
+                    out.push_str(&format!("{}{}:\n", pad, yaml_key(key)));
+                    write_yaml(item, indent + 1, out);
+                } else {
+                    out.push_str(&format!("{}{}: {}\n", pad, yaml_key(key), yaml_inline(item)));
+                }
+            }
+        }
+        Value::Array(arr) if !arr.is_empty() => {
+            for item in arr {
+                if is_yaml_block(item) {
+                    // El primer renglón del bloque hijo comparte línea con el guion
+                    let mut child = String::new();
+                    write_yaml(item, indent + 1, &mut child);
+                    out.push_str(&pad);
+                    out.push_str("- ");
+                    out.push_str(&child[pad.len() + 2..]);
+                } else {
+                    out.push_str(&format!("{}- {}\n", pad, yaml_inline(item)));
+                }
+            }
+        }
+        other => out.push_str(&format!("{}{}\n", pad, yaml_inline(other))),
+    }
+}
+
+fn is_yaml_block(value: &Value) -> bool {
+    match value {
+        Value::Object(obj) => !obj.is_empty(),
+        Value::Array(arr) => !arr.is_empty(),
+        _ => false,
+    }
+}
+
+fn yaml_inline(value: &Value) -> String {
+    match value {
+        Value::Object(_) => String::from("{}"),
+        Value::Array(_) => String::from("[]"),
+        Value::String(s) => Value::String(s.clone()).to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Las claves simples van sin comillas; las que YAML leería como otro tipo
+/// (`true`, `null`, `42`...) o con caracteres especiales se citan.
+fn yaml_key(key: &str) -> String {
+    const RESERVED: &[&str] = &["true", "false", "null", "yes", "no", "on", "off", "~"];
+    let plain = key.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && !RESERVED.contains(&key.to_lowercase().as_str());
+    if plain {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+/// Construye un CSV a partir de un array de objetos planos (una fila por objeto).
+pub fn build_csv_table(data: &[Value]) -> Result<String, ExportError> {
+    build_csv_table_with(data, b',')
+}
+
+/// Igual que `build_csv_table` pero con el separador de campos indicado.
+pub fn build_csv_table_with(data: &[Value], delimiter: u8) -> Result<String, ExportError> {
+    let mut wtr = csv::WriterBuilder::new().delimiter(delimiter).from_writer(vec![]);
+    let headers = get_unique_headers(data);
+
+    if headers.is_empty() {
+        // Valores simples: una fila por elemento
+        for item in data {
+            wtr.write_record([value_to_string_readable(item)])?;
+        }
+    } else {
+        wtr.write_record(&headers)?;
+        for item in data {
+            if let Value::Object(obj) = item {
+                let row: Vec<String> = headers.iter()
+                    .map(|&h| obj.get(h)
+                        .map(value_to_string_readable)
+                        .unwrap_or_default())
+                    .collect();
+                wtr.write_record(&row)?;
+            }
+        }
+    }
+
+    let bytes = wtr.into_inner().map_err(|e| ExportError::IoError(e.into_error()))?;
+    Ok(String::from_utf8(bytes)?)
+}
 
 pub fn write_array_to_excel_worksheet(
     arr: &[Value],
     worksheet: &mut Worksheet,
     header_format: &Format,
     cell_format: &Format,
+) -> Result<(), XlsxError> {
+    write_array_to_excel_worksheet_with(arr, worksheet, header_format, cell_format, &ExcelOptions::default())
+}
+
+pub fn write_array_to_excel_worksheet_with(
+    arr: &[Value],
+    worksheet: &mut Worksheet,
+    header_format: &Format,
+    cell_format: &Format,
+    options: &ExcelOptions,
 ) -> Result<(), XlsxError> {
     if arr.is_empty() {
         worksheet.write_string(0, 0, "No data available")?;
@@ -397,11 +737,14 @@ pub fn write_array_to_excel_worksheet(
     }
 
     // Escribir datos
+    let formats: Vec<Format> = headers.iter()
+        .map(|h| column_cell_format(options, h, cell_format))
+        .collect();
     for (row, item) in arr.iter().enumerate() {
         if let Value::Object(obj) = item {
             for (col, header) in headers.iter().enumerate() {
                 if let Some(value) = obj.get(*header) {
-                    write_value_to_excel_cell(worksheet, (row + 1) as u32, col as u16, value, cell_format)?;
+                    write_value_to_excel_cell(worksheet, (row + 1) as u32, col as u16, value, &formats[col])?;
                 }
             }
         }
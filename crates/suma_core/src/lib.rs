@@ -19,10 +19,10 @@ pub mod symbolics;
 
 // Re-export para fácil acceso
 pub use boolean_algebra::{BooleanExpr, TruthTable};
-pub use conversions::{NumberConverter};
+pub use conversions::{ConversionError, NumberConverter};
 
 
 
-pub use networking::subnets::{FLSMCalculator, SubnetRow, VLSMCalculator, BaseCalculator};
+pub use networking::subnets::{FLSMCalculator, SubnetRow, VLSMCalculator, BaseCalculator, IPv6SubnetCalculator, IPv6SubnetRow};
 pub use networking::utils::ipv6_format::{compress_ipv6, expand_ipv6};
 
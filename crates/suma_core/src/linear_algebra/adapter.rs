@@ -16,6 +16,14 @@ impl Scalar for f64 {
         // Usamos un Epsilon (margen de error) de 1e-10
         (self - other).abs() < 1e-10
     }
+
+    fn magnitude(&self) -> Option<f64> {
+        Some(self.abs())
+    }
+
+    fn is_negligible(&self, scale: f64) -> bool {
+        self.abs() <= 1e-10 * scale
+    }
 }
 
 // --- Adaptador para Expr ---
@@ -37,4 +45,23 @@ impl Scalar for Expr {
         // Esto usa el PartialEq que derivaste en ast.rs
         self == other
     }
-}
\ No newline at end of file
+}
+
+// --- Adaptador para Rational (fracciones exactas) ---
+/// Escalar racional exacto: permite RREF y solve sin error de redondeo.
+pub type Rational = num_rational::Rational64;
+
+impl Zero for Rational {
+    fn zero() -> Self { Rational::from_integer(0) }
+    fn is_zero(&self) -> bool { *self.numer() == 0 }
+}
+impl One for Rational {
+    fn one() -> Self { Rational::from_integer(1) }
+}
+
+impl Scalar for Rational {
+    fn is_approx(&self, other: &Self) -> bool {
+        // Las fracciones son exactas: aproximado == igual
+        self == other
+    }
+}
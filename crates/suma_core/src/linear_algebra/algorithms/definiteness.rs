@@ -0,0 +1,133 @@
+use crate::linear_algebra::matrices::implementations::dense::DenseMatrix;
+use crate::linear_algebra::traits::Scalar;
+
+// Pivotes con valor absoluto menor a esto se consideran cero
+const TOLERANCE: f64 = 1e-10;
+
+impl DenseMatrix<f64> {
+    /// Cuadrada y A[i][j] ≈ A[j][i] (con la tolerancia de `Scalar::is_approx`).
+    pub fn is_symmetric(&self) -> bool {
+        if self.rows != self.cols {
+            return false;
+        }
+        (0..self.rows).all(|i| {
+            (i + 1..self.cols).all(|j| self.get(i, j).is_approx(&self.get(j, i)))
+        })
+    }
+
+    /// Simétrica y con todos los pivotes de la factorización de Cholesky positivos.
+    pub fn is_positive_definite(&self) -> bool {
+        self.is_symmetric() && self.symmetric_pivots_check(false)
+    }
+
+    /// Simétrica y xᵀAx ≥ 0 para todo x (admite matrices singulares).
+    pub fn is_positive_semidefinite(&self) -> bool {
+        self.is_symmetric() && self.symmetric_pivots_check(true)
+    }
+
+    /// Eliminación simétrica (LDLᵀ sin pivoteo). Una matriz es definida positiva si todos
+    /// los pivotes son positivos. Para semidefinida se admiten pivotes nulos, siempre que
+    /// el resto de su fila también sea nulo (en una PSD, diagonal 0 implica fila 0).
+    fn symmetric_pivots_check(&self, allow_zero_pivots: bool) -> bool {
+        let n = self.rows;
+        let mut a = self.clone();
+
+        for k in 0..n {
+            let pivot = a.get(k, k);
+
+            if pivot.abs() <= TOLERANCE {
+                if !allow_zero_pivots {
+                    return false;
+                }
+                if (k + 1..n).any(|j| a.get(k, j).abs() > TOLERANCE) {
+                    return false;
+                }
+                continue;
+            }
+
+            if pivot < 0.0 {
+                return false;
+            }
+
+            // Complemento de Schur del pivote
+            for i in k + 1..n {
+                let factor = a.get(i, k) / pivot;
+                for j in k + 1..n {
+                    let updated = a.get(i, j) - factor * a.get(k, j);
+                    a.set(i, j, updated);
+                }
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix;
+
+    #[test]
+    fn test_symmetric_positive_definite() {
+        let m = matrix![
+            2.0, -1.0, 0.0;
+            -1.0, 2.0, -1.0;
+            0.0, -1.0, 2.0
+        ];
+        assert!(m.is_symmetric());
+        assert!(m.is_positive_definite());
+        assert!(m.is_positive_semidefinite());
+    }
+
+    #[test]
+    fn test_positive_semidefinite_singular() {
+        // Autovalores 0 y 2
+        let m = matrix![
+            1.0, 1.0;
+            1.0, 1.0
+        ];
+        assert!(!m.is_positive_definite());
+        assert!(m.is_positive_semidefinite());
+
+        // Diagonal nula con fila nula
+        let m = matrix![
+            0.0, 0.0;
+            0.0, 3.0
+        ];
+        assert!(m.is_positive_semidefinite());
+    }
+
+    #[test]
+    fn test_indefinite() {
+        // Autovalores 3 y -1
+        let m = matrix![
+            1.0, 2.0;
+            2.0, 1.0
+        ];
+        assert!(!m.is_positive_definite());
+        assert!(!m.is_positive_semidefinite());
+
+        // Diagonal nula pero fila no nula: indefinida
+        let m = matrix![
+            0.0, 1.0;
+            1.0, 0.0
+        ];
+        assert!(!m.is_positive_semidefinite());
+    }
+
+    #[test]
+    fn test_symmetry_tolerance() {
+        let m = matrix![
+            1.0, 0.5;
+            0.5 + 1e-12, 1.0
+        ];
+        assert!(m.is_symmetric());
+
+        let not_symmetric = matrix![
+            1.0, 0.5;
+            0.6, 1.0
+        ];
+        assert!(!not_symmetric.is_symmetric());
+        assert!(!not_symmetric.is_positive_definite());
+    }
+}
@@ -6,6 +6,9 @@ impl<T> DenseMatrix<T>
 where
     T: Scalar,
 {
+    /// Determinante a partir de la factorización LU: producto de la diagonal de `U`,
+    /// con el signo cambiado por cada intercambio de filas del pivoteo.
+    /// Una matriz singular devuelve cero.
     pub fn determinant(&self) -> Result<T, LinearAlgebraError> {
         if self.rows != self.cols {
             return Err(LinearAlgebraError::DimensionMismatch {
@@ -15,64 +18,44 @@ where
             });
         }
 
-        let n = self.rows;
-        // Trabajamos sobre una copia para no modificar la original
-        let mut mat = self.clone(); 
-        let mut swaps = 0;
-
-        // Algoritmo de eliminación Gaussiana (Sin llevar a RREF completo, solo Triangular Superior)
-        for i in 0..n {
-            // 1. Pivoteo
-            let mut pivot_row = i;
-            while pivot_row < n && mat.get(pivot_row, i).is_zero() {
-                pivot_row += 1;
-            }
-
-            if pivot_row == n {
-                // Si no encontramos pivote en esta columna, el determinante es 0
-                return Ok(T::zero());
-            }
-
-            if pivot_row != i {
-                mat.swap_rows(i, pivot_row)?;
-                swaps += 1;
-            }
-
-            // 2. Eliminación (Hacer ceros DEBAJO del pivote solamente)
-            for j in (i + 1)..n {
-                let pivot = mat.get(i, i);
-                let target = mat.get(j, i);
-
-                if !target.is_zero() {
-                    // Queremos hacer target cero.
-                    // R_j = R_j - (target/pivot) * R_i
-                    // Cuidado: add_scaled_row hace: R_tgt + (scalar * R_src)
-                    // Entonces scalar = -(target/pivot)
-                    
-                    // Nota: Aquí asumimos que Scalar soporta división.
-                    // Para evitar divisiones complejas en simbólico, a veces se usa Fraction-Free Gaussian,
-                    // pero con tu sistema de Expr actual, esto generará expresiones anidadas que simplify() manejará.
-                    
-                    let scalar = -(target / pivot);
-                    mat.add_scaled_row(j, i, scalar)?;
-                }
-            }
-        }
+        // `lu` solo falla por singularidad una vez validada la forma cuadrada
+        let Ok((_, u, perm)) = self.lu() else {
+            return Ok(T::zero());
+        };
 
-        // 3. Producto de la Diagonal
-        let mut det = T::one();
-        for i in 0..n {
-            det = det * mat.get(i, i);
-        }
+        Ok(determinant_from_lu(&u, &perm))
+    }
+}
 
-        // 4. Ajuste de Signo por Swaps
-        // Si swaps es impar, multiplicamos por -1
-        if swaps % 2 != 0 {
-            det = -det;
-        }
+/// Determinante a partir de `U` y `perm` de una factorización LU ya calculada.
+pub(crate) fn determinant_from_lu<T: Scalar>(u: &DenseMatrix<T>, perm: &[usize]) -> T {
+    let mut det = T::one();
+    for i in 0..u.rows {
+        det = det * u.get(i, i);
+    }
+
+    // Paridad de la permutación: cada ciclo de longitud k equivale a k - 1 intercambios
+    if permutation_swaps(perm) % 2 != 0 {
+        det = -det;
+    }
 
-        Ok(det)
+    det
+}
+
+fn permutation_swaps(perm: &[usize]) -> usize {
+    let mut visited = vec![false; perm.len()];
+    let mut swaps = 0;
+    for start in 0..perm.len() {
+        let mut i = start;
+        let mut cycle_len: usize = 0;
+        while !visited[i] {
+            visited[i] = true;
+            i = perm[i];
+            cycle_len += 1;
+        }
+        swaps += cycle_len.saturating_sub(1);
     }
+    swaps
 }
 
 #[cfg(test)]
@@ -172,4 +155,46 @@ mod tests {
             _ => panic!("Estructura incorrecta para det diagonal: {:?}", det),
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_determinant_sign_from_row_swaps() {
+        // Matrices de permutación: el signo sale solo de los intercambios
+        let m = matrix![
+            0.0, 1.0, 0.0;
+            0.0, 0.0, 1.0;
+            1.0, 0.0, 0.0
+        ];
+        assert!(m.determinant().unwrap().is_approx(&1.0));
+
+        let m = matrix![
+            0.0, 1.0;
+            1.0, 0.0
+        ];
+        assert!(m.determinant().unwrap().is_approx(&-1.0));
+    }
+
+    #[test]
+    fn test_determinant_rounding_singular_and_non_square() {
+        // Singular, pero la eliminación en flotantes deja residuos de ~1e-16
+        let m = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 9.0
+        ];
+        assert_eq!(m.determinant().unwrap(), 0.0);
+
+        let rect = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+        assert!(rect.determinant().is_err());
+    }
+
+    #[test]
+    fn test_determinant_small_scale_is_not_singular() {
+        // La tolerancia es relativa a la escala de cada columna, no absoluta
+        let m = matrix![
+            1e-11, 0.0;
+            0.0, 1e-11
+        ];
+        let det = m.determinant().unwrap();
+        assert!((det - 1e-22).abs() < 1e-30, "det = {}", det);
+    }
+}
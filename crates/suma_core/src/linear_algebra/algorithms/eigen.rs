@@ -0,0 +1,151 @@
+use crate::linear_algebra::matrices::implementations::dense::DenseMatrix;
+use crate::linear_algebra::error::LinearAlgebraError;
+
+impl DenseMatrix<f64> {
+    /// Autovalores de una matriz simétrica por el método de rotaciones de Jacobi,
+    /// en orden ascendente. `max_iter` es el número máximo de barridos completos
+    /// y `tol` la norma máxima admitida para la parte fuera de la diagonal.
+    pub fn eigenvalues_symmetric(&self, max_iter: usize, tol: f64) -> Result<Vec<f64>, LinearAlgebraError> {
+        self.eigenvectors_symmetric(max_iter, tol).map(|(values, _)| values)
+    }
+
+    /// Como `eigenvalues_symmetric`, pero devuelve también la matriz con las
+    /// rotaciones acumuladas: la columna `k` es el autovector (unitario) de `values[k]`.
+    pub fn eigenvectors_symmetric(&self, max_iter: usize, tol: f64) -> Result<(Vec<f64>, DenseMatrix<f64>), LinearAlgebraError> {
+        if self.rows != self.cols {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Eigenvalues (A must be square)".to_string(),
+                expected: self.rows,
+                found: self.cols,
+            });
+        }
+        if !self.is_symmetric() {
+            return Err(LinearAlgebraError::NotSymmetric);
+        }
+
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut v = DenseMatrix::zeros(n, n);
+        for i in 0..n {
+            v.set(i, i, 1.0);
+        }
+
+        let mut converged = off_diagonal_norm(&a) < tol;
+        for _ in 0..max_iter {
+            if converged {
+                break;
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a.get(p, q) != 0.0 {
+                        rotate(&mut a, &mut v, p, q);
+                    }
+                }
+            }
+            converged = off_diagonal_norm(&a) < tol;
+        }
+
+        if !converged {
+            return Err(LinearAlgebraError::NoConvergence { iterations: max_iter });
+        }
+
+        // Ordenamos autovalores y columnas de V de menor a mayor
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a.get(i, i).total_cmp(&a.get(j, j)));
+
+        let values = order.iter().map(|&i| a.get(i, i)).collect();
+        let mut vectors = DenseMatrix::zeros(n, n);
+        for (new_col, &old_col) in order.iter().enumerate() {
+            for row in 0..n {
+                vectors.set(row, new_col, v.get(row, old_col));
+            }
+        }
+
+        Ok((values, vectors))
+    }
+}
+
+fn off_diagonal_norm(a: &DenseMatrix<f64>) -> f64 {
+    let mut sum = 0.0;
+    for i in 0..a.rows {
+        for j in i + 1..a.cols {
+            sum += a.get(i, j) * a.get(i, j);
+        }
+    }
+    sum.sqrt()
+}
+
+// Rotación de Jacobi A' = Pᵀ A P que anula A[p][q]; V acumula V·P.
+fn rotate(a: &mut DenseMatrix<f64>, v: &mut DenseMatrix<f64>, p: usize, q: usize) {
+    let n = a.rows;
+    let theta = (a.get(q, q) - a.get(p, p)) / (2.0 * a.get(p, q));
+    // Raíz de menor módulo de t² + 2θt - 1 = 0, por estabilidad
+    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+    let c = 1.0 / (t * t + 1.0).sqrt();
+    let s = t * c;
+
+    for k in 0..n {
+        let (akp, akq) = (a.get(k, p), a.get(k, q));
+        a.set(k, p, c * akp - s * akq);
+        a.set(k, q, s * akp + c * akq);
+    }
+    for k in 0..n {
+        let (apk, aqk) = (a.get(p, k), a.get(q, k));
+        a.set(p, k, c * apk - s * aqk);
+        a.set(q, k, s * apk + c * aqk);
+    }
+    for k in 0..n {
+        let (vkp, vkq) = (v.get(k, p), v.get(k, q));
+        v.set(k, p, c * vkp - s * vkq);
+        v.set(k, q, s * vkp + c * vkq);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_eigenvalues_2x2() {
+        let m = matrix![2.0, 1.0; 1.0, 2.0];
+        let values = m.eigenvalues_symmetric(50, 1e-12).unwrap();
+        assert!((values[0] - 1.0).abs() < 1e-9);
+        assert!((values[1] - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_eigenvectors_satisfy_definition() {
+        let m = matrix![
+            2.0, -1.0, 0.0;
+            -1.0, 2.0, -1.0;
+            0.0, -1.0, 2.0
+        ];
+        let (values, vectors) = m.eigenvectors_symmetric(50, 1e-12).unwrap();
+
+        let sqrt2 = 2.0_f64.sqrt();
+        for (value, expected) in values.iter().zip([2.0 - sqrt2, 2.0, 2.0 + sqrt2]) {
+            assert!((value - expected).abs() < 1e-9, "{:?}", values);
+        }
+
+        // A v = λ v para cada columna
+        for k in 0..3 {
+            for i in 0..3 {
+                let av: f64 = (0..3).map(|j| m.get(i, j) * vectors.get(j, k)).sum();
+                assert!((av - values[k] * vectors.get(i, k)).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues_invalid_input() {
+        let rect = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+        assert!(matches!(rect.eigenvalues_symmetric(50, 1e-12), Err(LinearAlgebraError::DimensionMismatch { .. })));
+
+        let non_symmetric = matrix![1.0, 2.0; 0.0, 1.0];
+        assert_eq!(non_symmetric.eigenvalues_symmetric(50, 1e-12), Err(LinearAlgebraError::NotSymmetric));
+
+        let m = matrix![4.0, 1.0, 2.0; 1.0, 3.0, 0.5; 2.0, 0.5, 1.0];
+        assert_eq!(m.eigenvalues_symmetric(0, 1e-12), Err(LinearAlgebraError::NoConvergence { iterations: 0 }));
+    }
+}
@@ -9,7 +9,18 @@ where
     /// Transforma la matriz a su Forma Escalonada Reducida por Filas (RREF)
     /// utilizando el algoritmo de Gauss-Jordan.
     pub fn rref(&mut self) -> Result<(), LinearAlgebraError> {
+        self.rref_pivots().map(|_| ())
+    }
+
+    /// Igual que `rref`, pero devuelve la columna pivote de cada fila no nula.
+    /// La tolerancia relativa a la columna solo se aplica aquí, sobre los pivotes
+    /// sin normalizar: tras la reducción las filas pivote valen 1 y ya no conservan
+    /// la escala original, así que quien necesite saber qué columnas tienen pivote
+    /// debe usar esta lista en vez de volver a mirar las entradas.
+    pub(crate) fn rref_pivots(&mut self) -> Result<Vec<usize>, LinearAlgebraError> {
         let mut pivot_row = 0;
+        let mut pivots = Vec::new();
+        let scales = self.column_magnitudes();
 
         // Iteramos sobre las columnas (j)
         for j in 0..self.cols {
@@ -21,7 +32,7 @@ where
             // Buscamos una fila (desde pivot_row hacia abajo) que tenga un valor no-cero en la columna j.
             let mut pivot_found = false;
             for k in pivot_row..self.rows {
-                if !self.get_ref(k, j).is_zero() {
+                if !self.get_ref(k, j).is_negligible(scales[j]) {
                     // Encontramos un pivote no nulo. Lo traemos a la posición actual.
                     self.swap_rows(pivot_row, k)?;
                     pivot_found = true;
@@ -66,10 +77,11 @@ where
             // o que el usuario llame a simplify() al final.
             // (Para una implementación perfecta, Scalar podría tener un método .simplify_in_place())
 
+            pivots.push(j);
             pivot_row += 1;
         }
 
-        Ok(())
+        Ok(pivots)
     }
 }
 
@@ -42,28 +42,25 @@ where
         let mut augmented = DenseMatrix::new(n, 2 * n, augmented_data);
 
         // 3. Aplicar Gauss-Jordan
-        augmented.rref()?;
+        let pivots = augmented.rref_pivots()?;
+
+        // 4. Verificación de Singularidad
+        // Si A es invertible, cada columna de la parte izquierda tiene pivote y la
+        // parte izquierda queda como la identidad. Los pivotes se deciden antes de
+        // normalizar, con la escala de cada columna; las entradas ya reducidas no
+        // sirven para esto (una columna de orden 1e12 queda en 1).
+        if !(0..n).all(|i| pivots.get(i) == Some(&i)) {
+            // Nota: Podríamos agregar un error específico "SingularMatrix" en LinearAlgebraError
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Inverse (Singular Matrix check)".to_string(),
+                expected: 1,
+                found: 0
+            });
+        }
 
-        // 4. Extracción y Verificación de Singularidad
-        // Si A es invertible, la parte izquierda DEBE ser la matriz identidad.
-        // Verificamos que la diagonal principal sea 1 (o algo normalizado a 1).
-        // Si encontramos un 0 en la diagonal después de RREF, es singular.
-        
         let mut inverse_data = Vec::with_capacity(n * n);
 
         for i in 0..n {
-            // Verificación rápida de singularidad:
-            // En RREF, si A es invertible, A[i][i] debe ser 1.
-            let diag_val = augmented.get(i, i);
-            if diag_val.is_zero() {
-                // Nota: Podríamos agregar un error específico "SingularMatrix" en LinearAlgebraError
-                return Err(LinearAlgebraError::DimensionMismatch { 
-                    operation: "Inverse (Singular Matrix check)".to_string(),
-                    expected: 1, 
-                    found: 0 
-                });
-            }
-
             // Extraer parte derecha (columnas de n a 2n)
             for j in 0..n {
                 inverse_data.push(augmented.get(i, n + j));
@@ -166,4 +163,54 @@ mod tests {
             _ => panic!("Esperaba estructura 1/y, obtuve {:?}", cell_11),
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_inverse_singular_and_non_square() {
+        let singular_error = LinearAlgebraError::DimensionMismatch {
+            operation: "Inverse (Singular Matrix check)".to_string(),
+            expected: 1,
+            found: 0,
+        };
+
+        let exact = matrix![1.0, 2.0; 2.0, 4.0];
+        assert_eq!(exact.inverse().unwrap_err(), singular_error);
+
+        // Con ruido de redondeo tampoco debe devolver valores gigantes o NaN
+        let noisy = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 9.0
+        ];
+        assert_eq!(noisy.inverse().unwrap_err(), singular_error);
+
+        let rect = matrix![1.0, 2.0, 3.0];
+        assert!(matches!(rect.inverse(), Err(LinearAlgebraError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_inverse_small_scale() {
+        let m = matrix![
+            1e-11, 0.0;
+            0.0, 2e-11
+        ];
+        let inv = m.inverse().unwrap();
+        assert!((inv.get(0, 0) - 1e11).abs() < 1.0);
+        assert!((inv.get(1, 1) - 5e10).abs() < 1.0);
+        assert_eq!(m.rank(), 2);
+        assert_eq!(m.lu().unwrap().1.get(1, 1), 2e-11);
+    }
+
+    #[test]
+    fn test_inverse_mixed_column_scales() {
+        // Tras normalizar, los pivotes valen 1: la escala de la columna no debe volver a aplicarse
+        let big = matrix![1e12, 0.0; 0.0, 1.0];
+        let inv = big.inverse().unwrap();
+        assert!((inv.get(0, 0) - 1e-12).abs() < 1e-24);
+        assert_eq!(inv.get(1, 1), 1.0);
+
+        let small = matrix![1e-12, 0.0; 0.0, 1.0];
+        let inv = small.inverse().unwrap();
+        assert!((inv.get(0, 0) - 1e12).abs() < 1.0);
+        assert_eq!(inv.get(1, 1), 1.0);
+    }
+}
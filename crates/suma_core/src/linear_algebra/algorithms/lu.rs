@@ -0,0 +1,144 @@
+use crate::linear_algebra::matrices::implementations::dense::DenseMatrix;
+use crate::linear_algebra::traits::Scalar;
+use crate::linear_algebra::error::LinearAlgebraError;
+
+impl<T> DenseMatrix<T>
+where
+    T: Scalar,
+{
+    /// Factorización LU con pivoteo parcial: `P·A = L·U`.
+    ///
+    /// Devuelve `(L, U, perm)`, donde `L` es triangular inferior con unos en la
+    /// diagonal, `U` triangular superior y `perm[i]` es la fila de `A` que quedó
+    /// en la posición `i`. Se reutiliza con `LinearSystem::solve_lu` para resolver
+    /// varios `Ax = b` con la misma `A` sin volver a factorizar.
+    pub fn lu(&self) -> Result<(DenseMatrix<T>, DenseMatrix<T>, Vec<usize>), LinearAlgebraError> {
+        if self.rows != self.cols {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "LU Decomposition (A must be square)".to_string(),
+                expected: self.rows,
+                found: self.cols,
+            });
+        }
+
+        let n = self.rows;
+        let mut u = self.clone();
+        let mut l = DenseMatrix::zeros(n, n);
+        let mut perm: Vec<usize> = (0..n).collect();
+        let scales = self.column_magnitudes();
+
+        for k in 0..n {
+            // 1. Pivoteo parcial: el de mayor magnitud (el primero si hay empate).
+            //    Para tipos sin magnitud (Rational, Expr) queda el primer no nulo.
+            let magnitude = |i: usize| u.get_ref(i, k).magnitude().unwrap_or(0.0);
+            let Some(pivot_row) = (k..n)
+                .filter(|&i| !u.get_ref(i, k).is_negligible(scales[k]))
+                .min_by(|&a, &b| magnitude(b).partial_cmp(&magnitude(a)).unwrap_or(std::cmp::Ordering::Equal))
+            else {
+                return Err(LinearAlgebraError::DimensionMismatch {
+                    operation: "LU Decomposition (Singular Matrix)".to_string(),
+                    expected: 1,
+                    found: 0,
+                });
+            };
+
+            if pivot_row != k {
+                u.swap_rows(k, pivot_row)?;
+                l.swap_rows(k, pivot_row)?;
+                perm.swap(k, pivot_row);
+            }
+
+            // 2. Eliminación debajo del pivote; los multiplicadores forman L
+            let pivot = u.get(k, k);
+            for i in (k + 1)..n {
+                let target = u.get(i, k);
+                if target.is_zero() {
+                    continue;
+                }
+                let factor = target / pivot.clone();
+                l.set(i, k, factor.clone());
+                u.add_scaled_row(i, k, -factor)?;
+                // Cero exacto: en flotantes la resta puede dejar residuos de redondeo
+                u.set(i, k, T::zero());
+            }
+        }
+
+        for i in 0..n {
+            l.set(i, i, T::one());
+        }
+
+        Ok((l, u, perm))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::Rational;
+    use crate::matrix;
+
+    fn multiply(a: &DenseMatrix<f64>, b: &DenseMatrix<f64>) -> DenseMatrix<f64> {
+        let mut out = DenseMatrix::zeros(a.rows, b.cols);
+        for i in 0..a.rows {
+            for j in 0..b.cols {
+                let sum = (0..a.cols).map(|k| a.get(i, k) * b.get(k, j)).sum();
+                out.set(i, j, sum);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_lu_reconstructs_permuted_matrix() {
+        let a = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 10.0
+        ];
+        let (l, u, perm) = a.lu().unwrap();
+
+        // Pivoteo parcial: la fila con 7 pasa arriba
+        assert_eq!(perm[0], 2);
+        for i in 0..3 {
+            assert_eq!(l.get(i, i), 1.0);
+            for j in 0..i {
+                assert_eq!(u.get(i, j), 0.0);
+                assert_eq!(l.get(j, i), 0.0);
+            }
+        }
+
+        let mut permuted = DenseMatrix::zeros(3, 3);
+        for (i, &row) in perm.iter().enumerate() {
+            for j in 0..3 {
+                permuted.set(i, j, a.get(row, j));
+            }
+        }
+        assert!(multiply(&l, &u).is_approx(&permuted));
+    }
+
+    #[test]
+    fn test_lu_exact_rational() {
+        let r = |n| Rational::from_integer(n);
+        let a = matrix![
+            r(0), r(2);
+            r(3), r(1)
+        ];
+        let (l, u, perm) = a.lu().unwrap();
+        assert_eq!(perm, vec![1, 0]);
+        assert_eq!(l, matrix![r(1), r(0); r(0), r(1)]);
+        assert_eq!(u, matrix![r(3), r(1); r(0), r(2)]);
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        let a = matrix![
+            1.0, 2.0;
+            2.0, 4.0
+        ];
+        assert_eq!(a.lu().unwrap_err(), LinearAlgebraError::DimensionMismatch {
+            operation: "LU Decomposition (Singular Matrix)".to_string(),
+            expected: 1,
+            found: 0,
+        });
+    }
+}
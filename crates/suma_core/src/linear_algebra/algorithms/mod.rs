@@ -1,3 +1,7 @@
 pub mod gaussian;
 pub mod inverse;
-pub mod determinant;
\ No newline at end of file
+pub mod determinant;
+pub mod definiteness;
+pub mod lu;
+pub mod rank;
+pub mod eigen;
@@ -0,0 +1,60 @@
+use crate::linear_algebra::matrices::implementations::dense::DenseMatrix;
+use crate::linear_algebra::traits::Scalar;
+
+impl<T> DenseMatrix<T>
+where
+    T: Scalar,
+{
+    /// Rango: número de pivotes al reducir una copia por filas.
+    /// En `f64` un candidato a pivote cuenta como cero si es despreciable frente a
+    /// la mayor magnitud de su columna (`Scalar::is_negligible`); en `Rational` y
+    /// `Expr` la comparación es exacta.
+    pub fn rank(&self) -> usize {
+        let mut reduced = self.clone();
+        // Las operaciones de fila de rref siempre usan índices válidos
+        reduced.rref_pivots().expect("rref sobre índices válidos").len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linear_algebra::matrices::implementations::dense::DenseMatrix;
+    use crate::linear_algebra::Rational;
+    use crate::matrix;
+    use crate::symbolics::ast::{var, Expr};
+
+    #[test]
+    fn test_rank_numeric() {
+        let full = matrix![2.0, 1.0; 1.0, 3.0];
+        assert_eq!(full.rank(), 2);
+
+        // La tercera fila es combinación de las otras; en flotantes quedan residuos ~1e-16
+        let deficient = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 9.0
+        ];
+        assert_eq!(deficient.rank(), 2);
+
+        let rect = matrix![1.0, 2.0, 3.0, 4.0; 2.0, 4.0, 6.0, 8.0];
+        assert_eq!(rect.rank(), 1);
+        assert_eq!(DenseMatrix::<f64>::zeros(3, 2).rank(), 0);
+    }
+
+    #[test]
+    fn test_rank_exact_types() {
+        let r = |n| Rational::from_integer(n);
+        let m = matrix![r(1), r(2); r(2), r(4)];
+        assert_eq!(m.rank(), 1);
+
+        let m = matrix![var("x"), Expr::from(0.0); Expr::from(0.0), var("y")];
+        assert_eq!(m.rank(), 2);
+    }
+
+    #[test]
+    fn test_rank_mixed_column_scales() {
+        assert_eq!(matrix![1e12, 0.0; 0.0, 1.0].rank(), 2);
+        assert_eq!(matrix![1e-12, 0.0; 0.0, 1.0].rank(), 2);
+        assert_eq!(matrix![1e12, 2e12; 1.0, 2.0].rank(), 1);
+    }
+}
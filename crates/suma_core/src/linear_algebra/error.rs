@@ -13,6 +13,12 @@ pub enum LinearAlgebraError {
         index: usize,
         max: usize,      // El límite que se violó
     },
+    /// Sistema sin solución: tras RREF queda una fila `0 = c` con `c != 0`.
+    Inconsistent,
+    /// La operación solo está definida para matrices simétricas.
+    NotSymmetric,
+    /// Un método iterativo no alcanzó la tolerancia pedida.
+    NoConvergence { iterations: usize },
 }
 
 impl fmt::Display for LinearAlgebraError {
@@ -22,9 +28,21 @@ impl fmt::Display for LinearAlgebraError {
                 write!(f, "[Error {}]: Se esperaba {}, se encontró {}.", operation, expected, found)
             }
             // Mensaje claro para el usuario
+            LinearAlgebraError::IndexOutOfBounds { context, index, max: 0 } => {
+                write!(f, "Error de Índice: Intento de acceder a {} {}, pero la matriz no tiene ninguna.", context, index)
+            }
             LinearAlgebraError::IndexOutOfBounds { context, index, max } => {
                 write!(f, "Error de Índice: Intento de acceder a {} {}, pero el máximo permitido es {}.", context, index, max - 1)
             }
+            LinearAlgebraError::Inconsistent => {
+                write!(f, "Sistema inconsistente: no existe ninguna solución.")
+            }
+            LinearAlgebraError::NotSymmetric => {
+                write!(f, "La matriz no es simétrica.")
+            }
+            LinearAlgebraError::NoConvergence { iterations } => {
+                write!(f, "Sin convergencia tras {} iteraciones.", iterations)
+            }
         }
     }
 }
@@ -34,4 +52,29 @@ impl Error for LinearAlgebraError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         None
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_messages() {
+        let err = LinearAlgebraError::DimensionMismatch {
+            operation: "Multiplicación".to_string(),
+            expected: 3,
+            found: 2,
+        };
+        assert_eq!(err.to_string(), "[Error Multiplicación]: Se esperaba 3, se encontró 2.");
+
+        let err = LinearAlgebraError::IndexOutOfBounds { context: "Fila".to_string(), index: 4, max: 3 };
+        assert_eq!(err.to_string(), "Error de Índice: Intento de acceder a Fila 4, pero el máximo permitido es 2.");
+    }
+
+    #[test]
+    fn test_display_empty_dimension() {
+        // Antes `max - 1` desbordaba con matrices vacías
+        let err = LinearAlgebraError::IndexOutOfBounds { context: "Columna".to_string(), index: 0, max: 0 };
+        assert!(err.to_string().contains("no tiene ninguna"));
+    }
+}
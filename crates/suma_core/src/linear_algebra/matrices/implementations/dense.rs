@@ -2,6 +2,7 @@ use std::fmt;
 
 // src/linear_algebra/matrices/implementations/dense.rs
 use crate::linear_algebra::traits::Scalar;
+use crate::linear_algebra::error::LinearAlgebraError;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DenseMatrix<T>
@@ -22,16 +23,85 @@ where
         Self { data, rows, cols }
     }
 
+    /// Construye la matriz a partir de sus filas. Todas deben tener la misma longitud.
+    pub fn from_rows(rows: &[Vec<T>]) -> Result<Self, LinearAlgebraError> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for row in rows {
+            if row.len() != cols {
+                return Err(LinearAlgebraError::DimensionMismatch {
+                    operation: "Construcción por filas".to_string(),
+                    expected: cols,
+                    found: row.len(),
+                });
+            }
+            data.extend(row.iter().cloned());
+        }
+        Ok(Self { data, rows: rows.len(), cols })
+    }
+
+    /// Construye la matriz a partir de sus columnas. Todas deben tener la misma longitud.
+    pub fn from_columns(columns: &[Vec<T>]) -> Result<Self, LinearAlgebraError> {
+        let rows = columns.first().map_or(0, |col| col.len());
+        for col in columns {
+            if col.len() != rows {
+                return Err(LinearAlgebraError::DimensionMismatch {
+                    operation: "Construcción por columnas".to_string(),
+                    expected: rows,
+                    found: col.len(),
+                });
+            }
+        }
+        // El almacenamiento es por filas: recorremos las columnas de forma transpuesta
+        let mut data = Vec::with_capacity(rows * columns.len());
+        for i in 0..rows {
+            data.extend(columns.iter().map(|col| col[i].clone()));
+        }
+        Ok(Self { data, rows, cols: columns.len() })
+    }
+
+    /// Concatena horizontalmente `[self | other]`. Ambas matrices deben tener
+    /// el mismo número de filas.
+    pub fn augment(&self, other: &DenseMatrix<T>) -> Result<DenseMatrix<T>, LinearAlgebraError> {
+        if self.rows != other.rows {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Augment (Rows A vs Rows B)".to_string(),
+                expected: self.rows,
+                found: other.rows,
+            });
+        }
+
+        let cols = self.cols + other.cols;
+        let mut data = Vec::with_capacity(self.rows * cols);
+        for i in 0..self.rows {
+            data.extend_from_slice(&self.data[i * self.cols..(i + 1) * self.cols]);
+            data.extend_from_slice(&other.data[i * other.cols..(i + 1) * other.cols]);
+        }
+        Ok(Self { data, rows: self.rows, cols })
+    }
+
     // Cambio importante: Ahora devuelve T clonándolo, porque T podría no ser Copy (Expr)
-    pub fn get(&self, row: usize, col: usize) -> T {
+    // Acceso rápido sin validación: un índice de columna fuera de rango lee otra fila
+    // en silencio. Desde fuera del crate usar `try_get`.
+    pub(crate) fn get(&self, row: usize, col: usize) -> T {
         self.data[row * self.cols + col].clone() 
     }
 
     // Opcional: Una versión más eficiente que devuelve referencia
-    pub fn get_ref(&self, row: usize, col: usize) -> &T {
+    pub(crate) fn get_ref(&self, row: usize, col: usize) -> &T {
         &self.data[row * self.cols + col]
     }
 
+    /// Mayor magnitud de cada columna: la escala de referencia de `Scalar::is_negligible`.
+    /// Los tipos sin magnitud (Rational, Expr) dan 0.
+    pub(crate) fn column_magnitudes(&self) -> Vec<f64> {
+        (0..self.cols)
+            .map(|j| (0..self.rows)
+                .filter_map(|i| self.get_ref(i, j).magnitude())
+                .fold(0.0, f64::max))
+            .collect()
+    }
+
     pub fn zeros(rows: usize, cols: usize) -> Self {
         // T::zero() viene de nuestro trait Zero
         // vec! con clone requiere que T sea Clone, lo cual Scalar garantiza.
@@ -39,9 +109,41 @@ where
         Self { data, rows, cols }
     }
     
-    pub fn set(&mut self, row: usize, col: usize, value: T) {
+    // Igual que `get`: sin validación de índices. Desde fuera del crate usar `try_set`.
+    pub(crate) fn set(&mut self, row: usize, col: usize, value: T) {
         self.data[row * self.cols + col] = value;
     }
+
+    /// Lectura con validación de índices.
+    pub fn try_get(&self, row: usize, col: usize) -> Result<T, LinearAlgebraError> {
+        self.check_index(row, col)?;
+        Ok(self.get(row, col))
+    }
+
+    /// Escritura con validación de índices.
+    pub fn try_set(&mut self, row: usize, col: usize, value: T) -> Result<(), LinearAlgebraError> {
+        self.check_index(row, col)?;
+        self.set(row, col, value);
+        Ok(())
+    }
+
+    fn check_index(&self, row: usize, col: usize) -> Result<(), LinearAlgebraError> {
+        if row >= self.rows {
+            return Err(LinearAlgebraError::IndexOutOfBounds {
+                context: "Fila".to_string(),
+                index: row,
+                max: self.rows,
+            });
+        }
+        if col >= self.cols {
+            return Err(LinearAlgebraError::IndexOutOfBounds {
+                context: "Columna".to_string(),
+                index: col,
+                max: self.cols,
+            });
+        }
+        Ok(())
+    }
     
     pub fn is_approx(&self, other: &DenseMatrix<T>) -> bool {
         // 1. Si las dimensiones son distintas, imposible que sean iguales
@@ -120,4 +222,79 @@ where
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_rows_and_columns() {
+        let expected = crate::matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+        let by_rows = DenseMatrix::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let by_cols = DenseMatrix::from_columns(&[vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]).unwrap();
+
+        assert_eq!(by_rows, expected);
+        assert_eq!(by_cols, expected);
+    }
+
+    #[test]
+    fn test_from_rows_length_mismatch() {
+        let result = DenseMatrix::from_rows(&[vec![1.0, 2.0], vec![3.0]]);
+        assert_eq!(result, Err(LinearAlgebraError::DimensionMismatch {
+            operation: "Construcción por filas".to_string(),
+            expected: 2,
+            found: 1,
+        }));
+        assert!(DenseMatrix::from_columns(&[vec![1.0], vec![2.0, 3.0]]).is_err());
+    }
+
+    #[test]
+    fn test_augment() {
+        let a = crate::matrix![1.0, 2.0; 3.0, 4.0];
+        let b = crate::matrix![5.0; 6.0];
+        assert_eq!(a.augment(&b).unwrap(), crate::matrix![1.0, 2.0, 5.0; 3.0, 4.0, 6.0]);
+
+        let c = crate::matrix![1.0, 2.0, 3.0];
+        assert!(matches!(a.augment(&c), Err(LinearAlgebraError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_augment_symbolic() {
+        use crate::symbolics::ast::{var, Expr};
+        let a = crate::matrix![var("x"); var("y")];
+        let b = crate::matrix![Expr::from(1.0); Expr::from(2.0)];
+        let aug = a.augment(&b).unwrap();
+        assert_eq!(aug.cols, 2);
+        assert_eq!(aug.try_get(1, 0), Ok(var("y")));
+        assert_eq!(aug.try_get(1, 1), Ok(Expr::from(2.0)));
+    }
+
+    #[test]
+    fn test_try_get_and_set_in_bounds() {
+        let mut m = DenseMatrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(m.try_get(1, 2), Ok(6.0));
+
+        m.try_set(0, 1, 9.0).unwrap();
+        assert_eq!(m.try_get(0, 1), Ok(9.0));
+    }
+
+    #[test]
+    fn test_try_get_and_set_out_of_bounds() {
+        let mut m: DenseMatrix<f64> = DenseMatrix::zeros(2, 3);
+
+        // Con `get`, (0, 3) leería en silencio el elemento (1, 0)
+        assert_eq!(m.try_get(0, 3), Err(LinearAlgebraError::IndexOutOfBounds {
+            context: "Columna".to_string(),
+            index: 3,
+            max: 3,
+        }));
+        assert_eq!(m.try_set(2, 0, 1.0), Err(LinearAlgebraError::IndexOutOfBounds {
+            context: "Fila".to_string(),
+            index: 2,
+            max: 2,
+        }));
+        assert!(m.data.iter().all(|v| *v == 0.0));
+    }
+}
@@ -60,6 +60,7 @@ where
 
     /// Operación 3: Sumar Fila Escalada (Add Scaled)
     /// R_target -> R_target + (scalar * R_source)
+    /// Ojo al orden: primero la fila destino y después la fila origen.
     /// Esta es la operación "workhorse" de la eliminación gaussiana.
     pub fn add_scaled_row(&mut self, target_row: usize, source_row: usize, scalar: T) -> Result<(), LinearAlgebraError> {
         self.check_row_bounds(target_row)?;
@@ -116,6 +117,36 @@ mod tests {
         assert_ne!(target_cell, var("x")); 
     }
 
+    fn sample() -> DenseMatrix<f64> {
+        DenseMatrix::new(3, 2, vec![
+            1.0, 2.0,
+            3.0, 4.0,
+            5.0, 6.0,
+        ])
+    }
+
+    #[test]
+    fn test_swap_rows() {
+        let mut m = sample();
+        m.swap_rows(0, 2).unwrap();
+        assert_eq!(m.data, vec![5.0, 6.0, 3.0, 4.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_scale_row() {
+        let mut m = sample();
+        m.scale_row(1, 0.5).unwrap();
+        assert_eq!(m.data, vec![1.0, 2.0, 1.5, 2.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_add_scaled_row() {
+        // R2 -> R2 - 5 * R0
+        let mut m = sample();
+        m.add_scaled_row(2, 0, -5.0).unwrap();
+        assert_eq!(m.data, vec![1.0, 2.0, 3.0, 4.0, 0.0, -4.0]);
+    }
+
     #[test]
     fn test_index_out_of_bounds() {
         let mut m: DenseMatrix<Expr> = DenseMatrix::zeros(2, 2);
@@ -7,4 +7,5 @@ pub mod systems;
 
 pub use matrices::*;
 pub use traits::*;
-pub use systems::*;
\ No newline at end of file
+pub use systems::*;
+pub use adapter::Rational;
\ No newline at end of file
@@ -1,6 +1,34 @@
 use crate::linear_algebra::matrices::implementations::dense::DenseMatrix;
 use crate::linear_algebra::traits::Scalar;
 use crate::linear_algebra::error::LinearAlgebraError;
+use crate::linear_algebra::algorithms::determinant::determinant_from_lu;
+
+/// Solución general de Ax = b: `x = particular + Σ tₖ · null_space[k]`,
+/// con un parámetro libre `tₖ` por cada columna sin pivote.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneralSolution<T>
+where
+    T: Scalar,
+{
+    /// Solución con todas las variables libres en cero (n x 1).
+    pub particular: DenseMatrix<T>,
+    /// Base del espacio nulo de A, un vector (n x 1) por variable libre.
+    pub null_space: Vec<DenseMatrix<T>>,
+    /// Índices de las columnas (variables) con pivote.
+    pub pivot_columns: Vec<usize>,
+    /// Índices de las variables libres, en el mismo orden que `null_space`.
+    pub free_columns: Vec<usize>,
+}
+
+impl<T> GeneralSolution<T>
+where
+    T: Scalar,
+{
+    /// La solución es única cuando no hay variables libres.
+    pub fn is_unique(&self) -> bool {
+        self.free_columns.is_empty()
+    }
+}
 
 /// Estructura helper para resolver sistemas de ecuaciones lineales.
 /// No almacena datos, solo provee métodos estáticos de utilidad.
@@ -42,48 +70,189 @@ impl LinearSystem {
             });
         }
 
-        // 2. Construir Matriz Aumentada [A | b] manualmente
-        // (Sería ideal tener un método a.augment(&b) en DenseMatrix en el futuro)
+        // 2. Construir Matriz Aumentada [A | b]
         let rows = a.rows;
-        let cols_aug = a.cols + 1;
-        let mut aug_data = Vec::with_capacity(rows * cols_aug);
+        let mut augmented = a.augment(b)?;
+
+        // 3. Resolver usando el motor existente
+        let pivots = augmented.rref_pivots()?;
+
+        // 4. Validar que cada incógnita tenga pivote. Si falta alguno, el sistema es
+        // singular (inconsistente o con infinitas soluciones); para un solver
+        // simple, esto es un error.
+        if !(0..rows).all(|i| pivots.get(i) == Some(&i)) {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Solve System (Singular Matrix)".to_string(),
+                expected: 1,
+                found: 0,
+            });
+        }
+
+        // La solución está en la última columna
+        let x_data = (0..rows).map(|i| augmented.get(i, rows)).collect();
+
+        Ok(DenseMatrix::new(rows, 1, x_data))
+    }
+
+    /// Resuelve Ax = b por la regla de Cramer: `x_i = det(A_i) / det(A)`, donde `A_i`
+    /// es A con la columna `i` reemplazada por b. Costoso (n + 1 determinantes),
+    /// pero con `Expr` da directamente la forma cerrada de cada incógnita.
+    pub fn solve_cramer<T>(a: &DenseMatrix<T>, b: &DenseMatrix<T>) -> Result<DenseMatrix<T>, LinearAlgebraError>
+    where
+        T: Scalar,
+    {
+        if a.rows != b.rows {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Solve Cramer (Rows A vs Rows b)".to_string(),
+                expected: a.rows,
+                found: b.rows,
+            });
+        }
+        if b.cols != 1 {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Solve Cramer (b must be a vector)".to_string(),
+                expected: 1,
+                found: b.cols,
+            });
+        }
+
+        if a.rows != a.cols {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Solve Cramer (A must be square)".to_string(),
+                expected: a.rows,
+                found: a.cols,
+            });
+        }
 
-        for i in 0..rows {
-            // Copiar fila de A
-            for j in 0..a.cols {
-                aug_data.push(a.get(i, j));
+        // Una sola factorización de A: la singularidad se decide con sus pivotes
+        // (tolerancia relativa a cada columna), no con un umbral sobre det(A), que
+        // dependería de la escala de la matriz; det(A) sale de la diagonal de U.
+        let Ok((_, u, perm)) = a.lu() else {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Solve Cramer (Singular Matrix)".to_string(),
+                expected: 1,
+                found: 0,
+            });
+        };
+        let det = determinant_from_lu(&u, &perm);
+
+        let mut x_data = Vec::with_capacity(a.cols);
+        for i in 0..a.cols {
+            let mut replaced = a.clone();
+            for row in 0..a.rows {
+                replaced.set(row, i, b.get(row, 0));
             }
-            // Copiar elemento de b
-            aug_data.push(b.get(i, 0));
+            x_data.push(replaced.determinant()? / det.clone());
         }
 
-        let mut augmented = DenseMatrix::new(rows, cols_aug, aug_data);
+        Ok(DenseMatrix::new(a.cols, 1, x_data))
+    }
 
-        // 3. Resolver usando el motor existente
-        augmented.rref()?;
+    /// Solución general de Ax = b para cualquier A (m x n), incluidos los sistemas
+    /// con infinitas soluciones. Tras RREF, las columnas sin pivote son variables
+    /// libres; cada una aporta un vector a la base del espacio nulo.
+    ///
+    /// Devuelve `LinearAlgebraError::Inconsistent` si el sistema no tiene solución.
+    pub fn solve_general<T>(a: &DenseMatrix<T>, b: &DenseMatrix<T>) -> Result<GeneralSolution<T>, LinearAlgebraError>
+    where
+        T: Scalar,
+    {
+        if b.cols != 1 {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Solve General (b must be a vector)".to_string(),
+                expected: 1,
+                found: b.cols,
+            });
+        }
 
-        // 4. Extraer solución y validar consistencia
-        let mut x_data = Vec::with_capacity(rows);
-        
-        for i in 0..rows {
-            // Verificar si tenemos un pivote válido en la diagonal
-            // En RREF de una matriz invertible, la diagonal debe ser 1.
-            let diag = augmented.get(i, i);
-            if diag.is_zero() {
-                // Si la diagonal es 0, el sistema es singular (inconsistente o infinitas soluciones)
-                // Para una solver simple, esto es un error.
-                 return Err(LinearAlgebraError::DimensionMismatch {
-                    operation: "Solve System (Singular Matrix)".to_string(),
+        let n = a.cols;
+        let mut augmented = a.augment(b)?;
+        let pivot_columns = augmented.rref_pivots()?;
+
+        // 1. Columna pivote de cada fila; un pivote en la columna de b (0 = b_i) es inconsistente
+        if pivot_columns.contains(&n) {
+            return Err(LinearAlgebraError::Inconsistent);
+        }
+        let free_columns: Vec<usize> = (0..n).filter(|j| !pivot_columns.contains(j)).collect();
+
+        // 2. Solución particular: variables libres en cero
+        let mut particular = DenseMatrix::zeros(n, 1);
+        for (row, &col) in pivot_columns.iter().enumerate() {
+            particular.set(col, 0, augmented.get(row, n));
+        }
+
+        // 3. Base del espacio nulo: x_f = 1 y x_pivote = -coeficiente de f en su fila
+        let null_space = free_columns
+            .iter()
+            .map(|&free| {
+                let mut v = DenseMatrix::zeros(n, 1);
+                v.set(free, 0, T::one());
+                for (row, &col) in pivot_columns.iter().enumerate() {
+                    v.set(col, 0, -augmented.get(row, free));
+                }
+                v
+            })
+            .collect();
+
+        Ok(GeneralSolution { particular, null_space, pivot_columns, free_columns })
+    }
+
+    /// Resuelve Ax = b a partir de la factorización `(L, U, perm)` de `DenseMatrix::lu`.
+    /// Solo hace sustitución hacia adelante y hacia atrás, así que la factorización
+    /// se amortiza entre varios vectores `b`.
+    pub fn solve_lu<T>(
+        lu: &(DenseMatrix<T>, DenseMatrix<T>, Vec<usize>),
+        b: &DenseMatrix<T>,
+    ) -> Result<DenseMatrix<T>, LinearAlgebraError>
+    where
+        T: Scalar,
+    {
+        let (l, u, perm) = lu;
+        let n = u.rows;
+        if b.rows != n {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Solve LU (Rows A vs Rows b)".to_string(),
+                expected: n,
+                found: b.rows,
+            });
+        }
+        if b.cols != 1 {
+            return Err(LinearAlgebraError::DimensionMismatch {
+                operation: "Solve LU (b must be a vector)".to_string(),
+                expected: 1,
+                found: b.cols,
+            });
+        }
+
+        // 1. L y = P b (L tiene unos en la diagonal)
+        let mut y: Vec<T> = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut value = b.get(perm[i], 0);
+            for (j, y_j) in y.iter().enumerate() {
+                value = value - l.get(i, j) * y_j.clone();
+            }
+            y.push(value);
+        }
+
+        // 2. U x = y
+        let mut x = vec![T::zero(); n];
+        for i in (0..n).rev() {
+            let pivot = u.get(i, i);
+            if pivot.is_zero() {
+                return Err(LinearAlgebraError::DimensionMismatch {
+                    operation: "Solve LU (Singular Matrix)".to_string(),
                     expected: 1,
                     found: 0,
                 });
             }
-            
-            // La solución está en la última columna
-            x_data.push(augmented.get(i, rows));
+            let mut value = y[i].clone();
+            for j in (i + 1)..n {
+                value = value - u.get(i, j) * x[j].clone();
+            }
+            x[i] = value / pivot;
         }
 
-        Ok(DenseMatrix::new(rows, 1, x_data))
+        Ok(DenseMatrix::new(n, 1, x))
     }
 }
 
@@ -93,6 +262,7 @@ mod tests {
 
     use super::*;
     use crate::linear_algebra::matrices::implementations::dense::DenseMatrix;
+    use crate::linear_algebra::Rational;
     use crate::matrix;
     use crate::symbolics::ast::{var, Expr};
     use crate::symbolics::context;
@@ -194,6 +364,45 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_solve_rational_hilbert_system() {
+        // Matriz de Hilbert 3x3 (mal condicionada): H x = e1
+        // Solución exacta: primera columna de H^-1 = [9, -36, 30]
+        let r = |n: i64, d: i64| Rational::new(n, d);
+
+        let a = matrix![
+            r(1, 1), r(1, 2), r(1, 3);
+            r(1, 2), r(1, 3), r(1, 4);
+            r(1, 3), r(1, 4), r(1, 5)
+        ];
+        let b = matrix![
+            r(1, 1);
+            r(0, 1);
+            r(0, 1)
+        ];
+
+        let x = LinearSystem::solve(&a, &b).expect("Hilbert es invertible");
+        let expected = matrix![
+            r(9, 1);
+            r(-36, 1);
+            r(30, 1)
+        ];
+        assert_eq!(x, expected);
+
+        // Fracciones no enteras también se conservan exactas: x + y = 1, 3x = 1
+        let a = matrix![
+            r(1, 1), r(1, 1);
+            r(3, 1), r(0, 1)
+        ];
+        let b = matrix![
+            r(1, 1);
+            r(1, 1)
+        ];
+        let x = LinearSystem::solve(&a, &b).unwrap();
+        assert_eq!(x.get(0, 0), r(1, 3));
+        assert_eq!(x.get(1, 0), r(2, 3));
+    }
+
     #[test]
     fn test_solve_symbolic_system() {
         // Sistema Simbólico Triangular Superior:
@@ -260,4 +469,175 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_solve_lu_reuses_factorization() {
+        let a = matrix![
+            2.0, 1.0, 1.0;
+            4.0, -6.0, 0.0;
+            -2.0, 7.0, 2.0
+        ];
+        let lu = a.lu().expect("Matriz invertible");
+
+        for b in [matrix![5.0; -2.0; 9.0], matrix![1.0; 0.0; 0.0], matrix![0.0; 3.0; -1.0]] {
+            let x = LinearSystem::solve_lu(&lu, &b).unwrap();
+            let expected = LinearSystem::solve(&a, &b).unwrap();
+            assert!(x.is_approx(&expected), "LU: {:?}\nRREF: {:?}", x, expected);
+        }
+    }
+
+    #[test]
+    fn test_solve_lu_dimension_mismatch() {
+        let lu = matrix![1.0, 0.0; 0.0, 1.0].lu().unwrap();
+        assert!(LinearSystem::solve_lu(&lu, &matrix![1.0; 2.0; 3.0]).is_err());
+    }
+
+    #[test]
+    fn test_solve_general_underdetermined() {
+        // x + 2y - z = 3
+        // 2x + 4y + z = 9
+        // Pivotes en x y z; y es libre
+        let a = matrix![
+            1.0, 2.0, -1.0;
+            2.0, 4.0, 1.0
+        ];
+        let b = matrix![3.0; 9.0];
+
+        let sol = LinearSystem::solve_general(&a, &b).unwrap();
+        assert_eq!(sol.pivot_columns, vec![0, 2]);
+        assert_eq!(sol.free_columns, vec![1]);
+        assert!(sol.particular.is_approx(&matrix![4.0; 0.0; 1.0]));
+        assert_eq!(sol.null_space.len(), 1);
+        assert!(sol.null_space[0].is_approx(&matrix![-2.0; 1.0; 0.0]));
+
+        // Cualquier valor del parámetro da una solución: A (p + t·v) = b
+        for t in [-1.5, 0.0, 2.0] {
+            for i in 0..2 {
+                let row: f64 = (0..3)
+                    .map(|j| a.get(i, j) * (sol.particular.get(j, 0) + t * sol.null_space[0].get(j, 0)))
+                    .sum();
+                assert!(row.is_approx(&b.get(i, 0)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_general_unique_and_inconsistent() {
+        let a = matrix![2.0, 1.0; 1.0, 3.0];
+        let sol = LinearSystem::solve_general(&a, &matrix![5.0; 5.0]).unwrap();
+        assert!(sol.is_unique());
+        assert!(sol.particular.is_approx(&matrix![2.0; 1.0]));
+
+        // x + y = 1, 2x + 2y = 5
+        let a = matrix![1.0, 1.0; 2.0, 2.0];
+        assert_eq!(LinearSystem::solve_general(&a, &matrix![1.0; 5.0]), Err(LinearAlgebraError::Inconsistent));
+    }
+
+    #[test]
+    fn test_solve_general_symbolic_rhs() {
+        // x + y = a, con y libre: x = a - y
+        let a = matrix![Expr::from(1.0), Expr::from(1.0)];
+        let b = matrix![var("a")];
+
+        let sol = LinearSystem::solve_general(&a, &b).unwrap();
+        assert_eq!(sol.free_columns, vec![1]);
+        assert_eq!(sol.particular.get(0, 0).simplify(), var("a"));
+        assert_eq!(sol.null_space[0].get(0, 0).simplify(), Expr::Const(-1.0));
+        assert_eq!(sol.null_space[0].get(1, 0), Expr::Const(1.0));
+    }
+
+    #[test]
+    fn test_solve_cramer_numeric() {
+        let a = matrix![
+            2.0, 1.0, -1.0;
+            -3.0, -1.0, 2.0;
+            -2.0, 1.0, 2.0
+        ];
+        let b = matrix![8.0; -11.0; -3.0];
+
+        let x = LinearSystem::solve_cramer(&a, &b).unwrap();
+        assert!(x.is_approx(&matrix![2.0; 3.0; -1.0]), "{:?}", x);
+
+        let singular = matrix![1.0, 2.0; 2.0, 4.0];
+        assert_eq!(LinearSystem::solve_cramer(&singular, &matrix![1.0; 2.0]), Err(LinearAlgebraError::DimensionMismatch {
+            operation: "Solve Cramer (Singular Matrix)".to_string(),
+            expected: 1,
+            found: 0,
+        }));
+
+        let rect = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+        assert_eq!(LinearSystem::solve_cramer(&rect, &matrix![1.0; 2.0]), Err(LinearAlgebraError::DimensionMismatch {
+            operation: "Solve Cramer (A must be square)".to_string(),
+            expected: 2,
+            found: 3,
+        }));
+    }
+
+    #[test]
+    fn test_solve_cramer_symbolic_closed_form() {
+        // [a b; c d] x = [e; f]  =>  x = (ed - bf) / (ad - bc)
+        let a = matrix![var("a"), var("b"); var("c"), var("d")];
+        let b = matrix![var("e"); var("f")];
+        let x = LinearSystem::solve_cramer(&a, &b).unwrap();
+
+        let mut ctx = context::Context::new();
+        for (name, value) in [("a", 2.0), ("b", 1.0), ("c", 1.0), ("d", 3.0), ("e", 5.0), ("f", 5.0)] {
+            ctx.set(name, value);
+        }
+        assert!((x.get(0, 0).evaluate(&ctx).unwrap() - 2.0).abs() < 1e-12);
+        assert!((x.get(1, 0).evaluate(&ctx).unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_solve_small_scale_system() {
+        // 1e-11·I no es singular: la tolerancia escala con la matriz
+        let a = matrix![1e-11, 0.0; 0.0, 1e-11];
+        let b = matrix![2e-11; 3e-11];
+        let x = LinearSystem::solve(&a, &b).unwrap();
+        assert!(x.is_approx(&matrix![2.0; 3.0]));
+
+        let general = LinearSystem::solve_general(&a, &b).unwrap();
+        assert!(general.null_space.is_empty());
+    }
+
+    #[test]
+    fn test_solve_cramer_singularity_is_scale_independent() {
+        // det = 1e-33: bien condicionada aunque el determinante sea diminuto
+        let small = matrix![
+            1e-11, 0.0, 0.0;
+            0.0, 2e-11, 0.0;
+            0.0, 0.0, 5e-12
+        ];
+        let x = LinearSystem::solve_cramer(&small, &matrix![1e-11; 4e-11; 5e-12]).unwrap();
+        assert!(x.is_approx(&matrix![1.0; 2.0; 1.0]), "{:?}", x);
+
+        // Singular a gran escala: el residuo de redondeo de det(A) no la vuelve invertible
+        let large = matrix![
+            1e6, 2e6, 3e6;
+            4e6, 5e6, 6e6;
+            7e6, 8e6, 9e6
+        ];
+        assert!(LinearSystem::solve_cramer(&large, &matrix![1.0; 2.0; 3.0]).is_err());
+    }
+
+    #[test]
+    fn test_solve_mixed_column_scales() {
+        let b = matrix![2.0; 3.0];
+        for (scale, x0) in [(1e12, 2e-12), (1e-12, 2e12)] {
+            let a = matrix![scale, 0.0; 0.0, 1.0];
+            let x = LinearSystem::solve(&a, &b).unwrap();
+            assert!((x.get(0, 0) - x0).abs() <= 1e-12 * x0, "escala {}: {:?}", scale, x);
+            assert_eq!(x.get(1, 0), 3.0);
+
+            let general = LinearSystem::solve_general(&a, &b).unwrap();
+            assert_eq!(general.pivot_columns, vec![0, 1]);
+            assert!(general.null_space.is_empty());
+            assert!((general.particular.get(0, 0) - x0).abs() <= 1e-12 * x0);
+        }
+
+        // Sistema inconsistente a escala pequeña: 0 = 1e-12 no se confunde con 0 = 0
+        let a = matrix![1e-12, 1e-12; 1e-12, 1e-12];
+        let b = matrix![1e-12; 2e-12];
+        assert!(matches!(LinearSystem::solve_general(&a, &b), Err(LinearAlgebraError::Inconsistent)));
+    }
+}
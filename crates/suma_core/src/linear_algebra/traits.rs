@@ -24,4 +24,18 @@ pub trait Scalar:
     Zero + One + PartialEq
 {
     fn is_approx(&self, other: &Self) -> bool;
+
+    /// Magnitud usada para elegir el pivote en el pivoteo parcial.
+    /// `None` (tipos exactos o simbólicos) significa que cualquier valor no nulo sirve.
+    fn magnitude(&self) -> Option<f64> {
+        None
+    }
+
+    /// Cero a efectos de pivoteo, relativo a `scale` (la mayor magnitud de la
+    /// columna original, ver `DenseMatrix::column_magnitudes`). Los tipos numéricos
+    /// inexactos ignoran el ruido de redondeo sin confundir una matriz de escala
+    /// pequeña con una singular; los exactos y simbólicos usan `is_zero`.
+    fn is_negligible(&self, _scale: f64) -> bool {
+        self.is_zero()
+    }
 }
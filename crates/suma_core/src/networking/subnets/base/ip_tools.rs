@@ -12,6 +12,11 @@ pub fn cidr_to_mask(cidr: u8) -> Ipv4Addr {
     Ipv4Addr::from(mask_u32)
 }
 
+/// Máscara wildcard (inversa) de un prefijo, como la usan las ACL.
+pub fn cidr_to_wildcard(cidr: u8) -> Ipv4Addr {
+    Ipv4Addr::from(!u32::from(cidr_to_mask(cidr)))
+}
+
 pub fn mask_to_cidr(mask: Ipv4Addr) -> u8 {
     let octets = mask.octets();
     let mut cidr = 0;
@@ -92,6 +97,56 @@ pub fn parse_ip_cidr(ip_str: &str) -> Result<(Ipv4Addr, u8), String> {
 }
 
 
+/// Resume bloques `(red, cidr)` en el menor conjunto de superredes equivalente.
+/// Dos bloques se fusionan solo si tienen el mismo tamaño, son contiguos y el
+/// primero está alineado al prefijo superior; los bloques contenidos en otro se
+/// descartan. El resultado queda ordenado por dirección.
+pub fn aggregate(blocks: &[(Ipv4Addr, u8)]) -> Vec<(Ipv4Addr, u8)> {
+    let mut sorted: Vec<(u32, u8)> = blocks
+        .iter()
+        .map(|&(ip, cidr)| {
+            let cidr = cidr.min(32);
+            (u32::from(calculate_network_address(ip, cidr_to_mask(cidr))), cidr)
+        })
+        .collect();
+    // A igual red, primero el bloque más grande para descartar los contenidos
+    sorted.sort();
+
+    let block_end = |(network, cidr): (u32, u8)| network as u64 + (1u64 << (32 - cidr as u32));
+
+    let mut merged: Vec<(u32, u8)> = Vec::new();
+    for block in sorted {
+        if let Some(&last) = merged.last() {
+            if block_end(block) <= block_end(last) {
+                continue;
+            }
+        }
+        merged.push(block);
+
+        // Fusionar hermanos hasta que no quede ninguno (punto fijo)
+        while merged.len() >= 2 {
+            let (second_net, second_cidr) = merged[merged.len() - 1];
+            let (first_net, first_cidr) = merged[merged.len() - 2];
+            let parent_cidr = first_cidr.wrapping_sub(1);
+            let siblings = first_cidr == second_cidr
+                && first_cidr > 0
+                && u32::from(calculate_network_address(Ipv4Addr::from(first_net), cidr_to_mask(parent_cidr))) == first_net
+                && block_end((first_net, first_cidr)) == second_net as u64;
+            if !siblings {
+                break;
+            }
+            merged.pop();
+            merged.pop();
+            merged.push((first_net, parent_cidr));
+        }
+    }
+
+    merged
+        .into_iter()
+        .map(|(network, cidr)| (Ipv4Addr::from(network), cidr))
+        .collect()
+}
+
 // ip_tools.rs
 #[cfg(test)]
 mod tests {
@@ -122,6 +177,14 @@ mod tests {
         assert_eq!(cidr_to_mask(255), Ipv4Addr::new(255, 255, 255, 255));
     }
 
+    #[test]
+    fn test_cidr_to_wildcard() {
+        assert_eq!(cidr_to_wildcard(24), Ipv4Addr::new(0, 0, 0, 255));
+        assert_eq!(cidr_to_wildcard(27), Ipv4Addr::new(0, 0, 0, 31));
+        assert_eq!(cidr_to_wildcard(0), Ipv4Addr::new(255, 255, 255, 255));
+        assert_eq!(cidr_to_wildcard(32), Ipv4Addr::new(0, 0, 0, 0));
+    }
+
     // Tests para mask_to_cidr
     #[test]
     fn test_mask_to_cidr_valid() {
@@ -402,4 +465,74 @@ mod tests {
         assert_eq!(network_class(Ipv4Addr::new(172, 16, 0, 1)), "Clase B");
         assert_eq!(network_class(Ipv4Addr::new(192, 168, 0, 1)), "Clase C");
     }
-}
\ No newline at end of file
+
+    // Tests para aggregate
+    #[test]
+    fn test_aggregate_merges_aligned_siblings() {
+        let blocks = [
+            (Ipv4Addr::new(192, 168, 1, 128), 25),
+            (Ipv4Addr::new(192, 168, 1, 0), 26),
+            (Ipv4Addr::new(192, 168, 1, 64), 26),
+            (Ipv4Addr::new(192, 168, 1, 200), 29), // contenido en el /25
+        ];
+        assert_eq!(aggregate(&blocks), vec![(Ipv4Addr::new(192, 168, 1, 0), 24)]);
+    }
+
+    #[test]
+    fn test_aggregate_refuses_misaligned_or_gapped() {
+        // Contiguos y del mismo tamaño, pero .64/26 + .128/26 no forman un /25 alineado
+        let misaligned = [
+            (Ipv4Addr::new(10, 0, 0, 64), 26),
+            (Ipv4Addr::new(10, 0, 0, 128), 26),
+        ];
+        assert_eq!(aggregate(&misaligned), misaligned.to_vec());
+
+        // Hueco entre bloques
+        let gapped = [
+            (Ipv4Addr::new(10, 0, 0, 0), 26),
+            (Ipv4Addr::new(10, 0, 0, 128), 26),
+        ];
+        assert_eq!(aggregate(&gapped), gapped.to_vec());
+
+        // Todo el espacio de direcciones
+        let halves = [(Ipv4Addr::new(128, 0, 0, 0), 1), (Ipv4Addr::new(0, 0, 0, 0), 1)];
+        assert_eq!(aggregate(&halves), vec![(Ipv4Addr::new(0, 0, 0, 0), 0)]);
+    }
+
+    #[test]
+    fn test_subnet_overlap_and_membership() {
+        use crate::networking::subnets::base::model::{contains_ip, subnets_overlap, SubnetRow};
+
+        let row = |network: [u8; 4], cidr: u8| SubnetRow::new(1, Ipv4Addr::from(network), cidr_to_mask(cidr));
+        let big = row([10, 0, 0, 0], 24);
+        let inner = row([10, 0, 0, 64], 26);
+        let neighbor = row([10, 0, 1, 0], 24);
+
+        // Contención total en ambos sentidos
+        assert!(subnets_overlap(&big, &inner));
+        assert!(subnets_overlap(&inner, &big));
+        // Adyacentes pero disjuntas
+        assert!(!subnets_overlap(&big, &neighbor));
+
+        assert!(contains_ip(&big, Ipv4Addr::new(10, 0, 0, 0)));
+        assert!(contains_ip(&big, Ipv4Addr::new(10, 0, 0, 255)));
+        assert!(!contains_ip(&big, Ipv4Addr::new(10, 0, 1, 0)));
+        assert!(!contains_ip(&inner, Ipv4Addr::new(10, 0, 0, 128)));
+    }
+
+    #[test]
+    fn test_calculator_wildcard_mask() {
+        use crate::networking::subnets::{BaseCalculator, FLSMCalculator};
+
+        let flsm = FLSMCalculator::new("172.16.0.0/20", 4).unwrap();
+        assert_eq!(flsm.wildcard_mask(), "0.0.15.255");
+    }
+
+    #[test]
+    fn test_vlsm_plan_summarizes_to_base_network() {
+        use crate::networking::subnets::{BaseCalculator, VLSMCalculator};
+
+        let vlsm = VLSMCalculator::new("192.168.1.0/24", vec![100, 50, 20, 20]).unwrap();
+        assert_eq!(vlsm.summarize(), vec![(Ipv4Addr::new(192, 168, 1, 0), 24)]);
+    }
+}
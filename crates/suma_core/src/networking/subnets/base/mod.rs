@@ -2,4 +2,4 @@ pub mod model;
 pub mod ip_tools;
 pub mod export_dtos;
     
-pub use model::{BaseCalculator, SubnetRow};
+pub use model::{BaseCalculator, SubnetRow, contains_ip, subnets_overlap};
@@ -35,6 +35,12 @@ impl SubnetRow {
         }
     }
 
+    /// Prefijo de la subred, deducido del tamaño del bloque red..=broadcast.
+    pub fn cidr(&self) -> u8 {
+        let size = u32::from(self.broadcast) as u64 - u32::from(self.direccion_red) as u64 + 1;
+        32 - size.trailing_zeros() as u8
+    }
+
     pub fn to_csv(subnets: &[Self]) -> Result<String, Box<dyn Error>> {
         let mut wtr = Writer::from_writer(vec![]);
         for s in subnets {
@@ -46,6 +52,17 @@ impl SubnetRow {
     }
 }
 
+/// Verdadero si los rangos red..=broadcast se intersecan, incluido el caso en
+/// que una subred contiene por completo a la otra.
+pub fn subnets_overlap(a: &SubnetRow, b: &SubnetRow) -> bool {
+    a.direccion_red <= b.broadcast && b.direccion_red <= a.broadcast
+}
+
+/// Verdadero si `ip` cae en el bloque de la subred (red y broadcast incluidos).
+pub fn contains_ip(row: &SubnetRow, ip: Ipv4Addr) -> bool {
+    row.direccion_red <= ip && ip <= row.broadcast
+}
+
 pub trait BaseCalculator {
     fn base_ip(&self) -> Ipv4Addr;
     fn base_cidr(&self) -> u8;
@@ -57,6 +74,11 @@ pub trait BaseCalculator {
         cidr_to_mask(self.base_cidr())
     }
     
+    /// Máscara wildcard de la red base en notación decimal punteada.
+    fn wildcard_mask(&self) -> String {
+        cidr_to_wildcard(self.base_cidr()).to_string()
+    }
+
     fn network_class(&self) -> &'static str {
         network_class(self.base_ip())
     }
@@ -65,6 +87,15 @@ pub trait BaseCalculator {
         self.subnets().iter().map(|s| s.hosts_per_net).sum()
     }
 
+    /// Superredes que resumen las subredes calculadas (ver `aggregate`).
+    fn summarize(&self) -> Vec<(Ipv4Addr, u8)> {
+        let blocks: Vec<(Ipv4Addr, u8)> = self.subnets()
+            .iter()
+            .map(|s| (s.direccion_red, s.cidr()))
+            .collect();
+        aggregate(&blocks)
+    }
+
     fn utilization_percentage(&self) -> f64 {
         let total_capacity: u32 = self.subnets().iter().map(|s| s.hosts_per_net).sum();
         if total_capacity == 0 {
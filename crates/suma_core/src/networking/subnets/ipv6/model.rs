@@ -0,0 +1,195 @@
+// ipv6_calculator.rs
+use std::{error::Error, net::Ipv6Addr};
+use csv::Writer;
+use serde::{Deserialize, Serialize};
+use crate::networking::utils::expand_ipv6;
+
+/// Tope de filas que se listan: partir un /32 en /64 daría 2^32 subredes.
+/// El total real siempre está en `total_subnets()`.
+pub const MAX_LISTED_SUBNETS: usize = 65_536;
+
+/// Fila de subred IPv6. No hay broadcast: todo el rango es utilizable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IPv6SubnetRow {
+    pub subred: u32,
+    pub direccion_red: Ipv6Addr,
+    pub prefijo: u8,
+    pub primera_ip: Ipv6Addr,
+    pub ultima_ip: Ipv6Addr,
+}
+
+impl IPv6SubnetRow {
+    pub fn new(subred: u32, network: Ipv6Addr, prefix: u8) -> Self {
+        let network_u128 = u128::from(network) & prefix_to_mask(prefix);
+        let last = network_u128 | !prefix_to_mask(prefix);
+
+        Self {
+            subred,
+            direccion_red: Ipv6Addr::from(network_u128),
+            prefijo: prefix,
+            primera_ip: Ipv6Addr::from(network_u128),
+            ultima_ip: Ipv6Addr::from(last),
+        }
+    }
+
+    /// Cantidad de direcciones de la subred (2^(128 - prefijo)), saturada en `u128::MAX` para /0.
+    pub fn address_count(&self) -> u128 {
+        1u128.checked_shl(128 - self.prefijo as u32).unwrap_or(u128::MAX)
+    }
+
+    pub fn to_csv(subnets: &[Self]) -> Result<String, Box<dyn Error>> {
+        let mut wtr = Writer::from_writer(vec![]);
+        for s in subnets {
+            wtr.serialize(s)?;
+        }
+        wtr.flush()?;
+        let data = String::from_utf8(wtr.into_inner()?)?;
+        Ok(data)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IPv6SubnetCalculator {
+    base_ip: Ipv6Addr,
+    network_address: Ipv6Addr,
+    pub base_prefix: u8,
+    pub new_prefix: u8,
+    subnets: Vec<IPv6SubnetRow>,
+}
+
+impl IPv6SubnetCalculator {
+    /// Divide `ip` (p. ej. `2001:db8::/32`) en subredes de longitud `new_prefix`.
+    pub fn new(ip: &str, new_prefix: u8) -> Result<Self, String> {
+        let (base_ip, base_prefix) = parse_ipv6_prefix(ip)?;
+        if new_prefix > 128 {
+            return Err("El prefijo debe estar entre 0 y 128".to_string());
+        }
+        if new_prefix < base_prefix {
+            return Err(format!(
+                "El nuevo prefijo /{} es más corto que el de la red base /{}",
+                new_prefix, base_prefix
+            ));
+        }
+
+        let network_address = Ipv6Addr::from(u128::from(base_ip) & prefix_to_mask(base_prefix));
+        let mut calculator = Self {
+            base_ip,
+            network_address,
+            base_prefix,
+            new_prefix,
+            subnets: Vec::new(),
+        };
+
+        calculator.generate_subnets();
+
+        Ok(calculator)
+    }
+
+    pub fn base_ip(&self) -> Ipv6Addr {
+        self.base_ip
+    }
+
+    pub fn network_address(&self) -> Ipv6Addr {
+        self.network_address
+    }
+
+    pub fn subnets(&self) -> &[IPv6SubnetRow] {
+        &self.subnets
+    }
+
+    /// Subredes que caben en la red base: 2^(nuevo - base), saturado en `u128::MAX`.
+    pub fn total_subnets(&self) -> u128 {
+        1u128
+            .checked_shl((self.new_prefix - self.base_prefix) as u32)
+            .unwrap_or(u128::MAX)
+    }
+
+    /// Salto entre subredes consecutivas (su tamaño). `None` para /0, que no cabe en u128.
+    pub fn subnet_jump(&self) -> Option<u128> {
+        1u128.checked_shl(128 - self.new_prefix as u32)
+    }
+
+    fn generate_subnets(&mut self) {
+        self.subnets.clear();
+        let count = self.total_subnets().min(MAX_LISTED_SUBNETS as u128) as usize;
+        let jump = self.subnet_jump().unwrap_or(0);
+        let mut current = u128::from(self.network_address);
+
+        for i in 0..count {
+            self.subnets.push(IPv6SubnetRow::new(
+                (i + 1) as u32,
+                Ipv6Addr::from(current),
+                self.new_prefix,
+            ));
+            current = current.wrapping_add(jump);
+        }
+    }
+}
+
+fn prefix_to_mask(prefix: u8) -> u128 {
+    match prefix.min(128) {
+        0 => 0,
+        p => u128::MAX << (128 - p as u32),
+    }
+}
+
+// Helper para parsear IPv6/prefijo; sin prefijo se asume /64
+fn parse_ipv6_prefix(ip_str: &str) -> Result<(Ipv6Addr, u8), String> {
+    let (ip_part, prefix) = match ip_str.split_once('/') {
+        Some((ip_part, prefix_part)) => {
+            let prefix: u8 = prefix_part.parse().map_err(|e| format!("Prefijo inválido: {}", e))?;
+            if prefix > 128 {
+                return Err("El prefijo debe estar entre 0 y 128".to_string());
+            }
+            (ip_part, prefix)
+        }
+        None => (ip_str, 64),
+    };
+
+    let expanded = expand_ipv6(ip_part.trim()).map_err(|e| e.to_string())?;
+    let ip = expanded.parse().map_err(|e| format!("IP inválida: {}", e))?;
+    Ok((ip, prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ipv6_subnets_of_documentation_prefix() {
+        let calc = IPv6SubnetCalculator::new("2001:db8:abcd::1/48", 50).unwrap();
+
+        assert_eq!(calc.network_address(), "2001:db8:abcd::".parse::<Ipv6Addr>().unwrap());
+        assert_eq!(calc.total_subnets(), 4);
+        assert_eq!(calc.subnets().len(), 4);
+
+        let second = &calc.subnets()[1];
+        assert_eq!(second.subred, 2);
+        assert_eq!(second.prefijo, 50);
+        assert_eq!(second.direccion_red, "2001:db8:abcd:4000::".parse::<Ipv6Addr>().unwrap());
+        assert_eq!(second.primera_ip, second.direccion_red);
+        assert_eq!(
+            second.ultima_ip,
+            "2001:db8:abcd:7fff:ffff:ffff:ffff:ffff".parse::<Ipv6Addr>().unwrap()
+        );
+        assert_eq!(second.address_count(), 1u128 << 78);
+    }
+
+    #[test]
+    fn test_ipv6_large_split_is_capped() {
+        let calc = IPv6SubnetCalculator::new("2001:db8::/32", 64).unwrap();
+        assert_eq!(calc.total_subnets(), 1u128 << 32);
+        assert_eq!(calc.subnets().len(), MAX_LISTED_SUBNETS);
+        assert_eq!(
+            calc.subnets()[1].direccion_red,
+            "2001:db8:0:1::".parse::<Ipv6Addr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ipv6_invalid_input() {
+        assert!(IPv6SubnetCalculator::new("2001:db8::/48", 40).is_err());
+        assert!(IPv6SubnetCalculator::new("2001:db8::/129", 130).is_err());
+        assert!(IPv6SubnetCalculator::new("2001:zz8::/32", 48).is_err());
+    }
+}
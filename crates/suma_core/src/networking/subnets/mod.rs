@@ -1,7 +1,9 @@
 pub mod base;
 pub mod vlsm;
 pub mod flsm;
+pub mod ipv6;
 
 pub use base::{BaseCalculator, SubnetRow};
 pub use vlsm::{VLSMCalculator};
-pub use flsm::FLSMCalculator;
\ No newline at end of file
+pub use flsm::FLSMCalculator;
+pub use ipv6::{IPv6SubnetCalculator, IPv6SubnetRow};
\ No newline at end of file
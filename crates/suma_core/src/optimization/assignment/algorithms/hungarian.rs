@@ -0,0 +1,162 @@
+use crate::optimization::assignment::error::AssignmentError;
+use crate::optimization::assignment::problem::AssignmentProblem;
+
+impl AssignmentProblem {
+    /// Resuelve con el método húngaro (Kuhn–Munkres) en O(n³).
+    ///
+    /// Devuelve la columna asignada a cada fila y el valor total. Una matriz no
+    /// cuadrada se completa con filas o columnas ficticias de costo cero: si hay
+    /// más filas que columnas, las filas sin tarea reciben un índice `>= cols`.
+    pub fn solve(&self) -> Result<(Vec<usize>, f64), AssignmentError> {
+        let (rows, cols) = (self.cost.rows, self.cost.cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                if !self.cost.get(i, j).is_finite() {
+                    return Err(AssignmentError::NonFiniteCost { row: i, col: j });
+                }
+            }
+        }
+
+        // Matriz cuadrada de minimización; maximizar = minimizar el negativo
+        let n = rows.max(cols);
+        let sign = if self.maximize { -1.0 } else { 1.0 };
+        let padded: Vec<Vec<f64>> = (0..n)
+            .map(|i| (0..n)
+                .map(|j| if i < rows && j < cols { sign * self.cost.get(i, j) } else { 0.0 })
+                .collect())
+            .collect();
+
+        let assignment = hungarian(&padded);
+        let total = assignment.iter().enumerate()
+            .take(rows)
+            .filter(|&(_, &j)| j < cols)
+            .map(|(i, &j)| self.cost.get(i, j))
+            .sum();
+
+        Ok((assignment.into_iter().take(rows).collect(), total))
+    }
+}
+
+/// Versión con potenciales (u, v): agrega las filas una a una y, para cada una,
+/// busca un camino aumentante de costo reducido mínimo tipo Dijkstra.
+/// Devuelve la columna de cada fila.
+fn hungarian(cost: &[Vec<f64>]) -> Vec<usize> {
+    let n = cost.len();
+    // Índices 1..=n; la columna 0 es un nodo auxiliar
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; n + 1];
+    let mut row_of = vec![0usize; n + 1];
+    let mut way = vec![0usize; n + 1];
+
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut col = 0;
+        let mut min_to = vec![f64::INFINITY; n + 1];
+        let mut used = vec![false; n + 1];
+
+        loop {
+            used[col] = true;
+            let row = row_of[col];
+            let mut delta = f64::INFINITY;
+            let mut next = 0;
+            for j in 1..=n {
+                if used[j] {
+                    continue;
+                }
+                let reduced = cost[row - 1][j - 1] - u[row] - v[j];
+                if reduced < min_to[j] {
+                    min_to[j] = reduced;
+                    way[j] = col;
+                }
+                if min_to[j] < delta {
+                    delta = min_to[j];
+                    next = j;
+                }
+            }
+            for j in 0..=n {
+                if used[j] {
+                    u[row_of[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_to[j] -= delta;
+                }
+            }
+            col = next;
+            if row_of[col] == 0 {
+                break;
+            }
+        }
+
+        // Invertir el camino aumentante
+        while col != 0 {
+            let prev = way[col];
+            row_of[col] = row_of[prev];
+            col = prev;
+        }
+    }
+
+    let mut assignment = vec![0; n];
+    for j in 1..=n {
+        assignment[row_of[j] - 1] = j - 1;
+    }
+    assignment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_square_minimization() {
+        let problem = AssignmentProblem::new(matrix![
+            4.0, 1.0, 3.0;
+            2.0, 0.0, 5.0;
+            3.0, 2.0, 2.0
+        ]);
+        let (assignment, total) = problem.solve().unwrap();
+        assert_eq!(assignment, vec![1, 0, 2]);
+        assert!((total - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_maximization() {
+        let problem = AssignmentProblem::maximize(matrix![
+            4.0, 1.0, 3.0;
+            2.0, 0.0, 5.0;
+            3.0, 2.0, 2.0
+        ]);
+        let (assignment, total) = problem.solve().unwrap();
+        assert_eq!(assignment, vec![0, 2, 1]);
+        assert!((total - 11.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rectangular_is_padded() {
+        // Más columnas que filas: sobra una tarea
+        let wide = AssignmentProblem::new(matrix![
+            1.0, 2.0, 3.0;
+            3.0, 1.0, 2.0
+        ]);
+        let (assignment, total) = wide.solve().unwrap();
+        assert_eq!(assignment, vec![0, 1]);
+        assert!((total - 2.0).abs() < 1e-9);
+
+        // Más filas que columnas: una fila queda en la columna ficticia
+        let tall = AssignmentProblem::new(matrix![
+            1.0, 5.0;
+            5.0, 1.0;
+            2.0, 2.0
+        ]);
+        let (assignment, total) = tall.solve().unwrap();
+        assert_eq!(&assignment[..2], &[0, 1]);
+        assert!(assignment[2] >= 2);
+        assert!((total - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_non_finite_cost() {
+        let problem = AssignmentProblem::new(matrix![1.0, f64::NAN]);
+        assert_eq!(problem.solve(), Err(AssignmentError::NonFiniteCost { row: 0, col: 1 }));
+    }
+}
@@ -0,0 +1 @@
+pub mod hungarian;
\ No newline at end of file
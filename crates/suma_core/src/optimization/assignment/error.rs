@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum AssignmentError {
+    #[error("Costo no finito en la fila {row}, columna {col}.")]
+    NonFiniteCost { row: usize, col: usize },
+}
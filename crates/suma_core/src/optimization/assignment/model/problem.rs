@@ -0,0 +1,22 @@
+use crate::linear_algebra::DenseMatrix;
+
+/// Problema de asignación 1 a 1: cada fila (agente) recibe a lo sumo una columna
+/// (tarea) y viceversa. `cost.get(i, j)` es el costo (o beneficio, si `maximize`)
+/// de asignar la tarea `j` al agente `i`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssignmentProblem {
+    pub cost: DenseMatrix<f64>,
+    pub maximize: bool,
+}
+
+impl AssignmentProblem {
+    /// Problema de minimización de costo.
+    pub fn new(cost: DenseMatrix<f64>) -> Self {
+        Self { cost, maximize: false }
+    }
+
+    /// Problema de maximización (beneficio total).
+    pub fn maximize(cost: DenseMatrix<f64>) -> Self {
+        Self { cost, maximize: true }
+    }
+}
@@ -1,5 +1,7 @@
 use thiserror::Error;
 use super::linear::error::LinearOptimizationError;
+use super::transportation::error::TransportationError;
+use super::assignment::error::AssignmentError;
 
 #[derive(Debug, Error)]
 pub enum OptimizationError {
@@ -7,6 +9,14 @@ pub enum OptimizationError {
     #[error(transparent)]
     Linear(#[from] LinearOptimizationError),
 
+    /// Errores del modelo y solver de transporte
+    #[error(transparent)]
+    Transportation(#[from] TransportationError),
+
+    /// Errores del problema de asignación
+    #[error(transparent)]
+    Assignment(#[from] AssignmentError),
+
     // Futuro: Errores de Programación Entera
     // #[error(transparent)]
     // Integer(#[from] IntegerOptimizationError),
@@ -15,6 +25,11 @@ pub enum OptimizationError {
     // #[error(transparent)]
     // NonLinear(#[from] NonLinearError),
     
+    /// Branch & Bound agotó su presupuesto de nodos sin encontrar una solución entera.
+    /// No implica que el problema sea infactible.
+    #[error("Branch & Bound alcanzó el límite de {nodes} nodos sin encontrar una solución entera")]
+    NodeLimitReached { nodes: usize },
+
     // Errores genéricos del módulo de optimización (si fueran necesarios)
     #[error("Configuración de optimizador inválida: {0}")]
     ConfigError(String),
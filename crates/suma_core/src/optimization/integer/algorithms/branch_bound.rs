@@ -1,34 +1,165 @@
-use std::collections::VecDeque;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
 use crate::optimization::linear::algorithms::simplex::solve_primal;
-use crate::optimization::linear::model::{Constraint, LinearExpression, Relation, OptimizationDirection};
+use crate::optimization::linear::model::{LinearProblem, OptimizationDirection};
 use crate::optimization::linear::error::{OptimizationStatus, Solution};
 use crate::optimization::error::OptimizationError; // Usamos el error genérico
 use crate::optimization::integer::problem::IntegerProblem;
 
 const EPSILON: f64 = 1e-6;
 
-/// Resuelve un problema de Programación Entera Mixta (MILP) usando Branch & Bound.
+/// Orden en que Branch & Bound expande los nodos pendientes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BranchStrategy {
+    /// Búsqueda en profundidad (pila): llega rápido a una primera solución entera.
+    #[default]
+    DepthFirst,
+    /// Mejor cota primero (`BinaryHeap`): expande el nodo cuya relajación promete
+    /// el mejor objetivo, lo que suele ajustar antes el incumbente y explorar menos nodos.
+    BestBound,
+}
+
+/// Nodo pendiente: el subproblema y la cota de la relajación de su padre.
+struct PendingNode {
+    /// Cota normalizada para que "mayor" sea siempre "más prometedor".
+    priority: f64,
+    /// Orden de creación: a igual cota se expande el más reciente (como en DFS).
+    sequence: usize,
+    problem: LinearProblem,
+}
+
+impl PartialEq for PendingNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PendingNode {}
+
+impl PartialOrd for PendingNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PendingNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.total_cmp(&other.priority)
+            .then(self.sequence.cmp(&other.sequence))
+    }
+}
+
+/// Nodos por explorar, según la estrategia elegida.
+enum Frontier {
+    Stack(Vec<PendingNode>),
+    Heap(BinaryHeap<PendingNode>),
+}
+
+impl Frontier {
+    fn new(strategy: BranchStrategy) -> Self {
+        match strategy {
+            BranchStrategy::DepthFirst => Frontier::Stack(Vec::new()),
+            BranchStrategy::BestBound => Frontier::Heap(BinaryHeap::new()),
+        }
+    }
+
+    fn push(&mut self, node: PendingNode) {
+        match self {
+            Frontier::Stack(stack) => stack.push(node),
+            Frontier::Heap(heap) => heap.push(node),
+        }
+    }
+
+    fn pop(&mut self) -> Option<PendingNode> {
+        match self {
+            Frontier::Stack(stack) => stack.pop(),
+            Frontier::Heap(heap) => heap.pop(),
+        }
+    }
+}
+
+/// Resuelve un problema de Programación Entera Mixta (MILP) usando Branch & Bound
+/// en profundidad. Ver [`solve_integer_with_strategy`].
 pub fn solve_integer(problem: &IntegerProblem) -> Result<Solution, OptimizationError> {
+    solve_integer_with_strategy(problem, BranchStrategy::default())
+}
+
+/// Branch & Bound con la estrategia de selección de nodos indicada.
+/// En la solución devuelta, `iterations` es el número de nodos explorados.
+pub fn solve_integer_with_strategy(
+    problem: &IntegerProblem,
+    strategy: BranchStrategy,
+) -> Result<Solution, OptimizationError> {
+    solve_integer_with_stats(problem, strategy).map(|(solution, _)| solution)
+}
+
+/// Estadísticas de una ejecución de Branch & Bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SolveStats {
+    /// Relajaciones lineales resueltas (nodos del árbol).
+    pub nodes_explored: usize,
+    /// Veces que se encontró una solución entera mejor que la anterior.
+    pub incumbent_updates: usize,
+    /// La búsqueda se cortó por `MAX_NODES`: la solución puede no ser óptima.
+    pub hit_node_limit: bool,
+}
+
+/// Tope de nodos por ejecución; evita búsquedas interminables en problemas grandes.
+pub const MAX_NODES: usize = 1000;
+
+/// Como [`solve_integer_with_strategy`], pero devuelve además las estadísticas de la
+/// búsqueda. Si se alcanza el límite de nodos sin ninguna solución entera, el error es
+/// `OptimizationError::NodeLimitReached` (no `Infeasible`, que sí es concluyente).
+pub fn solve_integer_with_stats(
+    problem: &IntegerProblem,
+    strategy: BranchStrategy,
+) -> Result<(Solution, SolveStats), OptimizationError> {
+    branch_and_bound(problem, strategy, MAX_NODES)
+}
+
+fn branch_and_bound(
+    problem: &IntegerProblem,
+    strategy: BranchStrategy,
+    max_nodes: usize,
+) -> Result<(Solution, SolveStats), OptimizationError> {
     let direction = problem.linear_problem.objective.direction;
     let is_minimization = direction == OptimizationDirection::Minimize;
+    // Con la cota normalizada, "mayor es mejor" en ambos sentidos
+    let normalize = |value: f64| if is_minimization { -value } else { value };
 
     // Mejor solución entera encontrada hasta el momento (Incumbent)
     let mut best_solution: Option<Solution> = None;
     let mut best_obj_value = if is_minimization { f64::INFINITY } else { f64::NEG_INFINITY };
 
+    // Las cotas de las variables enteras se redondean hacia adentro: si el rango
+    // queda vacío (ej. 0.2 <= x <= 0.8) el problema no tiene solución entera.
+    let mut root = problem.linear_problem.clone();
+    for var_name in &problem.integer_variables {
+        let bounds = root.bounds_of(var_name);
+        if !tighten_bounds(&mut root, var_name, bounds.lower.ceil(), bounds.upper.floor()) {
+            return Err(crate::optimization::linear::error::LinearOptimizationError::Infeasible.into());
+        }
+    }
+
     // Cola de problemas por explorar (Nodes)
-    // Usamos VecDeque como Stack para DFS (Depth First Search) para encontrar soluciones rápido
-    let mut queue = VecDeque::new();
-    queue.push_back(problem.linear_problem.clone());
+    let mut queue = Frontier::new(strategy);
+    let mut sequence = 0;
+    queue.push(PendingNode { priority: f64::INFINITY, sequence, problem: root });
+
+    let mut stats = SolveStats::default();
 
-    let mut iterations = 0;
-    let max_nodes = 1000; // Seguridad para evitar loops infinitos en problemas grandes
+    while let Some(node) = queue.pop() {
+        // La cota del padre ya no mejora al incumbente: ni siquiera resolvemos el nodo
+        if best_solution.is_some() && node.priority <= normalize(best_obj_value) {
+            continue;
+        }
 
-    while let Some(current_prob) = queue.pop_back() {
-        iterations += 1;
-        if iterations > max_nodes {
-            break; // O retornar error de límite
+        if stats.nodes_explored == max_nodes {
+            stats.hit_node_limit = true;
+            break;
         }
+        stats.nodes_explored += 1;
+        let current_prob = node.problem;
 
         // 1. Resolver Relajación Lineal (Simplex)
         let result = solve_primal(&current_prob);
@@ -53,23 +184,22 @@ pub fn solve_integer(problem: &IntegerProblem) -> Result<Solution, OptimizationE
                     let floor_val = val.floor();
                     let ceil_val = val.ceil();
 
+                    // Las ramas ajustan las cotas de la variable en lugar de agregar
+                    // filas; una rama con cotas vacías se poda sin resolverla.
+
                     // Rama 1: var <= floor (ej. x <= 3)
                     let mut left_prob = current_prob.clone();
-                    left_prob.add_constraint(Constraint::new(
-                        var_to_expr(&var_name),
-                        Relation::LessOrEqual,
-                        floor_val
-                    ));
-                    queue.push_back(left_prob);
+                    if tighten_bounds(&mut left_prob, &var_name, f64::NEG_INFINITY, floor_val) {
+                        sequence += 1;
+                        queue.push(PendingNode { priority: normalize(sol.objective_value), sequence, problem: left_prob });
+                    }
 
                     // Rama 2: var >= ceil (ej. x >= 4)
                     let mut right_prob = current_prob.clone();
-                    right_prob.add_constraint(Constraint::new(
-                        var_to_expr(&var_name),
-                        Relation::GreaterOrEqual,
-                        ceil_val
-                    ));
-                    queue.push_back(right_prob);
+                    if tighten_bounds(&mut right_prob, &var_name, ceil_val, f64::INFINITY) {
+                        sequence += 1;
+                        queue.push(PendingNode { priority: normalize(sol.objective_value), sequence, problem: right_prob });
+                    }
 
                 } else {
                     // --- SOLUCIÓN ENTERA ENCONTRADA ---
@@ -77,6 +207,7 @@ pub fn solve_integer(problem: &IntegerProblem) -> Result<Solution, OptimizationE
                     // Como pasamos el filtro de Bound, esta solución es MEJOR que la anterior.
                     best_obj_value = sol.objective_value;
                     best_solution = Some(sol);
+                    stats.incumbent_updates += 1;
                 }
             },
             Err(_) => {
@@ -87,7 +218,11 @@ pub fn solve_integer(problem: &IntegerProblem) -> Result<Solution, OptimizationE
     }
 
     match best_solution {
-        Some(sol) => Ok(sol),
+        Some(mut sol) => {
+            sol.iterations = stats.nodes_explored;
+            Ok((sol, stats))
+        }
+        None if stats.hit_node_limit => Err(OptimizationError::NodeLimitReached { nodes: max_nodes }),
         None => Err(crate::optimization::linear::error::LinearOptimizationError::Infeasible.into()),
     }
 }
@@ -107,11 +242,17 @@ fn find_fractional_var(sol: &Solution, int_vars: &std::collections::HashSet<Stri
     None
 }
 
-/// Helper para crear una expresión lineal simple "1 * x"
-fn var_to_expr(name: &str) -> LinearExpression {
-    let mut expr = LinearExpression::new();
-    expr.add_term(name, 1.0);
-    expr
+/// Intersecta las cotas actuales de `var` con `[lower, upper]`.
+/// Devuelve `false` si el rango resultante es vacío (la rama se puede podar).
+fn tighten_bounds(problem: &mut LinearProblem, var: &str, lower: f64, upper: f64) -> bool {
+    let current = problem.bounds_of(var);
+    let lower = current.lower.max(lower);
+    let upper = current.upper.min(upper);
+    if lower > upper + EPSILON {
+        return false;
+    }
+    problem.set_bounds(var, lower, upper);
+    true
 }
 
 #[cfg(test)]
@@ -240,4 +381,116 @@ mod tests {
         // Verificamos que la solución cumple la restricción
         assert!(2.0*x + 2.0*y <= 9.0 + 1e-6);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_binary_knapsack() {
+        // Mochila 0/1: Max 10a + 13b + 7c + 8d  s.a.  4a + 6b + 3c + 5d <= 10
+        // La relajación toma a, c y media b; el óptimo entero es {a, b} con Z = 23.
+        let objective = Objective::maximize(expr(&[("a", 10.0), ("b", 13.0), ("c", 7.0), ("d", 8.0)], 0.0));
+        let mut linear = LinearProblem::new("Knapsack", objective);
+        linear.add_constraint(Constraint::new(
+            expr(&[("a", 4.0), ("b", 6.0), ("c", 3.0), ("d", 5.0)], 0.0),
+            Relation::LessOrEqual,
+            10.0
+        ));
+
+        let mut problem = IntegerProblem::new(linear);
+        for var in ["a", "b", "c", "d"] {
+            problem.mark_as_binary(var);
+        }
+
+        let solution = solve_integer(&problem).unwrap();
+        assert!((solution.objective_value - 23.0).abs() < 1e-6, "Z = {}", solution.objective_value);
+        for (var, expected) in [("a", 1.0), ("b", 1.0), ("c", 0.0), ("d", 0.0)] {
+            assert!((solution.variables[var] - expected).abs() < 1e-6, "{} = {}", var, solution.variables[var]);
+        }
+        // Las ramas no agregan filas: solo se tocan las cotas
+        assert_eq!(problem.linear_problem.constraints.len(), 1);
+    }
+
+    #[test]
+    fn test_integer_bounds_are_respected() {
+        // Max x  s.a.  x <= 10, con 0 <= x <= 7.5 y x entero -> x = 7
+        let objective = Objective::maximize(expr(&[("x", 1.0)], 0.0));
+        let mut linear = LinearProblem::new("Bounded", objective);
+        linear.add_constraint(Constraint::new(expr(&[("x", 1.0)], 0.0), Relation::LessOrEqual, 10.0));
+
+        let mut problem = IntegerProblem::new(linear);
+        problem.mark_as_integer("x");
+        problem.set_bounds("x", 0.0, 7.5);
+
+        let solution = solve_integer(&problem).unwrap();
+        assert!((solution.variables["x"] - 7.0).abs() < 1e-6);
+
+        // Un rango sin enteros se poda antes de resolver cualquier relajación
+        problem.set_bounds("x", 0.2, 0.8);
+        assert!(matches!(
+            solve_integer(&problem),
+            Err(OptimizationError::Linear(crate::optimization::linear::error::LinearOptimizationError::Infeasible))
+        ));
+    }
+
+    #[test]
+    fn test_relaxation_with_degenerate_artificial() {
+        // Max 2x1  s.a.  -x1 >= 0,  0 <= x1 <= 6  ->  x1 = 0 (la restricción fuerza x1 <= 0)
+        let objective = Objective::maximize(expr(&[("x1", 2.0)], 0.0));
+        let mut linear = LinearProblem::new("Degenerate", objective);
+        linear.add_constraint(Constraint::new(expr(&[("x1", -1.0)], 0.0), Relation::GreaterOrEqual, 0.0));
+
+        let mut problem = IntegerProblem::new(linear);
+        problem.mark_as_integer("x1");
+        problem.set_bounds("x1", 0.0, 6.0);
+
+        let solution = solve_integer(&problem).unwrap();
+        assert!(solution.variables["x1"].abs() < 1e-6, "x1 = {}", solution.variables["x1"]);
+        assert!(solution.objective_value.abs() < 1e-6);
+    }
+
+    fn knapsack(values: &[f64], weights: &[f64], capacity: f64) -> IntegerProblem {
+        let names: Vec<String> = (0..values.len()).map(|i| format!("x{}", i)).collect();
+        let terms = |coeffs: &[f64]| -> Vec<(&str, f64)> {
+            names.iter().map(String::as_str).zip(coeffs.iter().copied()).collect()
+        };
+        let objective = Objective::maximize(expr(&terms(values), 0.0));
+        let mut linear = LinearProblem::new("Knapsack", objective);
+        linear.add_constraint(Constraint::new(expr(&terms(weights), 0.0), Relation::LessOrEqual, capacity));
+
+        let mut problem = IntegerProblem::new(linear);
+        for name in &names {
+            problem.mark_as_binary(name);
+        }
+        problem
+    }
+
+    #[test]
+    fn test_best_bound_explores_fewer_nodes() {
+        let problem = knapsack(&[19.0, 12.0, 14.0, 14.0, 6.0, 20.0], &[5.0, 5.0, 20.0, 7.0, 16.0, 16.0], 34.0);
+
+        let dfs = solve_integer(&problem).unwrap();
+        let best = solve_integer_with_strategy(&problem, BranchStrategy::BestBound).unwrap();
+
+        assert!((dfs.objective_value - best.objective_value).abs() < 1e-6);
+        assert!(best.iterations < dfs.iterations, "BestBound: {} nodos, DFS: {} nodos", best.iterations, dfs.iterations);
+    }
+
+    #[test]
+    fn test_solve_stats() {
+        let problem = knapsack(&[19.0, 12.0, 14.0, 14.0, 6.0, 20.0], &[5.0, 5.0, 20.0, 7.0, 16.0, 16.0], 34.0);
+
+        let (solution, stats) = solve_integer_with_stats(&problem, BranchStrategy::DepthFirst).unwrap();
+        assert_eq!(stats.nodes_explored, solution.iterations);
+        assert!(stats.incumbent_updates >= 1);
+        assert!(!stats.hit_node_limit);
+    }
+
+    #[test]
+    fn test_node_limit_is_not_reported_as_infeasible() {
+        // Con un solo nodo solo se resuelve la relajación (fraccional): sin incumbente
+        let problem = knapsack(&[19.0, 12.0, 14.0, 14.0, 6.0, 20.0], &[5.0, 5.0, 20.0, 7.0, 16.0, 16.0], 34.0);
+
+        match branch_and_bound(&problem, BranchStrategy::DepthFirst, 1) {
+            Err(OptimizationError::NodeLimitReached { nodes }) => assert_eq!(nodes, 1),
+            other => panic!("Se esperaba NodeLimitReached, resultado: {:?}", other),
+        }
+    }
+}
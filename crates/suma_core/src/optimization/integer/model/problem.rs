@@ -29,4 +29,16 @@ impl IntegerProblem {
             self.integer_variables.insert(v.to_string());
         }
     }
+
+    /// Marca una variable como binaria: entera con `0 <= x <= 1`.
+    pub fn mark_as_binary(&mut self, var_name: &str) {
+        self.mark_as_integer(var_name);
+        self.linear_problem.set_bounds(var_name, 0.0, 1.0);
+    }
+
+    /// Fija las cotas `lower <= x <= upper` de una variable. Se guardan en el
+    /// problema lineal, así que el simplex las maneja sin agregar filas.
+    pub fn set_bounds(&mut self, var_name: &str, lower: f64, upper: f64) {
+        self.linear_problem.set_bounds(var_name, lower, upper);
+    }
 }
\ No newline at end of file
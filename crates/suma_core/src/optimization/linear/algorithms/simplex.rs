@@ -2,12 +2,61 @@ use std::collections::HashMap;
 use crate::optimization::linear::model::{LinearProblem, OptimizationDirection};
 use crate::optimization::linear::internal::tableau::SimplexTableau;
 use crate::optimization::linear::transformers::standard_form::{to_standard_form, StandardFormResult};
-use crate::optimization::linear::error::{OptimizationResult, LinearOptimizationError, Solution, OptimizationStatus}; // Nuevo Error
+use crate::optimization::linear::error::{OptimizationResult, LinearOptimizationError, Solution, OptimizationStatus, SimplexDiagnostic}; // Nuevo Error
 
 const MAX_ITERATIONS: usize = 10000;
 const EPSILON: f64 = 1e-9;
 
+/// Opciones de ejecución del simplex primal.
+#[derive(Debug, Clone, Default)]
+pub struct SimplexOptions {
+    /// Guarda una copia del tableau inicial y de cada tableau tras pivotear en `Solution::trace`.
+    pub record_trace: bool,
+    /// Activa la regla de Bland (menor índice para entrante y saliente) tras
+    /// `degenerate_pivot_limit` pivoteos degenerados seguidos. Garantiza que el
+    /// simplex termina aunque el problema cicle con la regla de Dantzig.
+    pub anti_cycling: bool,
+    /// Pivoteos degenerados consecutivos tolerados antes de pasar a Bland (0 = al primero).
+    pub degenerate_pivot_limit: usize,
+}
+
+impl SimplexOptions {
+    /// Opciones con el historial de tableaux activado (modo didáctico).
+    pub fn verbose() -> Self {
+        Self { record_trace: true, ..Self::default() }
+    }
+
+    /// Opciones con la regla anti-ciclado activada desde el primer pivoteo degenerado.
+    pub fn anti_cycling() -> Self {
+        Self { anti_cycling: true, ..Self::default() }
+    }
+}
+
+/// Registro de la ejecución compartido entre Fase 1 y Fase 2.
+struct SimplexLog {
+    iterations: usize,
+    record_trace: bool,
+    trace: Vec<SimplexTableau>,
+    /// `Some(límite)` si la regla de Bland está permitida (ver `SimplexOptions::anti_cycling`).
+    bland_after: Option<usize>,
+    /// Pivoteos degenerados: (iteración, columna entrante, columna saliente).
+    degenerate_pivots: Vec<(usize, usize, usize)>,
+}
+
+impl SimplexLog {
+    fn record(&mut self, tableau: &SimplexTableau) {
+        if self.record_trace {
+            self.trace.push(tableau.clone());
+        }
+    }
+}
+
 pub fn solve_primal(problem: &LinearProblem) -> OptimizationResult {
+    solve_primal_with_options(problem, &SimplexOptions::default())
+}
+
+/// Igual que `solve_primal`, pero permite configurar la ejecución (ver `SimplexOptions`).
+pub fn solve_primal_with_options(problem: &LinearProblem, options: &SimplexOptions) -> OptimizationResult {
     // 1. Convertir modelo
     let StandardFormResult { 
         mut tableau, 
@@ -15,31 +64,59 @@ pub fn solve_primal(problem: &LinearProblem) -> OptimizationResult {
         artificial_indices, 
         original_objective_row, 
         constraint_col_map,
+        var_offsets,
+        var_signs,
+        free_splits,
+        objective_offset,
+        row_signs,
+        row_identity_cols,
         .. 
-    } = to_standard_form(problem)
-        .map_err(|e| LinearOptimizationError::ValidationError(format!("{:?}", e)))?;
+    } = to_standard_form(problem)?;
 
     // Detectamos la dirección ORIGINAL
     let is_minimization = problem.objective.direction == OptimizationDirection::Minimize;
 
     let has_artificial_vars = !artificial_indices.is_empty();
 
+    let mut log = SimplexLog {
+        iterations: 0,
+        record_trace: options.record_trace,
+        trace: Vec::new(),
+        bland_after: options.anti_cycling.then_some(options.degenerate_pivot_limit),
+        degenerate_pivots: Vec::new(),
+    };
+    log.record(&tableau);
+
     // 2. FASE 1 (Buscar Factibilidad)
     if has_artificial_vars {
-        run_simplex_phase(&mut tableau, None)?;
+        if let PhaseOutcome::Unbounded(col) = run_simplex_phase(&mut tableau, None, &mut log)? {
+            let ray = unbounded_ray(&tableau, col, &reverse_map, &var_signs, &free_splits);
+            return Err(LinearOptimizationError::Unbounded { ray });
+        }
         let w_val = tableau.matrix.get(tableau.matrix.rows - 1, tableau.matrix.cols - 1);
         if w_val.abs() > 1e-5 { // Usar abs() por seguridad
             return Err(LinearOptimizationError::Infeasible);
         }
+        drive_out_artificials(&mut tableau, &artificial_indices);
         prepare_phase_2(&mut tableau, &original_objective_row, &artificial_indices);
+
+        // El cambio de fila Z no es un pivoteo: reemplaza la última foto de la Fase 1
+        if let Some(last) = log.trace.last_mut() {
+            *last = tableau.clone();
+        }
     }
 
     // 3. FASE 2 (Optimizar)
     let ignore_list = if has_artificial_vars { Some(&artificial_indices) } else { None };
-    run_simplex_phase(&mut tableau, ignore_list)?;
+    if let PhaseOutcome::Unbounded(col) = run_simplex_phase(&mut tableau, ignore_list, &mut log)? {
+        let ray = unbounded_ray(&tableau, col, &reverse_map, &var_signs, &free_splits);
+        return Err(LinearOptimizationError::Unbounded { ray });
+    }
 
     // 4. Extraer Resultados
-    let mut solution = extract_solution(&tableau, &reverse_map, &constraint_col_map);
+    let mut solution = extract_solution(
+        &tableau, &reverse_map, &constraint_col_map, &var_offsets, &var_signs, &free_splits
+    );
     
     // 5. AJUSTE DE SIGNOS (LA CORRECCIÓN)
     // Si el problema original era MAXIMIZAR, invertimos el signo del resultado final.
@@ -53,15 +130,52 @@ pub fn solve_primal(problem: &LinearProblem) -> OptimizationResult {
             *val = -*val;
         }
     }
+    // Constante del objetivo y aporte de las cotas sustituidas (x = offset + sign * x')
+    solution.objective_value += objective_offset;
+
+    solution.iterations = log.iterations;
+    solution.trace = log.trace;
+
+    let column_name = |col: usize| reverse_map.get(&col).cloned().unwrap_or_else(|| format!("_col_{}", col));
+    solution.diagnostics = log.degenerate_pivots.iter()
+        .map(|&(iteration, entering, leaving)| SimplexDiagnostic::DegeneratePivot {
+            iteration,
+            entering: column_name(entering),
+            leaving: column_name(leaving),
+        })
+        .collect();
+
+    solution.basic_variables = tableau.basic_vars.iter().map(|&col| column_name(col)).collect();
+    solution.non_basic_variables = (0..tableau.matrix.cols - 1)
+        .filter(|col| !tableau.basic_vars.contains(col) && !artificial_indices.contains(col))
+        .map(column_name)
+        .collect();
+
+    // 6. Análisis de sensibilidad sobre el tableau final (sin volver a resolver)
+    solution.rhs_ranges = rhs_ranges(&tableau, problem, &row_signs, &row_identity_cols);
+    solution.cost_ranges = cost_ranges(
+        &tableau, problem, &reverse_map, &artificial_indices, &var_signs, &free_splits, is_minimization
+    );
 
     Ok(solution)
 }
 
+/// Cómo termina una fase del simplex.
+enum PhaseOutcome {
+    Optimal,
+    /// Ninguna fila limita a la columna entrante: se puede mejorar sin fin en esa dirección.
+    Unbounded(usize),
+}
+
 fn run_simplex_phase(
     tableau: &mut SimplexTableau, 
-    ignore_cols: Option<&Vec<usize>>
-) -> Result<(), LinearOptimizationError> {
+    ignore_cols: Option<&Vec<usize>>,
+    log: &mut SimplexLog,
+) -> Result<PhaseOutcome, LinearOptimizationError> {
     let mut iterations = 0;
+    // Pivoteos degenerados seguidos; al superar el límite se usa Bland hasta el final de la fase
+    let mut degenerate_streak = 0;
+    let mut bland = false;
 
     loop {
         if iterations >= MAX_ITERATIONS {
@@ -70,20 +184,69 @@ fn run_simplex_phase(
         iterations += 1;
 
         if is_optimal(tableau, ignore_cols) {
-            return Ok(());
+            return Ok(PhaseOutcome::Optimal);
         }
         
-        let pivot_col = match select_entering_variable(tableau, ignore_cols) {
+        let pivot_col = match select_entering_variable(tableau, ignore_cols, bland) {
             Some(col) => col,
-            None => return Ok(()),
+            None => return Ok(PhaseOutcome::Optimal),
         };
 
-        let pivot_row = match select_leaving_variable(tableau, pivot_col) {
-            Some(row) => row,
-            None => return Err(LinearOptimizationError::Unbounded),
+        let (outcome, step) = match select_leaving_variable(tableau, pivot_col, bland) {
+            Some(choice) => choice,
+            None => return Ok(PhaseOutcome::Unbounded(pivot_col)),
         };
-        
-        tableau.pivot(pivot_row, pivot_col);
+
+        // Paso nulo: cambia la base pero no el punto (riesgo de ciclar)
+        if let RatioOutcome::Pivot(row) | RatioOutcome::PivotToUpper(row) = outcome {
+            if step.abs() < EPSILON {
+                log.degenerate_pivots.push((log.iterations + 1, pivot_col, tableau.basic_vars[row]));
+            }
+        }
+        if step.abs() < EPSILON {
+            degenerate_streak += 1;
+            if log.bland_after.is_some_and(|limit| degenerate_streak > limit) {
+                bland = true;
+            }
+        } else {
+            degenerate_streak = 0;
+        }
+
+        match outcome {
+            RatioOutcome::Pivot(row) => tableau.pivot(row, pivot_col),
+            RatioOutcome::PivotToUpper(row) => {
+                // La básica sale en su cota superior: la complementamos antes de pivotear
+                tableau.complement_basic_row(row);
+                tableau.pivot(row, pivot_col);
+            },
+            RatioOutcome::BoundFlip => tableau.complement_column(pivot_col),
+        }
+
+        log.iterations += 1;
+        log.record(tableau);
+    }
+}
+
+/// Saca de la base las artificiales que terminan la Fase 1 en cero (degeneradas).
+/// Si quedaran básicas, la Fase 2 podría hacerlas crecer sin que el ratio test lo
+/// impida y reportaría como óptimo un punto infactible. Cada pivoteo es nulo (el
+/// RHS de la fila es 0), así que no cambia el punto. Si la fila no tiene ninguna
+/// columna real distinta de cero es redundante: la artificial queda fija en 0.
+fn drive_out_artificials(tableau: &mut SimplexTableau, artificial_indices: &[usize]) {
+    let cols = tableau.matrix.cols;
+    for row in 0..tableau.basic_vars.len() {
+        if !artificial_indices.contains(&tableau.basic_vars[row]) {
+            continue;
+        }
+        let pivot_col = (0..cols - 1)
+            .filter(|col| !artificial_indices.contains(col))
+            .map(|col| (col, tableau.matrix.get(row, col).abs()))
+            .filter(|&(_, value)| value > EPSILON)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(col, _)| col);
+        if let Some(col) = pivot_col {
+            tableau.pivot(row, col);
+        }
     }
 }
 
@@ -105,6 +268,16 @@ fn prepare_phase_2(
         }
     }
 
+    // Las columnas complementadas en Fase 1 (x = u - x') también se sustituyen en Z
+    for col in 0..(cols - 1) {
+        if tableau.at_upper[col] {
+            let c = original_objective[col];
+            let rhs = tableau.matrix.get(z_row_idx, cols - 1);
+            tableau.matrix.set(z_row_idx, cols - 1, rhs - c * tableau.upper_bounds[col]);
+            tableau.matrix.set(z_row_idx, col, -c);
+        }
+    }
+
     // B. Pricing Out
     for (row_idx, &basic_col_idx) in tableau.basic_vars.iter().enumerate() {
         if row_idx < z_row_idx {
@@ -135,7 +308,9 @@ fn is_optimal(tableau: &SimplexTableau, ignore_cols: Option<&Vec<usize>>) -> boo
     true
 }
 
-fn select_entering_variable(tableau: &SimplexTableau, ignore_cols: Option<&Vec<usize>>) -> Option<usize> {
+/// Regla de Dantzig (coste reducido más negativo) o, con `bland`, la primera
+/// columna con coste reducido negativo.
+fn select_entering_variable(tableau: &SimplexTableau, ignore_cols: Option<&Vec<usize>>, bland: bool) -> Option<usize> {
     let last_row_idx = tableau.matrix.rows - 1;
     let mut min_val = -EPSILON;
     let mut entering_col = None;
@@ -146,6 +321,9 @@ fn select_entering_variable(tableau: &SimplexTableau, ignore_cols: Option<&Vec<u
         }
         let val = tableau.matrix.get(last_row_idx, j);
         if val < min_val {
+            if bland {
+                return Some(j);
+            }
             min_val = val;
             entering_col = Some(j);
         }
@@ -153,9 +331,34 @@ fn select_entering_variable(tableau: &SimplexTableau, ignore_cols: Option<&Vec<u
     entering_col
 }
 
-fn select_leaving_variable(tableau: &SimplexTableau, col_idx: usize) -> Option<usize> {
+/// Resultado del test de razón del simplex con variables acotadas.
+enum RatioOutcome {
+    /// La básica de la fila llega a cero y sale de la base.
+    Pivot(usize),
+    /// La básica de la fila llega a su cota superior y sale de la base.
+    PivotToUpper(usize),
+    /// La variable entrante alcanza su propia cota antes que cualquier básica.
+    BoundFlip,
+}
+
+/// Test de razón. Devuelve la decisión junto con el paso (cuánto crece la entrante).
+/// Con `bland`, los empates se resuelven por el menor índice de la variable básica.
+fn select_leaving_variable(tableau: &SimplexTableau, col_idx: usize, bland: bool) -> Option<(RatioOutcome, f64)> {
     let mut min_ratio = f64::INFINITY;
-    let mut leaving_row = None;
+    let mut outcome = None;
+    let mut leaving_var = usize::MAX;
+    let mut improves = |ratio: f64, row: usize, min_ratio: f64| {
+        let basic = tableau.basic_vars[row];
+        let better = if bland {
+            ratio < min_ratio - EPSILON || (ratio <= min_ratio + EPSILON && basic < leaving_var)
+        } else {
+            ratio < min_ratio
+        };
+        if better {
+            leaving_var = basic;
+        }
+        better
+    };
 
     for i in 0..(tableau.matrix.rows - 1) {
         let coeff = tableau.matrix.get(i, col_idx);
@@ -163,38 +366,102 @@ fn select_leaving_variable(tableau: &SimplexTableau, col_idx: usize) -> Option<u
 
         if coeff > EPSILON {
             let ratio = rhs / coeff;
-            if ratio < min_ratio {
+            if improves(ratio, i, min_ratio) {
                 min_ratio = ratio;
-                leaving_row = Some(i);
+                outcome = Some(RatioOutcome::Pivot(i));
+            }
+        } else if coeff < -EPSILON {
+            // La básica crece mientras entra la variable: solo limita si está acotada
+            let upper = tableau.upper_bounds[tableau.basic_vars[i]];
+            if upper.is_finite() {
+                let ratio = (upper - rhs) / -coeff;
+                if improves(ratio, i, min_ratio) {
+                    min_ratio = ratio;
+                    outcome = Some(RatioOutcome::PivotToUpper(i));
+                }
             }
         }
     }
-    leaving_row
+
+    if tableau.upper_bounds[col_idx] < min_ratio {
+        min_ratio = tableau.upper_bounds[col_idx];
+        outcome = Some(RatioOutcome::BoundFlip);
+    }
+    outcome.map(|o| (o, min_ratio))
+}
+
+/// Rayo de mejora para un problema no acotado, expresado en las variables de decisión.
+/// En el tableau, la entrante avanza 1 y cada básica cambia en `-a_ij`.
+fn unbounded_ray(
+    tableau: &SimplexTableau,
+    entering_col: usize,
+    reverse_map: &HashMap<usize, String>,
+    var_signs: &[f64],
+    free_splits: &[(usize, usize)],
+) -> HashMap<String, f64> {
+    let rhs_col = tableau.matrix.cols - 1;
+    let mut direction = vec![0.0; rhs_col];
+    direction[entering_col] = 1.0;
+    for (row_idx, &basic_col) in tableau.basic_vars.iter().enumerate() {
+        direction[basic_col] = -tableau.matrix.get(row_idx, entering_col);
+    }
+
+    // Deshacer complementos (x = u - x') y divisiones (x = x+ - x-)
+    for (col_idx, d) in direction.iter_mut().enumerate() {
+        if tableau.at_upper[col_idx] {
+            *d = -*d;
+        }
+    }
+    for &(pos_col, neg_col) in free_splits {
+        direction[pos_col] -= direction[neg_col];
+    }
+
+    let mut ray = HashMap::new();
+    for (&col_idx, name) in reverse_map {
+        if !name.starts_with('_') {
+            let d = var_signs[col_idx] * direction[col_idx];
+            if d.abs() > EPSILON {
+                ray.insert(name.clone(), d);
+            }
+        }
+    }
+    ray
 }
 
 fn extract_solution(
     tableau: &SimplexTableau, 
     reverse_map: &HashMap<usize, String>,
-    constraint_col_map: &HashMap<String, usize>
+    constraint_col_map: &HashMap<String, usize>,
+    var_offsets: &[f64],
+    var_signs: &[f64],
+    free_splits: &[(usize, usize)],
 ) -> Solution {
     let mut variables = HashMap::new();
     let num_rows = tableau.matrix.rows - 1;
     let rhs_col = tableau.matrix.cols - 1;
 
-    // 1. Variables de Decisión
+    // Valor de cada columna: básicas leen el RHS, no básicas valen 0.
+    // Luego se deshacen los complementos (u - x') y las divisiones x+ - x-.
+    let mut col_values = vec![0.0; rhs_col];
     for (row_idx, &col_idx) in tableau.basic_vars.iter().enumerate() {
         if row_idx < num_rows {
-            let val = tableau.matrix.get(row_idx, rhs_col);
-            if let Some(name) = reverse_map.get(&col_idx) {
-                if !name.starts_with('_') {
-                    variables.insert(name.clone(), val);
-                }
-            }
+            col_values[col_idx] = tableau.matrix.get(row_idx, rhs_col);
+        }
+    }
+    for (col_idx, val) in col_values.iter_mut().enumerate() {
+        if tableau.at_upper[col_idx] {
+            *val = tableau.upper_bounds[col_idx] - *val;
         }
     }
-    for name in reverse_map.values() {
-        if !name.starts_with('_') && !variables.contains_key(name) {
-            variables.insert(name.clone(), 0.0);
+    for &(pos_col, neg_col) in free_splits {
+        col_values[pos_col] -= col_values[neg_col];
+    }
+
+    // 1. Variables de Decisión: x = offset + sign * x'
+    for (&col_idx, name) in reverse_map {
+        if !name.starts_with('_') {
+            let val = var_offsets[col_idx] + var_signs[col_idx] * col_values[col_idx];
+            variables.insert(name.clone(), val);
         }
     }
 
@@ -212,7 +479,117 @@ fn extract_solution(
         objective_value: obj_val,
         variables,
         shadow_prices,
+        iterations: 0,
+        trace: Vec::new(),
+        diagnostics: Vec::new(),
+        basic_variables: Vec::new(),
+        non_basic_variables: Vec::new(),
+        rhs_ranges: HashMap::new(),
+        cost_ranges: HashMap::new(),
+    }
+}
+
+/// Ranging del RHS. Si b_i cambia en Δ, las básicas pasan a `b̄ + Δ·s_i·d`, con `d` la
+/// columna i de B⁻¹ (la que era identidad en el tableau inicial) y `s_i` el signo de
+/// normalización de la fila. La base se mantiene mientras `0 <= x_B <= u_B`.
+fn rhs_ranges(
+    tableau: &SimplexTableau,
+    problem: &LinearProblem,
+    row_signs: &[f64],
+    row_identity_cols: &[usize],
+) -> HashMap<String, (f64, f64)> {
+    let rhs_col = tableau.matrix.cols - 1;
+    let mut ranges = HashMap::new();
+
+    for (i, constraint) in problem.constraints.iter().enumerate() {
+        let Some(name) = &constraint.name else { continue };
+        let (mut lo, mut hi) = (f64::NEG_INFINITY, f64::INFINITY);
+
+        for (row, &basic) in tableau.basic_vars.iter().enumerate() {
+            let d = row_signs[i] * tableau.matrix.get(row, row_identity_cols[i]);
+            if d.abs() < EPSILON {
+                continue;
+            }
+            let value = tableau.matrix.get(row, rhs_col);
+            let upper = tableau.upper_bounds[basic];
+            // value + Δ·d >= 0  y  value + Δ·d <= upper
+            let (to_zero, to_upper) = (-value / d, (upper - value) / d);
+            if d > 0.0 {
+                lo = lo.max(to_zero);
+                hi = hi.min(to_upper);
+            } else {
+                hi = hi.min(to_zero);
+                lo = lo.max(to_upper);
+            }
+        }
+
+        ranges.insert(name.clone(), (constraint.rhs + lo, constraint.rhs + hi));
+    }
+    ranges
+}
+
+/// Ranging de costos. Un cambio δ en el costo (interno, de minimización) de una
+/// columna altera cada costo reducido no básico en `δ·(g_k - Σ g_B·a_rk)`; la solución
+/// sigue siendo óptima mientras todos se mantengan >= 0.
+fn cost_ranges(
+    tableau: &SimplexTableau,
+    problem: &LinearProblem,
+    reverse_map: &HashMap<usize, String>,
+    artificial_indices: &[usize],
+    var_signs: &[f64],
+    free_splits: &[(usize, usize)],
+    is_minimization: bool,
+) -> HashMap<String, (f64, f64)> {
+    let rhs_col = tableau.matrix.cols - 1;
+    let z_row = tableau.matrix.rows - 1;
+    let mut ranges = HashMap::new();
+
+    for (&col, name) in reverse_map {
+        if name.starts_with('_') {
+            continue;
+        }
+
+        // Dirección del cambio por columna: x = x+ - x- mueve ambas; las columnas
+        // complementadas (u - x) ven el costo con el signo cambiado.
+        let mut g = vec![0.0; rhs_col];
+        g[col] = 1.0;
+        if let Some(&(_, neg_col)) = free_splits.iter().find(|(pos, _)| *pos == col) {
+            g[neg_col] = -1.0;
+        }
+        for (c, value) in g.iter_mut().enumerate() {
+            if tableau.at_upper[c] {
+                *value = -*value;
+            }
+        }
+
+        let (mut lo, mut hi) = (f64::NEG_INFINITY, f64::INFINITY);
+        for k in 0..rhs_col {
+            if tableau.basic_vars.contains(&k) || artificial_indices.contains(&k) {
+                continue;
+            }
+            let alpha = g[k] - tableau.basic_vars.iter()
+                .enumerate()
+                .map(|(row, &basic)| g[basic] * tableau.matrix.get(row, k))
+                .sum::<f64>();
+            if alpha.abs() < EPSILON {
+                continue;
+            }
+            // r_k + δ·alpha >= 0
+            let limit = -tableau.matrix.get(z_row, k) / alpha;
+            if alpha > 0.0 {
+                lo = lo.max(limit);
+            } else {
+                hi = hi.min(limit);
+            }
+        }
+
+        // Costo interno = (±1 según dirección) · signo de sustitución · costo original
+        let scale = if is_minimization { 1.0 } else { -1.0 } * var_signs[col];
+        let (lo, hi) = if scale > 0.0 { (lo / scale, hi / scale) } else { (hi / scale, lo / scale) };
+        let cost = problem.objective.expression.coefficients.get(name).copied().unwrap_or(0.0);
+        ranges.insert(name.clone(), (cost + lo, cost + hi));
     }
+    ranges
 }
 
 
@@ -220,8 +597,8 @@ fn extract_solution(
 mod tests {
     use std::collections::HashMap;
     use crate::optimization::linear::model::{LinearProblem, Objective, Constraint, LinearExpression, Relation};
-    use crate::optimization::linear::algorithms::simplex::solve_primal;
-    use crate::optimization::linear::error::{OptimizationStatus, LinearOptimizationError};
+    use crate::optimization::linear::algorithms::simplex::{solve_primal, solve_primal_with_options, SimplexOptions};
+    use crate::optimization::linear::error::{OptimizationStatus, LinearOptimizationError, SimplexDiagnostic};
 
     fn expr(terms: &[(&str, f64)], constant: f64) -> LinearExpression {
         let mut e = LinearExpression::new();
@@ -265,6 +642,26 @@ fn test_sensitivity_analysis() {
     assert!((shadow_horas - 5.0).abs() < 1e-6, "Shadow Horas: {}", shadow_horas);
 }
 
+#[test]
+fn test_solution_reports_final_basis() {
+    // Mismo problema Z = 550: en el óptimo (x=10, y=5) ambas restricciones son activas,
+    // así que x e y son básicas y las dos holguras quedan fuera de la base.
+    let objective = Objective::maximize(expr(&[("x", 30.0), ("y", 50.0)], 0.0));
+    let mut problem = LinearProblem::new("Sensitivity", objective);
+    problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 2.0)], 0.0), Relation::LessOrEqual, 20.0).with_name("Madera"));
+    problem.add_constraint(Constraint::new(expr(&[("x", 1.0)], 0.0), Relation::LessOrEqual, 10.0).with_name("Horas"));
+
+    let solution = solve_primal(&problem).unwrap();
+
+    let mut basis = solution.basic_variables.clone();
+    basis.sort();
+    assert_eq!(basis, vec!["x".to_string(), "y".to_string()]);
+
+    let mut non_basic = solution.non_basic_variables.clone();
+    non_basic.sort();
+    assert_eq!(non_basic, vec!["_s_0".to_string(), "_s_1".to_string()]);
+}
+
 #[test]
 fn test_two_phase_minimization() {
     let objective = Objective::minimize(expr(&[("x", 2.0), ("y", 3.0)], 0.0));
@@ -305,10 +702,43 @@ fn test_two_phase_minimization() {
         let mut problem = LinearProblem::new("Unbounded", objective);
         problem.add_constraint(Constraint::new(expr(&[("y", 1.0)], 0.0), Relation::LessOrEqual, 5.0));
         match solve_primal(&problem) {
-            Err(LinearOptimizationError::Unbounded) => assert!(true),
+            Err(LinearOptimizationError::Unbounded { .. }) => assert!(true),
             _ => panic!("Expected Unbounded"),
         }
     }
+
+    #[test]
+    fn test_unbounded_problem_returns_ray() {
+        let objective = Objective::maximize(expr(&[("x", 1.0)], 0.0));
+        let mut problem = LinearProblem::new("Unbounded", objective);
+        problem.add_constraint(Constraint::new(expr(&[("y", 1.0)], 0.0), Relation::LessOrEqual, 5.0));
+        match solve_primal(&problem) {
+            Err(LinearOptimizationError::Unbounded { ray }) => {
+                assert!(!ray.is_empty());
+                // x crece sin límite, y no se mueve
+                assert!((ray["x"] - 1.0).abs() < 1e-9);
+                assert!(!ray.contains_key("y"));
+            },
+            other => panic!("Expected Unbounded, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_degenerate_pivot_diagnostic() {
+        // Max 2x + y  s.a.  x - y <= 0,  x + y <= 4
+        // Entra x y empata en razón 0 con la fila de x - y <= 0
+        let objective = Objective::maximize(expr(&[("x", 2.0), ("y", 1.0)], 0.0));
+        let mut problem = LinearProblem::new("Degenerate", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", -1.0)], 0.0), Relation::LessOrEqual, 0.0));
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 1.0)], 0.0), Relation::LessOrEqual, 4.0));
+
+        let solution = solve_primal(&problem).unwrap();
+        assert!((solution.objective_value - 6.0).abs() < 1e-6);
+        assert_eq!(
+            solution.diagnostics.first(),
+            Some(&SimplexDiagnostic::DegeneratePivot { iteration: 1, entering: "x".into(), leaving: "_s_0".into() })
+        );
+    }
     
     #[test]
     fn test_simple_minimization() {
@@ -330,4 +760,216 @@ fn test_two_phase_minimization() {
         let solution = solve_primal(&problem).expect("Debe tener solución");
         assert!((solution.objective_value - 80.0).abs() < 1e-6);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_iteration_trace() {
+        let objective = Objective::maximize(expr(&[("x", 3.0), ("y", 2.0)], 0.0));
+        let mut problem = LinearProblem::new("Test Mix", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", 2.0), ("y", 1.0)], 0.0), Relation::LessOrEqual, 100.0));
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 1.0)], 0.0), Relation::LessOrEqual, 80.0));
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0)], 0.0), Relation::LessOrEqual, 40.0));
+
+        let solution = solve_primal_with_options(&problem, &SimplexOptions::verbose()).unwrap();
+        assert!((solution.objective_value - 180.0).abs() < 1e-6);
+        assert!(solution.iterations > 0);
+        assert_eq!(solution.trace.len(), solution.iterations + 1);
+
+        // El último tableau del historial contiene el óptimo en la fila Z
+        let last = solution.trace.last().unwrap();
+        let z = last.matrix.get(last.matrix.rows - 1, last.matrix.cols - 1);
+        assert!((z - 180.0).abs() < 1e-6);
+
+        // Sin modo verbose solo se cuentan los pivoteos
+        let quiet = solve_primal(&problem).unwrap();
+        assert_eq!(quiet.iterations, solution.iterations);
+        assert!(quiet.trace.is_empty());
+    }
+
+    #[test]
+    fn test_objective_constant_is_reported() {
+        // Max 3x + 2y + 100 sobre el problema de Z = 180
+        let objective = Objective::maximize(expr(&[("x", 3.0), ("y", 2.0)], 100.0));
+        let mut problem = LinearProblem::new("Fixed Fee", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", 2.0), ("y", 1.0)], 0.0), Relation::LessOrEqual, 100.0));
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 1.0)], 0.0), Relation::LessOrEqual, 80.0));
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0)], 0.0), Relation::LessOrEqual, 40.0));
+        let solution = solve_primal(&problem).unwrap();
+        assert!((solution.objective_value - 280.0).abs() < 1e-6);
+
+        // En minimización la constante se suma igual (no se invierte con el signo)
+        let objective = Objective::minimize(expr(&[("x", 2.0), ("y", 3.0)], 100.0));
+        let mut problem = LinearProblem::new("Fixed Cost", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 1.0)], 0.0), Relation::GreaterOrEqual, 10.0));
+        let solution = solve_primal(&problem).unwrap();
+        assert!((solution.objective_value - 120.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_upper_bound_matches_explicit_constraint() {
+        // Max 3x + 2y  s.a.  x + y <= 4,  x + 3y <= 6,  x <= 3
+        let build = || {
+            let objective = Objective::maximize(expr(&[("x", 3.0), ("y", 2.0)], 0.0));
+            let mut problem = LinearProblem::new("Bounded", objective);
+            problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 1.0)], 0.0), Relation::LessOrEqual, 4.0));
+            problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 3.0)], 0.0), Relation::LessOrEqual, 6.0));
+            problem
+        };
+
+        let mut explicit = build();
+        explicit.add_constraint(Constraint::new(expr(&[("x", 1.0)], 0.0), Relation::LessOrEqual, 3.0));
+        let mut bounded = build();
+        bounded.set_upper_bound("x", 3.0);
+
+        let expected = solve_primal(&explicit).unwrap();
+        let solution = solve_primal(&bounded).unwrap();
+
+        assert!((solution.objective_value - expected.objective_value).abs() < 1e-6);
+        assert!((solution.objective_value - 11.0).abs() < 1e-6);
+        for var in ["x", "y"] {
+            assert!((solution.variables[var] - expected.variables[var]).abs() < 1e-6, "Variable {}", var);
+        }
+        // La cota no agrega filas al tableau
+        assert_eq!(bounded.constraints.len(), 2);
+    }
+
+    #[test]
+    fn test_bounded_variables_with_phase_one() {
+        // Min -x + y  s.a.  x + y >= 5,  1 <= x <= 2,  y <= 10
+        let objective = Objective::minimize(expr(&[("x", -1.0), ("y", 1.0)], 0.0));
+        let mut problem = LinearProblem::new("Bounded Min", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 1.0)], 0.0), Relation::GreaterOrEqual, 5.0));
+        problem.set_bounds("x", 1.0, 2.0);
+        problem.set_upper_bound("y", 10.0);
+
+        let solution = solve_primal(&problem).unwrap();
+        // x = 2 (en su cota), y = 3  =>  -2 + 3 = 1
+        assert!((solution.variables["x"] - 2.0).abs() < 1e-6);
+        assert!((solution.variables["y"] - 3.0).abs() < 1e-6);
+        assert!((solution.objective_value - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_free_variable_takes_negative_value() {
+        // Min x + 2y  s.a.  x + y >= -2,  x - y <= 4,  y <= 3,  x libre
+        // Óptimo único: x = -2, y = 0 (exige que x sea negativa).
+        let objective = Objective::minimize(expr(&[("x", 1.0), ("y", 2.0)], 0.0));
+        let mut problem = LinearProblem::new("Free", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 1.0)], 0.0), Relation::GreaterOrEqual, -2.0));
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", -1.0)], 0.0), Relation::LessOrEqual, 4.0));
+        problem.add_constraint(Constraint::new(expr(&[("y", 1.0)], 0.0), Relation::LessOrEqual, 3.0));
+        problem.set_free("x");
+
+        let solution = solve_primal(&problem).unwrap();
+        assert!((solution.variables["x"] - (-2.0)).abs() < 1e-6, "x = {}", solution.variables["x"]);
+        assert!(solution.variables["y"].abs() < 1e-6);
+        assert!((solution.objective_value - (-2.0)).abs() < 1e-6);
+        assert!(!solution.variables.contains_key("_neg_x"));
+    }
+
+    #[test]
+    fn test_variable_with_only_upper_bound() {
+        // Min x  s.a.  x >= -5,  con x en (-inf, 7]
+        let objective = Objective::minimize(expr(&[("x", 1.0)], 0.0));
+        let mut problem = LinearProblem::new("Upper Only", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0)], 0.0), Relation::GreaterOrEqual, -5.0));
+        problem.set_bounds("x", f64::NEG_INFINITY, 7.0);
+
+        let solution = solve_primal(&problem).unwrap();
+        assert!((solution.variables["x"] - (-5.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_validation_error_is_human_readable() {
+        let objective = Objective::maximize(expr(&[("x", 1.0)], 0.0));
+        let mut problem = LinearProblem::new("BadBounds", objective);
+        problem.set_bounds("x", 5.0, 1.0);
+
+        let err = solve_primal(&problem).unwrap_err();
+        assert!(matches!(err, LinearOptimizationError::ValidationError(_)));
+        assert_eq!(err.to_string(), "Error de validación: Cotas inconsistentes para 'x': 5 > 1");
+    }
+
+    // Ejemplo de Beale: con la regla de Dantzig el simplex cicla sin salir del vértice degenerado
+    fn beale_problem() -> LinearProblem {
+        let objective = Objective::minimize(expr(&[("x4", -0.75), ("x5", 20.0), ("x6", -0.5), ("x7", 6.0)], 0.0));
+        let mut problem = LinearProblem::new("Beale", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x4", 0.25), ("x5", -8.0), ("x6", -1.0), ("x7", 9.0)], 0.0), Relation::LessOrEqual, 0.0));
+        problem.add_constraint(Constraint::new(expr(&[("x4", 0.5), ("x5", -12.0), ("x6", -0.5), ("x7", 3.0)], 0.0), Relation::LessOrEqual, 0.0));
+        problem.add_constraint(Constraint::new(expr(&[("x6", 1.0)], 0.0), Relation::LessOrEqual, 1.0));
+        problem
+    }
+
+    #[test]
+    fn test_bland_rule_breaks_cycling() {
+        let problem = beale_problem();
+        assert!(matches!(solve_primal(&problem), Err(LinearOptimizationError::MaxIterationsReached)));
+
+        let solution = solve_primal_with_options(&problem, &SimplexOptions::anti_cycling()).unwrap();
+        assert!((solution.objective_value - (-1.25)).abs() < 1e-6, "Z = {}", solution.objective_value);
+        assert!((solution.variables["x4"] - 1.0).abs() < 1e-6);
+        assert!((solution.variables["x6"] - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_bland_rule_after_degenerate_limit() {
+        let options = SimplexOptions { anti_cycling: true, degenerate_pivot_limit: 10, ..SimplexOptions::default() };
+        let solution = solve_primal_with_options(&beale_problem(), &options).unwrap();
+        assert!((solution.objective_value - (-1.25)).abs() < 1e-6);
+    }
+
+    fn assert_range(ranges: &HashMap<String, (f64, f64)>, name: &str, expected: (f64, f64)) {
+        let (lo, hi) = ranges[name];
+        let close = |a: f64, b: f64| (a.is_infinite() && a == b) || (a - b).abs() < 1e-6;
+        assert!(close(lo, expected.0) && close(hi, expected.1), "{}: ({}, {}) vs {:?}", name, lo, hi, expected);
+    }
+
+    #[test]
+    fn test_sensitivity_ranges_maximization() {
+        // Wyndor: Max 3x + 5y  s.a.  x <= 4,  2y <= 12,  3x + 2y <= 18  (óptimo x = 2, y = 6)
+        let objective = Objective::maximize(expr(&[("x", 3.0), ("y", 5.0)], 0.0));
+        let mut problem = LinearProblem::new("Wyndor", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0)], 0.0), Relation::LessOrEqual, 4.0).with_name("planta1"));
+        problem.add_constraint(Constraint::new(expr(&[("y", 2.0)], 0.0), Relation::LessOrEqual, 12.0).with_name("planta2"));
+        problem.add_constraint(Constraint::new(expr(&[("x", 3.0), ("y", 2.0)], 0.0), Relation::LessOrEqual, 18.0).with_name("planta3"));
+
+        let solution = solve_primal(&problem).unwrap();
+        assert_range(&solution.rhs_ranges, "planta1", (2.0, f64::INFINITY));
+        assert_range(&solution.rhs_ranges, "planta2", (6.0, 18.0));
+        assert_range(&solution.rhs_ranges, "planta3", (12.0, 24.0));
+        assert_range(&solution.cost_ranges, "x", (0.0, 7.5));
+        assert_range(&solution.cost_ranges, "y", (2.0, f64::INFINITY));
+    }
+
+    #[test]
+    fn test_sensitivity_ranges_minimization_with_surplus() {
+        // Min 2x + 3y  s.a.  x + y >= 4,  x + 3y >= 6  (óptimo x = 3, y = 1)
+        let objective = Objective::minimize(expr(&[("x", 2.0), ("y", 3.0)], 0.0));
+        let mut problem = LinearProblem::new("Dieta", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 1.0)], 0.0), Relation::GreaterOrEqual, 4.0).with_name("c0"));
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 3.0)], 0.0), Relation::GreaterOrEqual, 6.0).with_name("c1"));
+
+        let solution = solve_primal(&problem).unwrap();
+        assert!((solution.objective_value - 9.0).abs() < 1e-6);
+        assert_range(&solution.rhs_ranges, "c0", (2.0, 6.0));
+        assert_range(&solution.rhs_ranges, "c1", (4.0, 12.0));
+        assert_range(&solution.cost_ranges, "x", (1.0, 3.0));
+        assert_range(&solution.cost_ranges, "y", (2.0, 6.0));
+    }
+
+    #[test]
+    fn test_degenerate_artificial_does_not_stay_basic() {
+        // Max x - 3y  s.a.  -2x - y >= -1,  x <= 50,  y >= 1  (cota inferior)
+        // Tras desplazar y = 1 + y', la primera fila queda -2x - y' >= 0: la artificial
+        // termina la Fase 1 en la base con valor 0 y no debe volver a crecer en la Fase 2.
+        let objective = Objective::maximize(expr(&[("x", 1.0), ("y", -3.0)], 0.0));
+        let mut problem = LinearProblem::new("Degenerate Artificial", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", -2.0), ("y", -1.0)], 0.0), Relation::GreaterOrEqual, -1.0));
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0)], 0.0), Relation::LessOrEqual, 50.0));
+        problem.set_lower_bound("y", 1.0);
+
+        let solution = solve_primal(&problem).unwrap();
+        assert!(solution.variables["x"].abs() < 1e-6, "x = {}", solution.variables["x"]);
+        assert!((solution.variables["y"] - 1.0).abs() < 1e-6, "y = {}", solution.variables["y"]);
+        assert!((solution.objective_value - (-3.0)).abs() < 1e-6, "Z = {}", solution.objective_value);
+    }
+}
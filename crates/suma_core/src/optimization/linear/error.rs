@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use thiserror::Error; // Necesitas agregar 'thiserror' a las dependencias si no está visible aquí
+use crate::optimization::linear::internal::tableau::SimplexTableau;
 
 // Mantenemos OptimizationStatus y Solution igual...
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -13,6 +14,71 @@ pub struct Solution {
     pub objective_value: f64,
     pub variables: HashMap<String, f64>,
     pub shadow_prices: HashMap<String, f64>,
+    /// Número de pivoteos realizados (Fase 1 + Fase 2). En Branch & Bound, número
+    /// de nodos explorados.
+    pub iterations: usize,
+    /// Tableau inicial y tableau tras cada pivoteo. Vacío salvo con `SimplexOptions::record_trace`.
+    pub trace: Vec<SimplexTableau>,
+    /// Avisos detectados durante la resolución (no impiden llegar al óptimo).
+    pub diagnostics: Vec<SimplexDiagnostic>,
+    /// Variables en la base final, en el orden de las filas del tableau.
+    /// Las columnas internas conservan su nombre (`_s_0` holgura, `_surplus_1` exceso, ...).
+    pub basic_variables: Vec<String>,
+    /// Columnas fuera de la base final (sin las artificiales de la Fase 1).
+    pub non_basic_variables: Vec<String>,
+    /// Rango `(mín, máx)` del RHS de cada restricción con nombre en el que la base
+    /// final sigue siendo factible (y, por tanto, su precio sombra válido).
+    pub rhs_ranges: HashMap<String, (f64, f64)>,
+    /// Rango `(mín, máx)` del coeficiente de cada variable de decisión en el objetivo
+    /// en el que la solución óptima no cambia.
+    pub cost_ranges: HashMap<String, (f64, f64)>,
+}
+
+impl Solution {
+    /// Variables en orden natural de nombre (`x2` antes que `x10`), para que los
+    /// informes salgan iguales en cada ejecución.
+    pub fn sorted_variables(&self) -> Vec<(&str, f64)> {
+        natural_sorted(&self.variables)
+    }
+
+    /// Precios sombra en orden natural de restricción (`c2` antes que `c10`).
+    pub fn sorted_shadow_prices(&self) -> Vec<(&str, f64)> {
+        natural_sorted(&self.shadow_prices)
+    }
+}
+
+fn natural_sorted(map: &HashMap<String, f64>) -> Vec<(&str, f64)> {
+    let mut entries: Vec<_> = map.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    entries.sort_by(|a, b| natural_key(a.0).cmp(&natural_key(b.0)).then_with(|| a.0.cmp(b.0)));
+    entries
+}
+
+// Separa el sufijo numérico: "c10" -> ("c", Some(10)); sin sufijo, None va primero
+fn natural_key(name: &str) -> (&str, Option<u64>) {
+    let prefix = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    (prefix, name[prefix.len()..].parse().ok())
+}
+
+/// Avisos del simplex sobre la trayectoria seguida.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimplexDiagnostic {
+    /// Pivoteo con paso cero (empate en la razón mínima en 0): la base cambia
+    /// pero el objetivo no mejora. Muchos seguidos pueden indicar un ciclo.
+    DegeneratePivot {
+        iteration: usize,
+        entering: String,
+        leaving: String,
+    },
+}
+
+impl std::fmt::Display for SimplexDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SimplexDiagnostic::DegeneratePivot { iteration, entering, leaving } => write!(
+                f, "Pivoteo degenerado en la iteración {}: entra '{}', sale '{}'", iteration, entering, leaving
+            ),
+        }
+    }
 }
 
 /// Errores específicos de Programación Lineal usando `thiserror`
@@ -21,8 +87,10 @@ pub enum LinearOptimizationError {
     #[error("El problema no tiene solución factible (Infeasible).")]
     Infeasible,
     
+    /// `ray` es la dirección de mejora: moverse sobre ella mantiene la factibilidad
+    /// y mejora el objetivo sin límite.
     #[error("El problema es no acotado (Unbounded).")]
-    Unbounded,
+    Unbounded { ray: HashMap<String, f64> },
     
     #[error("Límite de iteraciones alcanzado.")]
     MaxIterationsReached,
@@ -35,6 +103,59 @@ pub enum LinearOptimizationError {
 
     #[error("Expresión no lineal detectada: {0}")]
     NonLinearExpression(String),
+
+    #[error("Error de formato LP en la línea {line}: {message}")]
+    ParseError { line: usize, message: String },
 }
 
-pub type OptimizationResult = Result<Solution, LinearOptimizationError>;
\ No newline at end of file
+pub type OptimizationResult = Result<Solution, LinearOptimizationError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimization::error::OptimizationError;
+
+    #[test]
+    fn test_display_is_not_debug_formatting() {
+        let errors = [
+            LinearOptimizationError::Infeasible,
+            LinearOptimizationError::Unbounded { ray: HashMap::from([("x".to_string(), 1.0)]) },
+            LinearOptimizationError::ParseError { line: 3, message: "falta el objetivo".to_string() },
+        ];
+        for err in &errors {
+            let text = err.to_string();
+            assert_ne!(text, format!("{:?}", err));
+            assert!(!text.contains('{'), "{}", text);
+        }
+        assert_eq!(errors[2].to_string(), "Error de formato LP en la línea 3: falta el objetivo");
+
+        // El envoltorio del módulo de optimización muestra el mensaje interno
+        let wrapped: OptimizationError = LinearOptimizationError::Infeasible.into();
+        assert_eq!(wrapped.to_string(), "El problema no tiene solución factible (Infeasible).");
+    }
+
+    #[test]
+    fn test_sorted_entries_use_numeric_suffix() {
+        let prices: HashMap<String, f64> = (0..12).map(|i| (format!("c{}", i), i as f64)).collect();
+        let solution = Solution {
+            status: OptimizationStatus::Optimal,
+            objective_value: 0.0,
+            variables: HashMap::from([("y".into(), 1.0), ("x10".into(), 2.0), ("x2".into(), 3.0), ("x".into(), 4.0)]),
+            shadow_prices: prices,
+            iterations: 0,
+            trace: Vec::new(),
+            diagnostics: Vec::new(),
+            basic_variables: Vec::new(),
+            non_basic_variables: Vec::new(),
+            rhs_ranges: HashMap::new(),
+            cost_ranges: HashMap::new(),
+        };
+
+        let names: Vec<&str> = solution.sorted_shadow_prices().into_iter().map(|(k, _)| k).collect();
+        let expected: Vec<String> = (0..12).map(|i| format!("c{}", i)).collect();
+        assert_eq!(names, expected);
+
+        let names: Vec<&str> = solution.sorted_variables().into_iter().map(|(k, _)| k).collect();
+        assert_eq!(names, vec!["x", "x2", "x10", "y"]);
+    }
+}
@@ -7,9 +7,61 @@ pub struct SimplexTableau {
     pub basic_vars: Vec<usize>,    
     /// Índices de las variables fuera de la base (opcional, útil para debug)
     pub non_basic_vars: Vec<usize>,
+    /// Cota superior de cada columna (`f64::INFINITY` si no está acotada).
+    pub upper_bounds: Vec<f64>,
+    /// Columnas sustituidas por su complemento `u - x` (variable en su cota superior).
+    pub at_upper: Vec<bool>,
 }
 
 impl SimplexTableau {
+    /// Crea un tableau sin cotas superiores en ninguna columna.
+    pub fn new(matrix: DenseMatrix<f64>, basic_vars: Vec<usize>, non_basic_vars: Vec<usize>) -> Self {
+        let num_vars = matrix.cols - 1;
+        Self {
+            matrix,
+            basic_vars,
+            non_basic_vars,
+            upper_bounds: vec![f64::INFINITY; num_vars],
+            at_upper: vec![false; num_vars],
+        }
+    }
+
+    /// Sustituye la columna `col` (no básica) por su complemento `x' = u - x`.
+    /// Así la variable pasa a su cota superior sin cambiar la base:
+    /// `b_i -= a_ij * u` y `a_ij = -a_ij` en todas las filas, incluida Z.
+    pub fn complement_column(&mut self, col: usize) {
+        let upper = self.upper_bounds[col];
+        let rhs_col = self.matrix.cols - 1;
+
+        for i in 0..self.matrix.rows {
+            let a = self.matrix.get(i, col);
+            if a != 0.0 {
+                let rhs = self.matrix.get(i, rhs_col);
+                self.matrix.set(i, rhs_col, rhs - a * upper);
+                self.matrix.set(i, col, -a);
+            }
+        }
+        self.at_upper[col] = !self.at_upper[col];
+    }
+
+    /// Sustituye la variable básica de `row` por su complemento `x' = u - x`.
+    /// Se usa antes de pivotear cuando la básica sale de la base en su cota superior.
+    pub fn complement_basic_row(&mut self, row: usize) {
+        let basic = self.basic_vars[row];
+        let upper = self.upper_bounds[basic];
+        let rhs_col = self.matrix.cols - 1;
+
+        for j in 0..rhs_col {
+            if j != basic {
+                let a = self.matrix.get(row, j);
+                self.matrix.set(row, j, -a);
+            }
+        }
+        let rhs = self.matrix.get(row, rhs_col);
+        self.matrix.set(row, rhs_col, upper - rhs);
+        self.at_upper[basic] = !self.at_upper[basic];
+    }
+
     /// Ejecuta la operación de Pivoteo (Gaussian Pivot).
     /// 1. Divide la fila pivote por el elemento pivote (para hacerlo 1).
     /// 2. Resta múltiplos de la fila pivote a todas las demás filas (para hacerlas 0).
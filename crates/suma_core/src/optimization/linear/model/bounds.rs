@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// Cotas de una variable de decisión: `lower <= x <= upper`.
+/// Por defecto toda variable es no negativa y sin cota superior (`0 <= x < inf`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VariableBounds {
+    #[serde(with = "bound_value")]
+    pub lower: f64,
+    #[serde(with = "bound_value")]
+    pub upper: f64,
+}
+
+impl VariableBounds {
+    pub fn new(lower: f64, upper: f64) -> Self {
+        Self { lower, upper }
+    }
+
+    /// Variable libre, sin restricción de signo.
+    pub fn free() -> Self {
+        Self { lower: f64::NEG_INFINITY, upper: f64::INFINITY }
+    }
+
+    /// Indica si la variable no tiene ninguna cota finita.
+    pub fn is_free(&self) -> bool {
+        self.lower == f64::NEG_INFINITY && self.upper == f64::INFINITY
+    }
+
+    /// Indica si la variable tiene una cota superior finita.
+    pub fn has_upper(&self) -> bool {
+        self.upper.is_finite()
+    }
+}
+
+impl Default for VariableBounds {
+    fn default() -> Self {
+        Self { lower: 0.0, upper: f64::INFINITY }
+    }
+}
+
+/// JSON no admite infinitos: las cotas no finitas se guardan como `"inf"` / `"-inf"`.
+mod bound_value {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Finite(f64),
+        Named(String),
+    }
+
+    pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        match *value {
+            v if v == f64::INFINITY => Repr::Named("inf".into()),
+            v if v == f64::NEG_INFINITY => Repr::Named("-inf".into()),
+            v => Repr::Finite(v),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        match Repr::deserialize(deserializer)? {
+            Repr::Finite(v) => Ok(v),
+            Repr::Named(name) => match name.as_str() {
+                "inf" => Ok(f64::INFINITY),
+                "-inf" => Ok(f64::NEG_INFINITY),
+                other => Err(serde::de::Error::custom(format!("cota inválida: '{}'", other))),
+            },
+        }
+    }
+}
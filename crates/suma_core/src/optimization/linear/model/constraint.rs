@@ -1,8 +1,9 @@
 use std::fmt;
+use serde::{Deserialize, Serialize};
 use super::LinearExpression;
 
 /// Define la relación lógica entre el lado izquierdo (LHS) y el derecho (RHS).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Relation {
     LessOrEqual,    // <=
     GreaterOrEqual, // >=
@@ -21,7 +22,7 @@ impl fmt::Display for Relation {
 
 /// Representa una restricción lineal: LHS [Relación] RHS
 /// Ejemplo: 2x + y <= 10
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Constraint {
     /// Nombre opcional para identificar la restricción (ej: "Limite_Horas")
     pub name: Option<String>,
@@ -2,8 +2,9 @@
 
 use std::collections::HashMap;
 use std::fmt;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LinearExpression {
     pub coefficients: HashMap<String, f64>,
     pub constant: f64,
@@ -2,8 +2,10 @@ pub mod problem;
 pub mod objective;
 pub mod constraint;
 pub mod expression;
+pub mod bounds;
 
 pub use expression::*;
 pub use objective::*;
 pub use constraint::*;
-pub use problem::*;
\ No newline at end of file
+pub use problem::*;
+pub use bounds::*;
\ No newline at end of file
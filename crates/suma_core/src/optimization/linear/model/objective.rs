@@ -1,8 +1,9 @@
 use std::fmt;
+use serde::{Deserialize, Serialize};
 use super::LinearExpression;
 
 /// Define la dirección de la optimización.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OptimizationDirection {
     Maximize,
     Minimize,
@@ -10,7 +11,7 @@ pub enum OptimizationDirection {
 
 /// Representa la Función Objetivo del problema (Z).
 /// Ejemplo: Max Z = 3x + 2y
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Objective {
     pub direction: OptimizationDirection,
     pub expression: LinearExpression,
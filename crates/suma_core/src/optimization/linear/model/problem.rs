@@ -1,12 +1,19 @@
-use std::collections::HashSet;
-use super::{Objective, Constraint};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use super::{Objective, Constraint, VariableBounds, OptimizationDirection};
 
 /// Estructura principal que agrupa todo el modelo de optimización lineal.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinearProblem {
     pub name: String,
     pub objective: Objective,
     pub constraints: Vec<Constraint>,
+
+    /// Cotas explícitas por variable. Las variables ausentes usan `0 <= x < inf`.
+    /// Se manejan directamente en el simplex (técnica de variables acotadas),
+    /// sin agregar filas al tableau.
+    pub bounds: HashMap<String, VariableBounds>,
 }
 
 impl LinearProblem {
@@ -16,6 +23,7 @@ impl LinearProblem {
             name: name.to_string(),
             objective,
             constraints: Vec::new(),
+            bounds: HashMap::new(),
         }
     }
 
@@ -24,6 +32,31 @@ impl LinearProblem {
         self.constraints.push(constraint);
     }
 
+    /// Fija ambas cotas de una variable: `lower <= var <= upper`.
+    pub fn set_bounds(&mut self, var: &str, lower: f64, upper: f64) {
+        self.bounds.insert(var.to_string(), VariableBounds::new(lower, upper));
+    }
+
+    /// Fija la cota superior de una variable, conservando su cota inferior.
+    pub fn set_upper_bound(&mut self, var: &str, upper: f64) {
+        self.bounds.entry(var.to_string()).or_default().upper = upper;
+    }
+
+    /// Fija la cota inferior de una variable, conservando su cota superior.
+    pub fn set_lower_bound(&mut self, var: &str, lower: f64) {
+        self.bounds.entry(var.to_string()).or_default().lower = lower;
+    }
+
+    /// Declara una variable libre (sin restricción de signo): `-inf < var < inf`.
+    pub fn set_free(&mut self, var: &str) {
+        self.bounds.insert(var.to_string(), VariableBounds::free());
+    }
+
+    /// Devuelve las cotas efectivas de una variable (las por defecto si no se definieron).
+    pub fn bounds_of(&self, var: &str) -> VariableBounds {
+        self.bounds.get(var).copied().unwrap_or_default()
+    }
+
     /// Recolecta todos los nombres de variables únicos usados en el problema.
     /// Esto es vital para saber cuántas columnas (N) tendrá nuestra matriz.
     pub fn get_variables(&self) -> HashSet<String> {
@@ -41,6 +74,125 @@ impl LinearProblem {
             }
         }
 
+        // 3. Variables que solo aparecen en las cotas
+        for key in self.bounds.keys() {
+            vars.insert(key.clone());
+        }
+
         vars
     }
-}
\ No newline at end of file
+}
+
+// Formato legible compatible con `LinearProblem::from_lp_string`:
+//
+//   \ Problem name: Mix
+//   maximize
+//    obj: 3*x + 2*y + 100
+//   subject to
+//    madera: 2*x + 1*y <= 100
+//    1*x + 1*y <= 80
+//   bounds
+//    x <= 40
+//   end
+impl fmt::Display for LinearProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\\ Problem name: {}", self.name)?;
+
+        let dir_str = match self.objective.direction {
+            OptimizationDirection::Maximize => "maximize",
+            OptimizationDirection::Minimize => "minimize",
+        };
+        writeln!(f, "{}", dir_str)?;
+        writeln!(f, " obj: {}", self.objective.expression)?;
+
+        writeln!(f, "subject to")?;
+        for c in &self.constraints {
+            // La constante del LHS se pasa al RHS para que la fila se pueda volver a leer
+            let mut lhs = c.lhs.clone();
+            let rhs = c.rhs - lhs.constant;
+            lhs.set_constant(0.0);
+            match &c.name {
+                Some(name) => writeln!(f, " {}: {} {} {}", name, lhs, c.relation, rhs)?,
+                // Sin etiqueta: el parser la deja en `None`, igual que el original
+                None => writeln!(f, " {} {} {}", lhs, c.relation, rhs)?,
+            }
+        }
+
+        let mut bounded: Vec<_> = self.bounds.iter()
+            .filter(|(_, b)| **b != VariableBounds::default())
+            .collect();
+        if !bounded.is_empty() {
+            bounded.sort_by_key(|(name, _)| name.as_str());
+            writeln!(f, "bounds")?;
+            for (name, b) in bounded {
+                if b.is_free() {
+                    writeln!(f, " {} free", name)?;
+                } else if b.has_upper() {
+                    writeln!(f, " {} <= {} <= {}", b.lower, name, b.upper)?;
+                } else {
+                    writeln!(f, " {} >= {}", name, b.lower)?;
+                }
+            }
+        }
+
+        write!(f, "end")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimization::linear::model::{LinearExpression, Relation};
+
+    fn expr(terms: &[(&str, f64)], constant: f64) -> LinearExpression {
+        let mut e = LinearExpression::new();
+        for (name, coeff) in terms { e.add_term(name, *coeff); }
+        e.set_constant(constant);
+        e
+    }
+
+    fn sample_problem() -> LinearProblem {
+        let objective = Objective::maximize(expr(&[("x", 3.0), ("y", 2.0)], 100.0));
+        let mut problem = LinearProblem::new("Mix", objective);
+        problem.add_constraint(Constraint::new(expr(&[("x", 2.0), ("y", 1.0)], 0.0), Relation::LessOrEqual, 100.0).with_name("madera"));
+        problem.add_constraint(Constraint::new(expr(&[("x", 1.0), ("y", 1.0)], 0.0), Relation::LessOrEqual, 80.0).with_name("horas"));
+        problem.set_upper_bound("x", 40.0);
+        problem
+    }
+
+    #[test]
+    fn test_display_contains_objective_and_named_constraints() {
+        let text = sample_problem().to_string();
+
+        assert!(text.contains("maximize"));
+        assert!(text.contains("3*x + 2*y + 100"), "{}", text);
+        assert!(text.contains("subject to"));
+        assert!(text.contains("madera: 2*x + 1*y <= 100"), "{}", text);
+        assert!(text.contains("horas: 1*x + 1*y <= 80"), "{}", text);
+        assert!(text.contains("0 <= x <= 40"), "{}", text);
+    }
+
+    #[test]
+    fn test_display_round_trips_through_lp_parser() {
+        let original = sample_problem();
+        let parsed = LinearProblem::from_lp_string(&original.to_string()).unwrap();
+
+        assert_eq!(parsed.objective, original.objective);
+        assert_eq!(parsed.constraints, original.constraints);
+        assert_eq!(parsed.bounds_of("x"), original.bounds_of("x"));
+        assert_eq!(parsed.bounds_of("y"), VariableBounds::default());
+    }
+
+    #[test]
+    fn test_display_keeps_unnamed_constraints_unnamed() {
+        let mut problem = sample_problem();
+        problem.add_constraint(Constraint::new(expr(&[("y", 1.0)], 0.0), Relation::GreaterOrEqual, 5.0));
+
+        let text = problem.to_string();
+        assert!(text.contains("\n 1*y >= 5\n"), "{}", text);
+
+        let parsed = LinearProblem::from_lp_string(&text).unwrap();
+        assert_eq!(parsed.constraints, problem.constraints);
+        assert!(parsed.constraints[2].name.is_none());
+    }
+}
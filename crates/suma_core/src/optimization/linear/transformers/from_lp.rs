@@ -0,0 +1,515 @@
+// src/core/optimization/linear/transformers/from_lp.rs
+
+use crate::optimization::linear::model::{
+    LinearProblem, Objective, Constraint, LinearExpression, Relation, OptimizationDirection,
+};
+use crate::optimization::linear::error::LinearOptimizationError;
+
+impl LinearProblem {
+    /// Construye un problema a partir de un subconjunto del formato CPLEX LP:
+    ///
+    /// ```text
+    /// \ comentario
+    /// Maximize
+    ///  obj: 3 x + 2 y
+    /// Subject To
+    ///  c1: 2 x + y <= 100
+    ///  c2: x + y <= 80
+    /// Bounds
+    ///  x <= 40
+    ///  -inf <= z <= 10
+    ///  w free
+    /// End
+    /// ```
+    ///
+    /// Las expresiones pueden ocupar varias líneas. Las secciones `General`,
+    /// `Binary` y `SOS` no están soportadas.
+    pub fn from_lp_string(text: &str) -> Result<LinearProblem, LinearOptimizationError> {
+        let sections = split_sections(text)?;
+
+        let mut direction = None;
+        let mut objective = LinearExpression::new();
+        let mut constraints = Vec::new();
+        let mut bounds = Vec::new();
+
+        for section in sections {
+            let mut parser = TokenParser::new(tokenize(&section.body)?);
+            match section.kind {
+                SectionKind::Objective(dir) => {
+                    if direction.is_some() {
+                        return Err(parse_error(section.line, "Hay más de una función objetivo"));
+                    }
+                    direction = Some(dir);
+                    parser.skip_label();
+                    objective = parser.parse_expression()?;
+                    parser.expect_end()?;
+                },
+                SectionKind::Constraints => {
+                    while !parser.is_done() {
+                        constraints.push(parser.parse_constraint()?);
+                    }
+                },
+                SectionKind::Bounds => {
+                    while !parser.is_done() {
+                        bounds.push(parser.parse_bound()?);
+                    }
+                },
+                SectionKind::End => {},
+            }
+        }
+
+        let direction = direction
+            .ok_or_else(|| parse_error(1, "Falta la sección Maximize/Minimize"))?;
+
+        let mut problem = LinearProblem::new("lp", Objective::new(direction, objective));
+        for c in constraints {
+            problem.add_constraint(c);
+        }
+        for bound in bounds {
+            match bound {
+                Bound::Lower(var, v) => problem.set_lower_bound(&var, v),
+                Bound::Upper(var, v) => problem.set_upper_bound(&var, v),
+                Bound::Fixed(var, v) => problem.set_bounds(&var, v, v),
+                Bound::Range(var, l, u) => problem.set_bounds(&var, l, u),
+                Bound::Free(var) => problem.set_free(&var),
+            }
+        }
+        Ok(problem)
+    }
+}
+
+// --- Secciones ---
+
+enum SectionKind {
+    Objective(OptimizationDirection),
+    Constraints,
+    Bounds,
+    End,
+}
+
+struct Section {
+    kind: SectionKind,
+    /// Línea (1-based) donde empieza la sección.
+    line: usize,
+    /// Texto de la sección. Se conserva la numeración de líneas con líneas vacías.
+    body: String,
+}
+
+fn parse_error(line: usize, message: &str) -> LinearOptimizationError {
+    LinearOptimizationError::ParseError { line, message: message.to_string() }
+}
+
+/// Reconoce un encabezado al principio de la línea y devuelve el resto de la línea.
+fn section_header(line: &str) -> Option<(SectionKind, &str)> {
+    let lower = line.to_lowercase();
+    let headers: [(&str, fn() -> SectionKind); 13] = [
+        ("maximize", || SectionKind::Objective(OptimizationDirection::Maximize)),
+        ("maximum", || SectionKind::Objective(OptimizationDirection::Maximize)),
+        ("max", || SectionKind::Objective(OptimizationDirection::Maximize)),
+        ("minimize", || SectionKind::Objective(OptimizationDirection::Minimize)),
+        ("minimum", || SectionKind::Objective(OptimizationDirection::Minimize)),
+        ("min", || SectionKind::Objective(OptimizationDirection::Minimize)),
+        ("subject to", || SectionKind::Constraints),
+        ("such that", || SectionKind::Constraints),
+        ("s.t.", || SectionKind::Constraints),
+        ("st", || SectionKind::Constraints),
+        ("bounds", || SectionKind::Bounds),
+        ("bound", || SectionKind::Bounds),
+        ("end", || SectionKind::End),
+    ];
+
+    for (word, kind) in headers {
+        if let Some(rest) = lower.strip_prefix(word) {
+            // El encabezado debe ser una palabra completa ("min" no debe capturar "minutos: ...")
+            if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+                return Some((kind(), &line[word.len()..]));
+            }
+        }
+    }
+    None
+}
+
+fn split_sections(text: &str) -> Result<Vec<Section>, LinearOptimizationError> {
+    let mut sections: Vec<Section> = Vec::new();
+
+    for (idx, raw) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        // Los comentarios empiezan con '\' y llegan hasta el fin de línea
+        let line = raw.split('\\').next().unwrap_or("").trim();
+
+        if let Some((kind, rest)) = section_header(line) {
+            let unsupported = matches!(kind, SectionKind::End) && !rest.trim().is_empty();
+            if unsupported {
+                return Err(parse_error(line_no, "Texto inesperado después de End"));
+            }
+            let body = "\n".repeat(line_no - 1) + rest;
+            sections.push(Section { kind, line: line_no, body });
+            continue;
+        }
+
+        let lower = line.to_lowercase();
+        if ["general", "generals", "gen", "integer", "binary", "binaries", "bin", "sos"].contains(&lower.as_str()) {
+            return Err(parse_error(line_no, &format!("Sección '{}' no soportada", line)));
+        }
+
+        match sections.last_mut() {
+            Some(section) => {
+                if matches!(section.kind, SectionKind::End) && !line.is_empty() {
+                    return Err(parse_error(line_no, "Texto inesperado después de End"));
+                }
+                // Rellenamos con saltos para que el tokenizador conozca la línea real
+                let current = section.body.matches('\n').count() + 1;
+                section.body.push_str(&"\n".repeat(line_no - current));
+                section.body.push_str(line);
+            },
+            None if line.is_empty() => {},
+            None => return Err(parse_error(line_no, "Se esperaba Maximize o Minimize")),
+        }
+    }
+
+    Ok(sections)
+}
+
+// --- Tokenizador ---
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Colon,
+    Rel(Relation),
+}
+
+fn tokenize(body: &str) -> Result<Vec<(Token, usize)>, LinearOptimizationError> {
+    let mut tokens = Vec::new();
+
+    for (idx, line) in body.lines().enumerate() {
+        let line_no = idx + 1;
+        let chars: Vec<char> = line.chars().collect();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            match c {
+                ' ' | '\t' => { i += 1; },
+                '+' => { tokens.push((Token::Plus, line_no)); i += 1; },
+                '-' => { tokens.push((Token::Minus, line_no)); i += 1; },
+                '*' => { tokens.push((Token::Star, line_no)); i += 1; },
+                ':' => { tokens.push((Token::Colon, line_no)); i += 1; },
+                '<' | '>' | '=' => {
+                    let next = chars.get(i + 1).copied();
+                    let (rel, len) = match (c, next) {
+                        ('<', Some('=')) | ('=', Some('<')) => (Relation::LessOrEqual, 2),
+                        ('>', Some('=')) | ('=', Some('>')) => (Relation::GreaterOrEqual, 2),
+                        ('<', _) => (Relation::LessOrEqual, 1),
+                        ('>', _) => (Relation::GreaterOrEqual, 1),
+                        _ => (Relation::Equal, 1),
+                    };
+                    tokens.push((Token::Rel(rel), line_no));
+                    i += len;
+                },
+                d if d.is_ascii_digit() || d == '.' => {
+                    let start = i;
+                    while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                        i += 1;
+                    }
+                    // Exponente solo si le sigue un dígito (así "2e" no se come la variable "e")
+                    if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                        let mut j = i + 1;
+                        if j < chars.len() && (chars[j] == '+' || chars[j] == '-') { j += 1; }
+                        if j < chars.len() && chars[j].is_ascii_digit() {
+                            i = j;
+                            while i < chars.len() && chars[i].is_ascii_digit() { i += 1; }
+                        }
+                    }
+                    let text: String = chars[start..i].iter().collect();
+                    let value = text.parse::<f64>()
+                        .map_err(|_| parse_error(line_no, &format!("Número inválido '{}'", text)))?;
+                    tokens.push((Token::Number(value), line_no));
+                },
+                a if a.is_alphabetic() || a == '_' => {
+                    let start = i;
+                    while i < chars.len() && (chars[i].is_alphanumeric() || "_.[]".contains(chars[i])) {
+                        i += 1;
+                    }
+                    tokens.push((Token::Ident(chars[start..i].iter().collect()), line_no));
+                },
+                other => return Err(parse_error(line_no, &format!("Carácter inesperado '{}'", other))),
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+// --- Parser ---
+
+enum Bound {
+    Lower(String, f64),
+    Upper(String, f64),
+    Fixed(String, f64),
+    Range(String, f64, f64),
+    Free(String),
+}
+
+struct TokenParser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl TokenParser {
+    fn new(tokens: Vec<(Token, usize)>) -> Self {
+        Self { tokens, pos: 0 }
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset).map(|(t, _)| t)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens.get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map(|(_, l)| *l)
+            .unwrap_or(1)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn error(&self, message: &str) -> LinearOptimizationError {
+        parse_error(self.line(), message)
+    }
+
+    fn expect_end(&self) -> Result<(), LinearOptimizationError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(t) => Err(self.error(&format!("Token inesperado {:?}", t))),
+        }
+    }
+
+    /// Consume una etiqueta `nombre:` si la hay.
+    fn skip_label(&mut self) -> Option<String> {
+        if let (Some(Token::Ident(name)), Some(Token::Colon)) = (self.peek(), self.peek_at(1)) {
+            let name = name.clone();
+            self.pos += 2;
+            return Some(name);
+        }
+        None
+    }
+
+    /// Número con signo opcional. Acepta `inf` / `infinity`.
+    fn parse_signed_number(&mut self) -> Result<f64, LinearOptimizationError> {
+        let mut sign = 1.0;
+        while let Some(Token::Plus | Token::Minus) = self.peek() {
+            if self.next() == Some(Token::Minus) { sign = -sign; }
+        }
+        match self.next() {
+            Some(Token::Number(v)) => Ok(sign * v),
+            Some(Token::Ident(ref s)) if is_infinity(s) => Ok(sign * f64::INFINITY),
+            other => Err(self.error(&format!("Se esperaba un número, se encontró {:?}", other))),
+        }
+    }
+
+    /// Expresión lineal: términos `[+|-] [coef] [*] var` o constantes, hasta una relación o el final.
+    fn parse_expression(&mut self) -> Result<LinearExpression, LinearOptimizationError> {
+        let mut expr = LinearExpression::new();
+        let mut first = true;
+
+        while let Some(token) = self.peek() {
+            if matches!(token, Token::Rel(_)) {
+                break;
+            }
+            // Una etiqueta marca el inicio de la siguiente restricción
+            if !first && matches!((token, self.peek_at(1)), (Token::Ident(_), Some(Token::Colon))) {
+                break;
+            }
+
+            let mut sign = 1.0;
+            let mut has_sign = false;
+            while let Some(Token::Plus | Token::Minus) = self.peek() {
+                if self.next() == Some(Token::Minus) { sign = -sign; }
+                has_sign = true;
+            }
+            if !first && !has_sign {
+                return Err(self.error("Falta un operador entre términos"));
+            }
+
+            let mut coeff = sign;
+            let mut had_number = false;
+            if let Some(Token::Number(v)) = self.peek() {
+                coeff *= *v;
+                had_number = true;
+                self.pos += 1;
+                if let Some(Token::Star) = self.peek() {
+                    self.pos += 1;
+                }
+            }
+
+            match self.peek() {
+                Some(Token::Ident(name)) if !is_infinity(name) => {
+                    let name = name.clone();
+                    self.pos += 1;
+                    expr.add_term(&name, coeff);
+                },
+                _ if had_number => expr.constant += coeff,
+                other => return Err(self.error(&format!("Término inválido: {:?}", other))),
+            }
+            first = false;
+        }
+
+        Ok(expr)
+    }
+
+    /// `[nombre:] expresión relación número`
+    fn parse_constraint(&mut self) -> Result<Constraint, LinearOptimizationError> {
+        let name = self.skip_label();
+        let mut lhs = self.parse_expression()?;
+
+        let relation = match self.next() {
+            Some(Token::Rel(r)) => r,
+            other => return Err(self.error(&format!("Se esperaba <=, >= o =, se encontró {:?}", other))),
+        };
+        // La constante del LHS pasa al RHS
+        let rhs = self.parse_signed_number()? - lhs.constant;
+        lhs.set_constant(0.0);
+
+        let constraint = Constraint::new(lhs, relation, rhs);
+        Ok(match name {
+            Some(n) => constraint.with_name(&n),
+            None => constraint,
+        })
+    }
+
+    /// `var free`, `var rel num`, `num rel var` o `num rel var rel num`.
+    fn parse_bound(&mut self) -> Result<Bound, LinearOptimizationError> {
+        if let Some(Token::Ident(name)) = self.peek() {
+            if !is_infinity(name) {
+                let var = name.clone();
+                self.pos += 1;
+                return match self.next() {
+                    Some(Token::Ident(ref w)) if w.eq_ignore_ascii_case("free") => Ok(Bound::Free(var)),
+                    Some(Token::Rel(rel)) => {
+                        let value = self.parse_signed_number()?;
+                        Ok(match rel {
+                            Relation::LessOrEqual => Bound::Upper(var, value),
+                            Relation::GreaterOrEqual => Bound::Lower(var, value),
+                            Relation::Equal => Bound::Fixed(var, value),
+                        })
+                    },
+                    other => Err(self.error(&format!("Cota inválida para '{}': {:?}", var, other))),
+                };
+            }
+        }
+
+        // num rel var [rel num]
+        let value = self.parse_signed_number()?;
+        let rel = match self.next() {
+            Some(Token::Rel(r)) => r,
+            other => return Err(self.error(&format!("Se esperaba una relación, se encontró {:?}", other))),
+        };
+        let var = match self.next() {
+            Some(Token::Ident(v)) => v,
+            other => return Err(self.error(&format!("Se esperaba una variable, se encontró {:?}", other))),
+        };
+        let first = match rel {
+            Relation::LessOrEqual => Bound::Lower(var, value),
+            Relation::GreaterOrEqual => Bound::Upper(var, value),
+            Relation::Equal => Bound::Fixed(var, value),
+        };
+
+        if let Some(Token::Rel(second)) = self.peek().cloned() {
+            self.pos += 1;
+            let upper = self.parse_signed_number()?;
+            return match (first, second) {
+                (Bound::Lower(var, lower), Relation::LessOrEqual) => Ok(Bound::Range(var, lower, upper)),
+                _ => Err(self.error("Una doble cota debe tener la forma 'l <= x <= u'")),
+            };
+        }
+
+        Ok(first)
+    }
+}
+
+fn is_infinity(s: &str) -> bool {
+    s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("infinity")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimization::linear::algorithms::simplex::solve_primal;
+
+    const MIX_LP: &str = r"
+\ Problema de mezcla de productos
+Maximize
+ obj: 3 x + 2 y
+Subject To
+ madera: 2 x + y <= 100
+ horas:  x + y
+         <= 80
+Bounds
+ x <= 40
+End
+";
+
+    #[test]
+    fn test_parse_and_solve_lp() {
+        let problem = LinearProblem::from_lp_string(MIX_LP).unwrap();
+
+        assert_eq!(problem.objective.direction, OptimizationDirection::Maximize);
+        assert_eq!(problem.constraints.len(), 2);
+        assert_eq!(problem.constraints[1].name.as_deref(), Some("horas"));
+        assert_eq!(problem.constraints[1].rhs, 80.0);
+        assert_eq!(problem.bounds_of("x").upper, 40.0);
+
+        let solution = solve_primal(&problem).unwrap();
+        assert!((solution.objective_value - 180.0).abs() < 1e-6);
+        assert!((solution.variables["x"] - 20.0).abs() < 1e-6);
+        assert!((solution.variables["y"] - 60.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_parse_bounds_and_constants() {
+        let text = "min\n 2x - 3.5 y + 10\nst\n c1: x + y >= 1\n x - y + 2 = 0\nbounds\n -inf <= x <= 5\n1 <= y <= 1e1\n z free\n w = 3\nend";
+        let problem = LinearProblem::from_lp_string(text).unwrap();
+
+        assert_eq!(problem.objective.direction, OptimizationDirection::Minimize);
+        assert_eq!(problem.objective.expression.coefficients["y"], -3.5);
+        assert_eq!(problem.objective.expression.constant, 10.0);
+        // La constante del LHS se mueve al RHS
+        assert_eq!(problem.constraints[1].rhs, -2.0);
+        assert!(problem.constraints[1].name.is_none());
+
+        assert_eq!(problem.bounds_of("x").lower, f64::NEG_INFINITY);
+        assert_eq!(problem.bounds_of("x").upper, 5.0);
+        assert_eq!(problem.bounds_of("y").upper, 10.0);
+        assert!(problem.bounds_of("z").is_free());
+        assert_eq!(problem.bounds_of("w").lower, 3.0);
+    }
+
+    #[test]
+    fn test_parse_errors_report_line() {
+        let text = "maximize\n x + y\nsubject to\n x + y 10\n";
+        match LinearProblem::from_lp_string(text) {
+            Err(LinearOptimizationError::ParseError { line, .. }) => assert_eq!(line, 4),
+            other => panic!("Se esperaba ParseError, se obtuvo {:?}", other),
+        }
+
+        assert!(LinearProblem::from_lp_string("subject to\n x <= 1").is_err());
+        assert!(LinearProblem::from_lp_string("max\n x\ngeneral\n x\nend").is_err());
+    }
+}
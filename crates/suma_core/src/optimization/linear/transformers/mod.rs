@@ -1,2 +1,3 @@
 pub mod from_symbolics;
-pub mod standard_form;
\ No newline at end of file
+pub mod standard_form;
+pub mod from_lp;
\ No newline at end of file
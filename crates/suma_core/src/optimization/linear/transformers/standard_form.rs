@@ -13,6 +13,20 @@ pub struct StandardFormResult {
     
     // Mapa para rastrear qué columna corresponde a la holgura de qué restricción
     pub constraint_col_map: HashMap<String, usize>,
+
+    /// Desplazamiento de cada variable de decisión, por columna (`x = offset + sign * x'`).
+    pub var_offsets: Vec<f64>,
+    /// Signo de la sustitución: `-1.0` para variables con solo cota superior (`x = upper - x'`).
+    pub var_signs: Vec<f64>,
+    /// Variables libres divididas como `x = x+ - x-`: pares (columna x+, columna x-).
+    pub free_splits: Vec<(usize, usize)>,
+    /// Término constante del objetivo original: su constante más el aporte de las sustituciones de cotas.
+    pub objective_offset: f64,
+    /// `-1.0` en las filas que se multiplicaron por -1 para dejar el RHS no negativo.
+    pub row_signs: Vec<f64>,
+    /// Columna que forma la identidad inicial en cada fila (holgura o artificial).
+    /// En el tableau final contiene la columna correspondiente de B⁻¹.
+    pub row_identity_cols: Vec<usize>,
 }
 
 pub fn to_standard_form(problem: &LinearProblem) -> Result<StandardFormResult, LinearOptimizationError> {
@@ -22,12 +36,82 @@ pub fn to_standard_form(problem: &LinearProblem) -> Result<StandardFormResult, L
     let num_decision_vars = vars.len();
     let num_constraints = problem.constraints.len();
 
+    // 1.b Cotas. Cada variable se reescribe como x = offset + sign * x', con x' >= 0:
+    //   - lower finito:          x = lower + x',  0 <= x' <= upper - lower
+    //   - solo upper finito:     x = upper - x'
+    //   - libre (-inf, inf):     x = x+ - x-  (columna extra para x-)
+    let mut var_offsets = Vec::with_capacity(num_decision_vars);
+    let mut var_signs = Vec::with_capacity(num_decision_vars);
+    let mut shifted_uppers = Vec::with_capacity(num_decision_vars);
+    let mut free_vars = Vec::new();
+    for (i, name) in vars.iter().enumerate() {
+        let b = problem.bounds_of(name);
+        if b.lower.is_nan() || b.upper.is_nan() || b.lower == f64::INFINITY || b.upper == f64::NEG_INFINITY {
+            return Err(LinearOptimizationError::ValidationError(
+                format!("Cotas inválidas para '{}': [{}, {}]", name, b.lower, b.upper)
+            ));
+        }
+        if b.upper < b.lower {
+            return Err(LinearOptimizationError::ValidationError(
+                format!("Cotas inconsistentes para '{}': {} > {}", name, b.lower, b.upper)
+            ));
+        }
+
+        if b.lower.is_finite() {
+            var_offsets.push(b.lower);
+            var_signs.push(1.0);
+            shifted_uppers.push(b.upper - b.lower);
+        } else if b.upper.is_finite() {
+            var_offsets.push(b.upper);
+            var_signs.push(-1.0);
+            shifted_uppers.push(f64::INFINITY);
+        } else {
+            var_offsets.push(0.0);
+            var_signs.push(1.0);
+            shifted_uppers.push(f64::INFINITY);
+            free_vars.push(i);
+        }
+    }
+    let num_structural = num_decision_vars + free_vars.len();
+    let free_splits: Vec<(usize, usize)> = free_vars.iter()
+        .enumerate()
+        .map(|(k, &col)| (col, num_decision_vars + k))
+        .collect();
+
+    // RHS ajustado por la sustitución. Si queda negativo, la fila se multiplica
+    // por -1 (y se invierte la relación) para que la base inicial sea factible.
+    let mut row_signs = Vec::with_capacity(num_constraints);
+    let mut row_relations = Vec::with_capacity(num_constraints);
+    let mut row_rhs = Vec::with_capacity(num_constraints);
+    for c in &problem.constraints {
+        let mut rhs = c.rhs;
+        for (i, name) in vars.iter().enumerate() {
+            if let Some(coeff) = c.lhs.coefficients.get(name) {
+                rhs -= coeff * var_offsets[i];
+            }
+        }
+        if rhs < 0.0 {
+            let flipped = match c.relation {
+                Relation::LessOrEqual => Relation::GreaterOrEqual,
+                Relation::GreaterOrEqual => Relation::LessOrEqual,
+                Relation::Equal => Relation::Equal,
+            };
+            row_signs.push(-1.0);
+            row_relations.push(flipped);
+            row_rhs.push(-rhs);
+        } else {
+            row_signs.push(1.0);
+            row_relations.push(c.relation);
+            row_rhs.push(rhs);
+        }
+    }
+
     // 2. Contar variables auxiliares
     let mut num_slack = 0;
     let mut num_artificial = 0;
 
-    for c in &problem.constraints {
-        match c.relation {
+    for relation in &row_relations {
+        match relation {
             Relation::LessOrEqual => num_slack += 1,
             Relation::GreaterOrEqual => {
                 num_slack += 1;
@@ -37,7 +121,7 @@ pub fn to_standard_form(problem: &LinearProblem) -> Result<StandardFormResult, L
         }
     }
 
-    let num_total_vars = num_decision_vars + num_slack + num_artificial;
+    let num_total_vars = num_structural + num_slack + num_artificial;
     let rows = num_constraints + 1;
     let cols = num_total_vars + 1;
 
@@ -48,31 +132,39 @@ pub fn to_standard_form(problem: &LinearProblem) -> Result<StandardFormResult, L
         var_map.insert(name.clone(), i);
         reverse_map.insert(i, name.clone());
     }
+    for &(pos_col, neg_col) in &free_splits {
+        reverse_map.insert(neg_col, format!("_neg_{}", vars[pos_col]));
+    }
 
     // 4. Inicializar Matriz
     let mut matrix = zeros!(rows, cols);
     let mut basic_vars = vec![0; num_constraints];
     let mut artificial_indices = Vec::new();
     let mut constraint_col_map = HashMap::new(); // Nuevo mapa
+    let mut row_identity_cols = Vec::with_capacity(num_constraints);
 
-    let mut current_slack_col = num_decision_vars;
-    let mut current_artificial_col = num_decision_vars + num_slack;
+    let mut current_slack_col = num_structural;
+    let mut current_artificial_col = num_structural + num_slack;
 
     // 5. Llenar Restricciones
     for (row_idx, constraint) in problem.constraints.iter().enumerate() {
         // A) Coeficientes decisión
         for (var_name, coeff) in &constraint.lhs.coefficients {
             if let Some(&col_idx) = var_map.get(var_name) {
-                matrix.set(row_idx, col_idx, *coeff);
+                matrix.set(row_idx, col_idx, *coeff * var_signs[col_idx] * row_signs[row_idx]);
             }
         }
+        for &(pos_col, neg_col) in &free_splits {
+            matrix.set(row_idx, neg_col, -matrix.get(row_idx, pos_col));
+        }
 
         // B) Vars Auxiliares y Mapeo para Shadow Prices
-        match constraint.relation {
+        match row_relations[row_idx] {
             Relation::LessOrEqual => {
                 matrix.set(row_idx, current_slack_col, 1.0);
                 reverse_map.insert(current_slack_col, format!("_s_{}", row_idx));
                 basic_vars[row_idx] = current_slack_col;
+                row_identity_cols.push(current_slack_col);
                 
                 if let Some(name) = &constraint.name {
                     constraint_col_map.insert(name.clone(), current_slack_col);
@@ -94,6 +186,7 @@ pub fn to_standard_form(problem: &LinearProblem) -> Result<StandardFormResult, L
                 reverse_map.insert(current_artificial_col, format!("_art_{}", row_idx));
                 artificial_indices.push(current_artificial_col);
                 basic_vars[row_idx] = current_artificial_col;
+                row_identity_cols.push(current_artificial_col);
                 current_artificial_col += 1;
             },
             Relation::Equal => {
@@ -101,23 +194,30 @@ pub fn to_standard_form(problem: &LinearProblem) -> Result<StandardFormResult, L
                 reverse_map.insert(current_artificial_col, format!("_art_{}", row_idx));
                 artificial_indices.push(current_artificial_col);
                 basic_vars[row_idx] = current_artificial_col;
+                row_identity_cols.push(current_artificial_col);
                 current_artificial_col += 1;
             }
         }
         // C) RHS
-        matrix.set(row_idx, cols - 1, constraint.rhs);
+        matrix.set(row_idx, cols - 1, row_rhs[row_idx]);
     }
 
     // 6. Construir Funciones Objetivo
     let mut original_objective_row = vec![0.0; cols]; 
     let is_minimization = problem.objective.direction == OptimizationDirection::Minimize;
+    // La constante no afecta al óptimo, pero sí al valor reportado
+    let mut objective_offset = problem.objective.expression.constant;
 
     for (var_name, coeff) in &problem.objective.expression.coefficients {
         if let Some(&col_idx) = var_map.get(var_name) {
             let val = if is_minimization { *coeff } else { -*coeff };
-            original_objective_row[col_idx] = val;
+            original_objective_row[col_idx] = val * var_signs[col_idx];
+            objective_offset += coeff * var_offsets[col_idx];
         }
     }
+    for &(pos_col, neg_col) in &free_splits {
+        original_objective_row[neg_col] = -original_objective_row[pos_col];
+    }
 
     // Configurar fila Z
     let z_row_idx = rows - 1;
@@ -147,13 +247,22 @@ pub fn to_standard_form(problem: &LinearProblem) -> Result<StandardFormResult, L
         .filter(|v| !basic_vars.contains(v))
         .collect();
 
+    let mut tableau = SimplexTableau::new(matrix, basic_vars, non_basic_vars);
+    tableau.upper_bounds[..num_decision_vars].copy_from_slice(&shifted_uppers);
+
     Ok(StandardFormResult {
-        tableau: SimplexTableau { matrix, basic_vars, non_basic_vars },
+        tableau,
         var_map,
         reverse_map,
         artificial_indices,
         original_objective_row,
         constraint_col_map,
+        var_offsets,
+        var_signs,
+        free_splits,
+        objective_offset,
+        row_signs,
+        row_identity_cols,
     })
 }
 #[cfg(test)]
@@ -238,4 +347,63 @@ mod tests {
         assert_eq!(matrix.get(1, sur1_idx), -1.0);
         assert_eq!(matrix.get(1, art1_idx), 1.0);
     }
+
+    #[test]
+    fn test_lower_bound_shifts_rhs_and_flips_negative_rows() {
+        // x >= 4 como cota; x - y <= 1  =>  x' - y <= -3  =>  -x' + y >= 3
+        let objective = Objective::minimize(quick_expr(vec![("x", 2.0), ("y", 1.0)]));
+        let mut problem = LinearProblem::new("Shift", objective);
+        problem.add_constraint(Constraint::new(quick_expr(vec![("x", 1.0), ("y", -1.0)]), Relation::LessOrEqual, 1.0));
+        problem.set_bounds("x", 4.0, 10.0);
+
+        let res = to_standard_form(&problem).unwrap();
+        let x_idx = *res.var_map.get("x").unwrap();
+        let y_idx = *res.var_map.get("y").unwrap();
+
+        assert_eq!(res.var_offsets[x_idx], 4.0);
+        assert_eq!(res.tableau.upper_bounds[x_idx], 6.0);
+        assert_eq!(res.tableau.upper_bounds[y_idx], f64::INFINITY);
+        assert_eq!(res.objective_offset, 8.0);
+
+        let rhs_col = res.tableau.matrix.cols - 1;
+        assert_eq!(res.tableau.matrix.get(0, rhs_col), 3.0);
+        assert_eq!(res.tableau.matrix.get(0, x_idx), -1.0);
+        assert_eq!(res.artificial_indices.len(), 1, "La fila invertida pasa a ser >=");
+    }
+
+    #[test]
+    fn test_objective_constant_goes_to_offset() {
+        let mut expr = quick_expr(vec![("x", 3.0)]);
+        expr.set_constant(100.0);
+        let problem = LinearProblem::new("Const", Objective::maximize(expr));
+
+        let res = to_standard_form(&problem).unwrap();
+        assert_eq!(res.objective_offset, 100.0);
+    }
+
+    #[test]
+    fn test_free_variable_is_split() {
+        let objective = Objective::minimize(quick_expr(vec![("x", 1.0), ("y", 2.0)]));
+        let mut problem = LinearProblem::new("Free", objective);
+        problem.add_constraint(Constraint::new(quick_expr(vec![("x", 3.0), ("y", 1.0)]), Relation::LessOrEqual, 6.0));
+        problem.set_free("x");
+
+        let res = to_standard_form(&problem).unwrap();
+        let x_idx = *res.var_map.get("x").unwrap();
+        assert_eq!(res.free_splits.len(), 1);
+        let (pos, neg) = res.free_splits[0];
+        assert_eq!(pos, x_idx);
+        assert_eq!(res.reverse_map[&neg], "_neg_x");
+        assert_eq!(res.tableau.matrix.get(0, neg), -3.0);
+        assert_eq!(res.original_objective_row[neg], -1.0);
+    }
+
+    #[test]
+    fn test_inconsistent_bounds_are_rejected() {
+        let objective = Objective::maximize(quick_expr(vec![("x", 1.0)]));
+        let mut problem = LinearProblem::new("BadBounds", objective);
+        problem.set_bounds("x", 5.0, 1.0);
+
+        assert!(matches!(to_standard_form(&problem), Err(LinearOptimizationError::ValidationError(_))));
+    }
 }
\ No newline at end of file
@@ -2,5 +2,7 @@
 pub mod linear;
 // pub mod nonlinear; // Futura expansión
 pub mod integer; 
+pub mod transportation;
+pub mod assignment;
 
 pub mod error;
\ No newline at end of file
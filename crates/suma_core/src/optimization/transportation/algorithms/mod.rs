@@ -0,0 +1 @@
+pub mod modi;
\ No newline at end of file
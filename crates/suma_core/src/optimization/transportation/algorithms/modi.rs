@@ -0,0 +1,380 @@
+use std::collections::VecDeque;
+use crate::optimization::transportation::error::TransportationError;
+use crate::optimization::transportation::problem::{DummyNode, TransportationProblem, TransportationSolution};
+
+const EPSILON: f64 = 1e-9;
+const MAX_ITERATIONS: usize = 10_000;
+
+/// Método para construir la solución básica factible inicial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InitialMethod {
+    /// Esquina noroeste: ignora los costos, solo recorre la tabla.
+    NorthwestCorner,
+    /// Aproximación de Vogel: asigna primero donde no hacerlo penalizaría más.
+    /// Suele quedar muy cerca del óptimo.
+    #[default]
+    Vogel,
+}
+
+/// Base del método: asignaciones y celdas básicas (m + n - 1, incluidas las
+/// degeneradas con cantidad cero, para que formen un árbol).
+struct Basis {
+    allocation: Vec<Vec<f64>>,
+    basic: Vec<Vec<bool>>,
+}
+
+impl TransportationProblem {
+    /// Resuelve con Vogel como solución inicial y MODI hasta el óptimo.
+    pub fn solve(&self) -> Result<TransportationSolution, TransportationError> {
+        self.solve_with(InitialMethod::default())
+    }
+
+    /// Resuelve partiendo de la solución inicial indicada. Si la oferta y la
+    /// demanda no coinciden, agrega un origen o destino ficticio con costo cero.
+    pub fn solve_with(&self, method: InitialMethod) -> Result<TransportationSolution, TransportationError> {
+        self.validate()?;
+        let (balanced, dummy) = self.balanced();
+
+        let mut basis = match method {
+            InitialMethod::NorthwestCorner => northwest_corner(&balanced),
+            InitialMethod::Vogel => vogel(&balanced),
+        };
+        let iterations = modi(&balanced.costs, &mut basis)?;
+
+        let total_cost = basis.allocation.iter().zip(&balanced.costs)
+            .flat_map(|(alloc_row, cost_row)| alloc_row.iter().zip(cost_row))
+            .map(|(q, c)| q * c)
+            .sum();
+
+        Ok(TransportationSolution { allocation: basis.allocation, total_cost, dummy, iterations })
+    }
+
+    // Copia balanceada del problema (con la fila o columna ficticia si hace falta)
+    fn balanced(&self) -> (TransportationProblem, Option<DummyNode>) {
+        let mut balanced = self.clone();
+        let supply: f64 = self.supply.iter().sum();
+        let demand: f64 = self.demand.iter().sum();
+
+        if supply - demand > EPSILON {
+            balanced.demand.push(supply - demand);
+            for row in &mut balanced.costs {
+                row.push(0.0);
+            }
+            (balanced, Some(DummyNode::Destination))
+        } else if demand - supply > EPSILON {
+            balanced.supply.push(demand - supply);
+            balanced.costs.push(vec![0.0; self.demand.len()]);
+            (balanced, Some(DummyNode::Source))
+        } else {
+            (balanced, None)
+        }
+    }
+}
+
+fn empty_basis(rows: usize, cols: usize) -> Basis {
+    Basis {
+        allocation: vec![vec![0.0; cols]; rows],
+        basic: vec![vec![false; cols]; rows],
+    }
+}
+
+/// Esquina noroeste. Si una fila y una columna se agotan a la vez solo se avanza
+/// de fila: la celda siguiente entra a la base con cero (degenerada).
+fn northwest_corner(problem: &TransportationProblem) -> Basis {
+    let (m, n) = (problem.supply.len(), problem.demand.len());
+    let mut supply = problem.supply.clone();
+    let mut demand = problem.demand.clone();
+    let mut basis = empty_basis(m, n);
+
+    let (mut i, mut j) = (0, 0);
+    loop {
+        let quantity = supply[i].min(demand[j]);
+        basis.allocation[i][j] = quantity;
+        basis.basic[i][j] = true;
+        supply[i] -= quantity;
+        demand[j] -= quantity;
+
+        if i == m - 1 && j == n - 1 {
+            break;
+        }
+        if (supply[i] <= EPSILON && i < m - 1) || j == n - 1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    basis
+}
+
+/// Aproximación de Vogel. Cada paso tacha una sola línea (fila o columna), así la
+/// base termina con exactamente m + n - 1 celdas aunque haya degeneración.
+fn vogel(problem: &TransportationProblem) -> Basis {
+    let (m, n) = (problem.supply.len(), problem.demand.len());
+    let costs = &problem.costs;
+    let mut supply = problem.supply.clone();
+    let mut demand = problem.demand.clone();
+    let mut basis = empty_basis(m, n);
+    let mut rows: Vec<usize> = (0..m).collect();
+    let mut cols: Vec<usize> = (0..n).collect();
+
+    loop {
+        // Queda una sola línea: todo lo pendiente va por ella
+        if rows.len() == 1 {
+            for &j in &cols {
+                assign(&mut basis, &mut supply, &mut demand, rows[0], j);
+            }
+            break;
+        }
+        if cols.len() == 1 {
+            for &i in &rows {
+                assign(&mut basis, &mut supply, &mut demand, i, cols[0]);
+            }
+            break;
+        }
+
+        let best_row = rows.iter()
+            .map(|&i| (penalty(cols.iter().map(|&j| costs[i][j]).collect()), i))
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+        let best_col = cols.iter()
+            .map(|&j| (penalty(rows.iter().map(|&i| costs[i][j]).collect()), j))
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+
+        let cheapest_in_row = |i: usize| *cols.iter().min_by(|&&a, &&b| costs[i][a].total_cmp(&costs[i][b])).unwrap();
+        let cheapest_in_col = |j: usize| *rows.iter().min_by(|&&a, &&b| costs[a][j].total_cmp(&costs[b][j])).unwrap();
+        let (i, j) = match (best_row, best_col) {
+            (Some((row_penalty, _)), Some((col_penalty, j))) if col_penalty > row_penalty => (cheapest_in_col(j), j),
+            (Some((_, i)), _) => (i, cheapest_in_row(i)),
+            _ => unreachable!("quedan al menos dos filas y dos columnas"),
+        };
+
+        assign(&mut basis, &mut supply, &mut demand, i, j);
+        if supply[i] <= EPSILON {
+            rows.retain(|&r| r != i);
+        } else {
+            cols.retain(|&c| c != j);
+        }
+    }
+    basis
+}
+
+fn assign(basis: &mut Basis, supply: &mut [f64], demand: &mut [f64], i: usize, j: usize) {
+    let quantity = supply[i].min(demand[j]);
+    basis.allocation[i][j] = quantity;
+    basis.basic[i][j] = true;
+    supply[i] -= quantity;
+    demand[j] -= quantity;
+}
+
+// Penalización de Vogel: diferencia entre los dos costos más bajos de la línea
+fn penalty(mut line: Vec<f64>) -> f64 {
+    line.sort_by(f64::total_cmp);
+    line[1] - line[0]
+}
+
+/// Método MODI (u-v): calcula los potenciales con las celdas básicas, busca la
+/// celda no básica con costo reducido más negativo y la hace entrar por su ciclo
+/// (stepping-stone). Devuelve el número de pivoteos.
+fn modi(costs: &[Vec<f64>], basis: &mut Basis) -> Result<usize, TransportationError> {
+    let (m, n) = (costs.len(), costs[0].len());
+
+    for iteration in 0..MAX_ITERATIONS {
+        let (u, v) = potentials(costs, &basis.basic);
+
+        // Costo reducido c_ij - u_i - v_j de las celdas no básicas
+        let entering = (0..m)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .filter(|&(i, j)| !basis.basic[i][j])
+            .map(|(i, j)| (costs[i][j] - u[i] - v[j], i, j))
+            .filter(|(reduced, _, _)| *reduced < -EPSILON)
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+
+        let Some((_, row, col)) = entering else {
+            return Ok(iteration);
+        };
+
+        // Ciclo: la celda entrante (+) y el camino del árbol de columna `col` a
+        // fila `row`, alternando - y + desde la columna.
+        let path = tree_path(&basis.basic, row, col);
+        let theta = path.iter().step_by(2)
+            .map(|&(i, j)| basis.allocation[i][j])
+            .fold(f64::INFINITY, f64::min);
+        let leaving = *path.iter().step_by(2)
+            .find(|&&(i, j)| basis.allocation[i][j] - theta <= EPSILON)
+            .expect("el ciclo siempre tiene una celda con el mínimo");
+
+        basis.allocation[row][col] += theta;
+        for (k, &(i, j)) in path.iter().enumerate() {
+            if k % 2 == 0 {
+                basis.allocation[i][j] -= theta;
+            } else {
+                basis.allocation[i][j] += theta;
+            }
+        }
+        basis.allocation[leaving.0][leaving.1] = 0.0;
+        basis.basic[leaving.0][leaving.1] = false;
+        basis.basic[row][col] = true;
+    }
+    Err(TransportationError::MaxIterationsReached)
+}
+
+// u_i + v_j = c_ij en cada celda básica, con u_0 = 0
+fn potentials(costs: &[Vec<f64>], basic: &[Vec<bool>]) -> (Vec<f64>, Vec<f64>) {
+    let (m, n) = (costs.len(), costs[0].len());
+    let mut u = vec![None; m];
+    let mut v = vec![None; n];
+    u[0] = Some(0.0);
+
+    // Nodos del árbol: filas (true) y columnas (false)
+    let mut queue = VecDeque::from([(true, 0)]);
+    while let Some((is_row, k)) = queue.pop_front() {
+        if is_row {
+            let ui = u[k].unwrap();
+            let reached: Vec<usize> = (0..n).filter(|&j| basic[k][j] && v[j].is_none()).collect();
+            for j in reached {
+                v[j] = Some(costs[k][j] - ui);
+                queue.push_back((false, j));
+            }
+        } else {
+            let vj = v[k].unwrap();
+            let reached: Vec<usize> = (0..m).filter(|&i| basic[i][k] && u[i].is_none()).collect();
+            for i in reached {
+                u[i] = Some(costs[i][k] - vj);
+                queue.push_back((true, i));
+            }
+        }
+    }
+
+    (u.into_iter().map(|x| x.unwrap_or(0.0)).collect(), v.into_iter().map(|x| x.unwrap_or(0.0)).collect())
+}
+
+// Celdas básicas del camino de la columna `col` a la fila `row` (en ese orden)
+fn tree_path(basic: &[Vec<bool>], row: usize, col: usize) -> Vec<(usize, usize)> {
+    let (m, n) = (basic.len(), basic[0].len());
+    // Índices de nodo: filas 0..m, columnas m..m+n
+    let mut parent: Vec<Option<usize>> = vec![None; m + n];
+    let mut visited = vec![false; m + n];
+    let start = m + col;
+    visited[start] = true;
+
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if node == row {
+            break;
+        }
+        let neighbors: Vec<usize> = if node < m {
+            (0..n).filter(|&j| basic[node][j]).map(|j| m + j).collect()
+        } else {
+            (0..m).filter(|&i| basic[i][node - m]).collect()
+        };
+        for next in neighbors {
+            if !visited[next] {
+                visited[next] = true;
+                parent[next] = Some(node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    // Reconstrucción desde la fila hacia la columna, luego se invierte
+    let mut path = Vec::new();
+    let mut node = row;
+    while let Some(prev) = parent[node] {
+        let cell = if node < m { (node, prev - m) } else { (prev, node - m) };
+        path.push(cell);
+        node = prev;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn textbook() -> TransportationProblem {
+        TransportationProblem::new(
+            vec![7.0, 9.0, 18.0],
+            vec![5.0, 8.0, 7.0, 14.0],
+            vec![
+                vec![19.0, 30.0, 50.0, 10.0],
+                vec![70.0, 30.0, 40.0, 60.0],
+                vec![40.0, 8.0, 70.0, 20.0],
+            ],
+        )
+    }
+
+    fn cost_of(problem: &TransportationProblem, basis: &Basis) -> f64 {
+        (0..problem.supply.len())
+            .flat_map(|i| (0..problem.demand.len()).map(move |j| (i, j)))
+            .map(|(i, j)| basis.allocation[i][j] * problem.costs[i][j])
+            .sum()
+    }
+
+    #[test]
+    fn test_initial_solutions() {
+        let problem = textbook();
+        let nw = northwest_corner(&problem);
+        let vam = vogel(&problem);
+
+        assert!((cost_of(&problem, &nw) - 1015.0).abs() < 1e-9);
+        assert!((cost_of(&problem, &vam) - 779.0).abs() < 1e-9);
+        // Base completa: m + n - 1 celdas
+        for basis in [&nw, &vam] {
+            assert_eq!(basis.basic.iter().flatten().filter(|&&b| b).count(), 6);
+        }
+    }
+
+    #[test]
+    fn test_modi_reaches_optimum_from_both_starts() {
+        let problem = textbook();
+        for method in [InitialMethod::NorthwestCorner, InitialMethod::Vogel] {
+            let solution = problem.solve_with(method).unwrap();
+            assert!((solution.total_cost - 743.0).abs() < 1e-9, "{:?}: {}", method, solution.total_cost);
+            assert_eq!(solution.dummy, None);
+
+            for (i, row) in solution.allocation.iter().enumerate() {
+                assert!((row.iter().sum::<f64>() - problem.supply[i]).abs() < 1e-9);
+            }
+            for j in 0..problem.demand.len() {
+                let shipped: f64 = solution.allocation.iter().map(|row| row[j]).sum();
+                assert!((shipped - problem.demand[j]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unbalanced_adds_dummy() {
+        // Sobran 25 unidades de oferta: destino ficticio
+        let problem = TransportationProblem::new(
+            vec![30.0, 20.0],
+            vec![10.0, 15.0],
+            vec![vec![1.0, 2.0], vec![3.0, 1.0]],
+        );
+        let solution = problem.solve().unwrap();
+        assert_eq!(solution.dummy, Some(DummyNode::Destination));
+        assert_eq!(solution.allocation[0].len(), 3);
+        assert!((solution.total_cost - 25.0).abs() < 1e-9);
+
+        // Falta oferta: origen ficticio
+        let short = TransportationProblem::new(vec![5.0], vec![4.0, 4.0], vec![vec![2.0, 1.0]]);
+        let solution = short.solve().unwrap();
+        assert_eq!(solution.dummy, Some(DummyNode::Source));
+        assert_eq!(solution.allocation.len(), 2);
+        assert!((solution.total_cost - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_invalid_shapes() {
+        let problem = TransportationProblem::new(vec![1.0], vec![1.0, 1.0], vec![vec![1.0]]);
+        assert_eq!(problem.solve(), Err(TransportationError::CostShape { rows: 1, cols: 2 }));
+
+        let negative = TransportationProblem::new(vec![-1.0], vec![1.0], vec![vec![1.0]]);
+        assert_eq!(negative.solve(), Err(TransportationError::InvalidQuantity));
+
+        let nan = TransportationProblem::new(vec![1.0, 1.0], vec![2.0], vec![vec![1.0], vec![f64::NAN]]);
+        assert_eq!(nan.solve(), Err(TransportationError::InvalidCost { row: 1, col: 0 }));
+
+        let infinite = TransportationProblem::new(vec![2.0], vec![1.0, 1.0], vec![vec![f64::INFINITY, 1.0]]);
+        assert_eq!(infinite.solve(), Err(TransportationError::InvalidCost { row: 0, col: 0 }));
+    }
+}
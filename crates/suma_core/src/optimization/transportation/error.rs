@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum TransportationError {
+    #[error("El problema de transporte necesita al menos un origen y un destino.")]
+    Empty,
+
+    #[error("La matriz de costos debe ser de {rows}x{cols} (orígenes x destinos).")]
+    CostShape { rows: usize, cols: usize },
+
+    #[error("La oferta y la demanda deben ser valores finitos no negativos.")]
+    InvalidQuantity,
+
+    #[error("El costo de ({row}, {col}) no es un valor finito.")]
+    InvalidCost { row: usize, col: usize },
+
+    #[error("Límite de iteraciones alcanzado.")]
+    MaxIterationsReached,
+}
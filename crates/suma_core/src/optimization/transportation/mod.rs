@@ -0,0 +1,7 @@
+pub mod algorithms;
+pub mod model;
+
+pub mod error;
+
+pub use model::*;
+pub use algorithms::*;
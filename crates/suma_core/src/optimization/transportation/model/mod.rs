@@ -0,0 +1 @@
+pub mod problem;
\ No newline at end of file
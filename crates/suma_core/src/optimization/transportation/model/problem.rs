@@ -0,0 +1,65 @@
+use crate::optimization::transportation::error::TransportationError;
+
+/// Problema de transporte: enviar `supply[i]` desde cada origen para cubrir
+/// `demand[j]` en cada destino, con costo unitario `costs[i][j]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransportationProblem {
+    pub supply: Vec<f64>,
+    pub demand: Vec<f64>,
+    pub costs: Vec<Vec<f64>>,
+}
+
+/// Fila o columna ficticia (costo cero) agregada para balancear el problema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DummyNode {
+    /// Origen ficticio (última fila): la demanda superaba a la oferta.
+    Source,
+    /// Destino ficticio (última columna): la oferta superaba a la demanda.
+    Destination,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransportationSolution {
+    /// Cantidad enviada de cada origen a cada destino. Incluye la fila o columna
+    /// ficticia si el problema estaba desbalanceado (ver `dummy`).
+    pub allocation: Vec<Vec<f64>>,
+    /// Costo total de los envíos reales (los ficticios cuestan cero).
+    pub total_cost: f64,
+    pub dummy: Option<DummyNode>,
+    /// Pivoteos del método MODI desde la solución inicial.
+    pub iterations: usize,
+}
+
+impl TransportationProblem {
+    pub fn new(supply: Vec<f64>, demand: Vec<f64>, costs: Vec<Vec<f64>>) -> Self {
+        Self { supply, demand, costs }
+    }
+
+    /// Indica si la oferta total coincide con la demanda total.
+    pub fn is_balanced(&self) -> bool {
+        let supply: f64 = self.supply.iter().sum();
+        let demand: f64 = self.demand.iter().sum();
+        (supply - demand).abs() < 1e-9
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), TransportationError> {
+        if self.supply.is_empty() || self.demand.is_empty() {
+            return Err(TransportationError::Empty);
+        }
+        let shape_ok = self.costs.len() == self.supply.len()
+            && self.costs.iter().all(|row| row.len() == self.demand.len());
+        if !shape_ok {
+            return Err(TransportationError::CostShape { rows: self.supply.len(), cols: self.demand.len() });
+        }
+        if self.supply.iter().chain(&self.demand).any(|q| !q.is_finite() || *q < 0.0) {
+            return Err(TransportationError::InvalidQuantity);
+        }
+        // Un costo NaN o infinito rompe las penalizaciones de Vogel y los potenciales u + v
+        for (row, costs) in self.costs.iter().enumerate() {
+            if let Some(col) = costs.iter().position(|c| !c.is_finite()) {
+                return Err(TransportationError::InvalidCost { row, col });
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::probability::bayes::BN_base::BayesianNetworkBase;
+use crate::probability::bayes::BayesianNetwork;
+use std::collections::{HashSet, VecDeque};
+
+/// Prueba de d-separación por alcanzabilidad ("Bayes-ball").
+///
+/// `a` y `b` son condicionalmente independientes dado `given` si no existe un
+/// camino activo entre ellos. Solo usa la estructura del grafo, no las CPTs.
+/// Un nodo observado está trivialmente separado de cualquier otro.
+pub fn is_d_separated(
+    network: &BayesianNetwork,
+    a: usize,
+    b: usize,
+    given: &HashSet<usize>,
+) -> bool {
+    if given.contains(&a) || given.contains(&b) {
+        return true;
+    }
+    if a == b {
+        return false;
+    }
+
+    // 1. Observados y sus ancestros: activan las v-estructuras (A → C ← B)
+    let mut observed_ancestors = HashSet::new();
+    let mut pending: Vec<usize> = given.iter().copied().collect();
+    while let Some(node) = pending.pop() {
+        if observed_ancestors.insert(node) {
+            pending.extend(network.get_parents(node));
+        }
+    }
+
+    // 2. Recorrido sobre (nodo, llegó desde un hijo). Desde un hijo ("subiendo")
+    //    el camino sigue a padres e hijos si el nodo no está observado; desde un
+    //    padre ("bajando") sigue a los hijos si no está observado, y a los padres
+    //    solo si es (ancestro de) evidencia.
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::from([(a, true)]);
+
+    while let Some((node, from_child)) = queue.pop_front() {
+        if !visited.insert((node, from_child)) {
+            continue;
+        }
+        let observed = given.contains(&node);
+        if node == b && !observed {
+            return false;
+        }
+
+        if from_child && !observed {
+            queue.extend(network.get_parents(node).into_iter().map(|p| (p, true)));
+            queue.extend(network.get_children(node).into_iter().map(|c| (c, false)));
+        } else if !from_child {
+            if !observed {
+                queue.extend(network.get_children(node).into_iter().map(|c| (c, false)));
+            }
+            if observed_ancestors.contains(&node) {
+                queue.extend(network.get_parents(node).into_iter().map(|p| (p, true)));
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binary_root(bn: &mut BayesianNetwork, name: &str) {
+        bn.add_binary_node(name, vec![], vec![(vec![], 0.5)]).unwrap();
+    }
+
+    fn binary_child(bn: &mut BayesianNetwork, name: &str, parent: &str) {
+        bn.add_binary_node(name, vec![parent], vec![(vec![true], 0.9), (vec![false], 0.1)]).unwrap();
+    }
+
+    #[test]
+    fn test_chain_and_fork() {
+        // Cadena A → B → C  y  bifurcación B → D
+        let mut bn = BayesianNetwork::new();
+        binary_root(&mut bn, "A");
+        binary_child(&mut bn, "B", "A");
+        binary_child(&mut bn, "C", "B");
+        binary_child(&mut bn, "D", "B");
+        let id = |name| bn.get_id_from_name(name).unwrap();
+
+        assert!(!is_d_separated(&bn, id("A"), id("C"), &HashSet::new()));
+        assert!(is_d_separated(&bn, id("A"), id("C"), &HashSet::from([id("B")])));
+        assert!(!is_d_separated(&bn, id("C"), id("D"), &HashSet::new()));
+        assert!(is_d_separated(&bn, id("C"), id("D"), &HashSet::from([id("B")])));
+    }
+
+    #[test]
+    fn test_v_structure_is_activated_by_descendant() {
+        // Rain → WetGrass ← Sprinkler,  WetGrass → Slippery
+        let mut bn = BayesianNetwork::new();
+        binary_root(&mut bn, "Rain");
+        binary_root(&mut bn, "Sprinkler");
+        bn.add_binary_node("WetGrass", vec!["Rain", "Sprinkler"], vec![
+            (vec![true, true], 0.99),
+            (vec![true, false], 0.8),
+            (vec![false, true], 0.9),
+            (vec![false, false], 0.0),
+        ]).unwrap();
+        binary_child(&mut bn, "Slippery", "WetGrass");
+        let id = |name| bn.get_id_from_name(name).unwrap();
+
+        // Causas independientes a priori...
+        assert!(is_d_separated(&bn, id("Rain"), id("Sprinkler"), &HashSet::new()));
+        // ...pero dependientes al observar el efecto o un descendiente (explaining away)
+        assert!(!is_d_separated(&bn, id("Rain"), id("Sprinkler"), &HashSet::from([id("WetGrass")])));
+        assert!(!is_d_separated(&bn, id("Rain"), id("Sprinkler"), &HashSet::from([id("Slippery")])));
+        // Observar el nodo intermedio bloquea la cadena Rain → WetGrass → Slippery
+        assert!(is_d_separated(&bn, id("Rain"), id("Slippery"), &HashSet::from([id("WetGrass")])));
+    }
+}
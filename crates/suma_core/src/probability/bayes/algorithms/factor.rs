@@ -0,0 +1,186 @@
+use crate::probability::bayes::BN_base::State;
+use std::collections::HashMap;
+
+/// Factor de probabilidad sobre un conjunto de variables discretas.
+/// Cada asignación se guarda como un vector de estados en el orden de `variables`.
+#[derive(Debug, Clone)]
+pub struct Factor {
+    pub variables: Vec<usize>,
+    pub domains: Vec<Vec<State>>,
+    pub values: HashMap<Vec<State>, f64>,
+}
+
+impl Factor {
+    pub fn new(variables: Vec<usize>, domains: Vec<Vec<State>>, values: HashMap<Vec<State>, f64>) -> Self {
+        Factor { variables, domains, values }
+    }
+
+    pub fn contains(&self, variable: usize) -> bool {
+        self.variables.contains(&variable)
+    }
+
+    /// Valor de una asignación completa; las ausentes valen 0.
+    pub fn value(&self, assignment: &[State]) -> f64 {
+        self.values.get(assignment).copied().unwrap_or(0.0)
+    }
+
+    /// Producto cartesiano de los dominios de las variables del factor.
+    pub fn assignments(&self) -> Vec<Vec<State>> {
+        cartesian_product(&self.domains)
+    }
+
+    /// Fija las variables observadas y las elimina del factor.
+    pub fn reduce(&self, evidence: &HashMap<usize, State>) -> Factor {
+        if !self.variables.iter().any(|v| evidence.contains_key(v)) {
+            return self.clone();
+        }
+
+        let kept: Vec<usize> = (0..self.variables.len())
+            .filter(|&i| !evidence.contains_key(&self.variables[i]))
+            .collect();
+
+        let mut values = HashMap::new();
+        for (assignment, value) in &self.values {
+            let consistent = self.variables.iter().enumerate().all(|(i, var)| {
+                evidence.get(var).map_or(true, |observed| &assignment[i] == observed)
+            });
+            if consistent {
+                let key: Vec<State> = kept.iter().map(|&i| assignment[i].clone()).collect();
+                values.insert(key, *value);
+            }
+        }
+
+        Factor {
+            variables: kept.iter().map(|&i| self.variables[i]).collect(),
+            domains: kept.iter().map(|&i| self.domains[i].clone()).collect(),
+            values,
+        }
+    }
+
+    /// Producto punto a punto de dos factores sobre la unión de sus variables.
+    pub fn product(&self, other: &Factor) -> Factor {
+        let mut variables = self.variables.clone();
+        let mut domains = self.domains.clone();
+        for (i, var) in other.variables.iter().enumerate() {
+            if !variables.contains(var) {
+                variables.push(*var);
+                domains.push(other.domains[i].clone());
+            }
+        }
+
+        let other_positions: Vec<usize> = other.variables
+            .iter()
+            .map(|var| variables.iter().position(|v| v == var).unwrap())
+            .collect();
+
+        let mut values = HashMap::new();
+        for assignment in cartesian_product(&domains) {
+            let left = self.value(&assignment[..self.variables.len()]);
+            if left == 0.0 {
+                continue;
+            }
+            let projected: Vec<State> = other_positions.iter().map(|&i| assignment[i].clone()).collect();
+            let right = other.value(&projected);
+            if right != 0.0 {
+                values.insert(assignment, left * right);
+            }
+        }
+
+        Factor { variables, domains, values }
+    }
+
+    /// Marginaliza (suma) una variable del factor.
+    pub fn sum_out(&self, variable: usize) -> Factor {
+        let Some(index) = self.variables.iter().position(|&v| v == variable) else {
+            return self.clone();
+        };
+
+        let mut values: HashMap<Vec<State>, f64> = HashMap::new();
+        for (assignment, value) in &self.values {
+            let mut key = assignment.clone();
+            key.remove(index);
+            *values.entry(key).or_insert(0.0) += value;
+        }
+
+        let mut variables = self.variables.clone();
+        let mut domains = self.domains.clone();
+        variables.remove(index);
+        domains.remove(index);
+
+        Factor { variables, domains, values }
+    }
+
+    /// Escala los valores para que sumen 1. Un factor nulo se deja tal cual.
+    pub fn normalize(&mut self) {
+        let total: f64 = self.values.values().sum();
+        if total > 0.0 {
+            for value in self.values.values_mut() {
+                *value /= total;
+            }
+        }
+    }
+}
+
+fn cartesian_product(domains: &[Vec<State>]) -> Vec<Vec<State>> {
+    let mut combinations: Vec<Vec<State>> = vec![vec![]];
+    for domain in domains {
+        combinations = combinations
+            .into_iter()
+            .flat_map(|prefix| {
+                domain.iter().map(move |state| {
+                    let mut combo = prefix.clone();
+                    combo.push(state.clone());
+                    combo
+                })
+            })
+            .collect();
+    }
+    combinations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binary() -> Vec<State> {
+        vec![State::True, State::False]
+    }
+
+    #[test]
+    fn test_product_and_sum_out() {
+        // P(A) y P(B | A)
+        let a = Factor::new(vec![0], vec![binary()], HashMap::from([
+            (vec![State::True], 0.3),
+            (vec![State::False], 0.7),
+        ]));
+        let b = Factor::new(vec![0, 1], vec![binary(), binary()], HashMap::from([
+            (vec![State::True, State::True], 0.9),
+            (vec![State::True, State::False], 0.1),
+            (vec![State::False, State::True], 0.2),
+            (vec![State::False, State::False], 0.8),
+        ]));
+
+        let joint = a.product(&b);
+        assert_eq!(joint.variables, vec![0, 1]);
+
+        let marginal = joint.sum_out(0);
+        assert_eq!(marginal.variables, vec![1]);
+        assert!((marginal.value(&[State::True]) - (0.3 * 0.9 + 0.7 * 0.2)).abs() < 1e-12);
+        assert!((marginal.value(&[State::False]) - (0.3 * 0.1 + 0.7 * 0.8)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_reduce_drops_observed_variable() {
+        let b = Factor::new(vec![0, 1], vec![binary(), binary()], HashMap::from([
+            (vec![State::True, State::True], 0.9),
+            (vec![State::True, State::False], 0.1),
+            (vec![State::False, State::True], 0.2),
+            (vec![State::False, State::False], 0.8),
+        ]));
+
+        let reduced = b.reduce(&HashMap::from([(0, State::False)]));
+        assert_eq!(reduced.variables, vec![1]);
+        assert_eq!(reduced.value(&[State::True]), 0.2);
+        assert_eq!(reduced.value(&[State::False]), 0.8);
+    }
+}
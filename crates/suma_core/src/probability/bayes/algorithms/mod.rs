@@ -1,3 +1,9 @@
 pub mod sampling;
+pub mod factor;
+pub mod variable_elimination;
+pub mod d_separation;
 
-pub use sampling::*;
\ No newline at end of file
+pub use sampling::*;
+pub use factor::Factor;
+pub use variable_elimination::*;
+pub use d_separation::*;
\ No newline at end of file
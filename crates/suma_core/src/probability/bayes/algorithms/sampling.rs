@@ -1,4 +1,4 @@
-use crate::probability::bayes::BN_base::State;
+use crate::probability::bayes::BN_base::{BayesianNetworkBase, CPTBase, State};
 use crate::probability::bayes::BayesianNetwork;
 use std::collections::HashMap;
 
@@ -62,6 +62,54 @@ pub fn rejection_sampling(
     distribution
 }
 
+/// MAP aproximado: devuelve la asignación completa más frecuente entre las muestras
+/// aceptadas por rejection sampling. Útil cuando la enumeración exacta es inviable.
+/// Devuelve `None` si ninguna muestra es consistente con la evidencia.
+pub fn approximate_map(
+    network: &BayesianNetwork,
+    evidence: &HashMap<usize, State>,
+    n_samples: usize,
+) -> Option<HashMap<usize, State>>
+{
+    let topo_order = network.topological_order().unwrap();
+    let mut counts: HashMap<Vec<(usize, State)>, usize> = HashMap::new();
+    let mut best: Option<(Vec<(usize, State)>, usize)> = None;
+
+    for _ in 0..n_samples {
+        let mut sample = HashMap::new();
+        let mut assignment = Vec::with_capacity(topo_order.len());
+        let mut valid = true;
+
+        for node in &topo_order {
+            let parent_values = network.get_parent_values(node, &sample);
+            let generated_val = network.sample_node(node, &parent_values);
+
+            // Misma regla de aceptación que rejection_sampling
+            if let Some(evidence_val) = evidence.get(node) {
+                if generated_val != *evidence_val {
+                    valid = false;
+                    break;
+                }
+            }
+
+            assignment.push((*node, generated_val.clone()));
+            sample.insert(*node, generated_val);
+        }
+
+        if valid {
+            let count = counts.entry(assignment.clone()).or_insert(0);
+            *count += 1;
+
+            // En caso de empate se conserva la asignación que alcanzó primero la cuenta
+            if best.as_ref().map_or(true, |(_, best_count)| *count > *best_count) {
+                best = Some((assignment, *count));
+            }
+        }
+    }
+
+    best.map(|(assignment, _)| assignment.into_iter().collect())
+}
+
 pub fn likelihood_sampling( // Nombre mantenido por compatibilidad con WASM
    network: &BayesianNetwork,
    evidence: &HashMap<usize, State>,
@@ -86,8 +134,14 @@ pub fn likelihood_sampling( // Nombre mantenido por compatibilidad con WASM
                 node_value = evidence_val.clone();
 
                 // 2. Ponderar la muestra: W = W * P(Evidencia | Padres)
-                let prob_evidence = network.get_conditional_probability(*node, &parent_values, evidence_val.clone()).unwrap_or(0.0);
-                sample_weight *= prob_evidence;
+                // Si la CPT no cubre esta combinación de padres, la muestra se descarta (peso 0).
+                match network.get_conditional_probability(*node, &parent_values, evidence_val.clone()) {
+                    Some(prob_evidence) => sample_weight *= prob_evidence,
+                    None => {
+                        sample_weight = 0.0;
+                        break;
+                    }
+                }
 
             } else {
                 // 3. Si no es evidencia, muestrear el valor normalmente.
@@ -119,10 +173,96 @@ pub fn likelihood_sampling( // Nombre mantenido por compatibilidad con WASM
     distribution
 }
 
+/// Gibbs sampling (MCMC). Parte de una asignación aleatoria consistente con la
+/// evidencia y en cada barrido vuelve a muestrear cada nodo no observado dada su
+/// manta de Markov: `P(x | padres) · Π P(hijo | padres del hijo)`.
+/// A diferencia de rejection sampling no descarta muestras, así que sirve con
+/// evidencia poco probable. Los primeros `burn_in` barridos no se cuentan.
+pub fn gibbs_sampling(
+    network: &BayesianNetwork,
+    evidence: &HashMap<usize, State>,
+    query: usize,
+    n_samples: usize,
+    burn_in: usize,
+) -> HashMap<State, f64>
+{
+    if let Some(observed) = evidence.get(&query) {
+        return HashMap::from([(observed.clone(), 1.0)]);
+    }
+
+    let topo_order = network.topological_order().unwrap();
+
+    // 1. Estado inicial: muestreo hacia adelante con la evidencia fijada
+    let mut sample = HashMap::new();
+    for node in &topo_order {
+        let value = match evidence.get(node) {
+            Some(observed) => observed.clone(),
+            None => network.sample_node(node, &network.get_parent_values(node, &sample)),
+        };
+        sample.insert(*node, value);
+    }
+
+    let hidden: Vec<usize> = topo_order.iter().copied().filter(|n| !evidence.contains_key(n)).collect();
+    let mut counts: HashMap<State, usize> = HashMap::new();
+
+    for sweep in 0..burn_in + n_samples {
+        for &node in &hidden {
+            let value = sample_from_blanket(network, node, &mut sample);
+            sample.insert(node, value);
+        }
+        if sweep >= burn_in {
+            *counts.entry(sample[&query].clone()).or_insert(0) += 1;
+        }
+    }
+
+    let mut distribution = HashMap::new();
+    if n_samples > 0 {
+        for (state, count) in counts {
+            distribution.insert(state, count as f64 / n_samples as f64);
+        }
+    }
+    distribution
+}
+
+/// Muestrea `node` condicionado a su manta de Markov en `sample`. Si todos los
+/// valores tienen peso cero (CPT incompleta) se conserva el valor actual.
+fn sample_from_blanket(network: &BayesianNetwork, node: usize, sample: &mut HashMap<usize, State>) -> State {
+    let current = sample[&node].clone();
+    let values = network.get_cpt(node).map(|cpt| cpt.possible_values()).unwrap_or_default();
+    let children = network.get_children(node);
+
+    let weights: Vec<f64> = values.iter().map(|value| {
+        sample.insert(node, value.clone());
+        let own = network
+            .get_conditional_probability(node, &network.get_parent_values(&node, sample), value.clone())
+            .unwrap_or(0.0);
+        children.iter().fold(own, |acc, child| {
+            let parents = network.get_parent_values(child, sample);
+            acc * network.get_conditional_probability(*child, &parents, sample[child].clone()).unwrap_or(0.0)
+        })
+    }).collect();
+
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return current;
+    }
+
+    let target = crate::probability::utils::random::random_f64() * total;
+    let mut cumulative = 0.0;
+    for (value, weight) in values.iter().zip(&weights) {
+        cumulative += weight;
+        if target < cumulative {
+            return value.clone();
+        }
+    }
+    // Redondeo: el último valor con peso positivo
+    values.iter().zip(&weights).rev().find(|(_, w)| **w > 0.0).map(|(v, _)| v.clone()).unwrap_or(current)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::probability::bayes::BN_base::{CPTBase, State};
+    use crate::probability::bayes::BN_base::{BayesianNetworkBase, CPTBase, State};
     use crate::probability::bayes::implementations::CPTs::{BinaryCPT, DiscreteCPT, CPT};
     use crate::data_structures::dag::DAG;
     use std::collections::HashMap;
@@ -350,6 +490,172 @@ mod tests {
                 "Probabilidad estimada: {}, esperada: ~{}", prob_c_true, expected_prob);
     }
 
+    #[test]
+    fn test_likelihood_sampling_incomplete_cpt() {
+        // B solo define la combinación A=True; las muestras con A=False deben descartarse
+        let mut dag = DAG::<usize>::new();
+        dag.add_node(0);
+        dag.add_node(1);
+        dag.add_edge(0, 1).unwrap();
+
+        let mut cpts: HashMap<usize, CPT> = HashMap::new();
+
+        cpts.insert(0, CPT::Binary(BinaryCPT::new_no_parents(
+            vec![State::True, State::False],
+            vec![0.5]
+        )));
+
+        cpts.insert(1, CPT::Binary(BinaryCPT::new_with_parents(
+            vec![vec![State::True]],
+            vec![HashMap::from([(State::True, 0.7)])],
+            vec![State::True, State::False]
+        )));
+
+        let mut name_to_id = HashMap::new();
+        name_to_id.insert("A".to_string(), 0);
+        name_to_id.insert("B".to_string(), 1);
+
+        let network = BayesianNetwork::from_parts(dag, cpts, name_to_id);
+        assert_eq!(network.get_conditional_probability(1, &[State::False], State::True), None);
+
+        let mut evidence = HashMap::new();
+        evidence.insert(1, State::True);
+
+        let distribution = likelihood_sampling(&network, &evidence, 0, 2000);
+
+        let prob_a_true = *distribution.get(&State::True).unwrap_or(&0.0);
+        assert!((prob_a_true - 1.0).abs() < 1e-9, "Probabilidad estimada: {}", prob_a_true);
+        assert!(distribution.get(&State::False).is_none());
+    }
+
+    // Enumeración exacta de la asignación más probable dada la evidencia
+    fn exact_map(network: &BayesianNetwork, evidence: &HashMap<usize, State>) -> HashMap<usize, State> {
+        let order = network.topological_order().unwrap();
+        let mut assignments: Vec<HashMap<usize, State>> = vec![HashMap::new()];
+
+        for node in &order {
+            let values = match evidence.get(node) {
+                Some(v) => vec![v.clone()],
+                None => network.get_cpt(*node).unwrap().possible_values(),
+            };
+            assignments = assignments
+                .into_iter()
+                .flat_map(|a| values.iter().map(move |v| {
+                    let mut next = a.clone();
+                    next.insert(*node, v.clone());
+                    next
+                }))
+                .collect();
+        }
+
+        let joint = |a: &HashMap<usize, State>| -> f64 {
+            order.iter().map(|node| {
+                let parents = network.get_parent_values(node, a);
+                network.get_conditional_probability(*node, &parents, a[node].clone()).unwrap_or(0.0)
+            }).product()
+        };
+
+        assignments
+            .into_iter()
+            .max_by(|a, b| joint(a).partial_cmp(&joint(b)).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_approximate_map_sprinkler() {
+        let mut bn = BayesianNetwork::new();
+        bn.add_binary_node("Rain", vec![], vec![(vec![], 0.2)]).unwrap();
+        bn.add_binary_node("Sprinkler", vec!["Rain"], vec![
+            (vec![true], 0.01),
+            (vec![false], 0.40),
+        ]).unwrap();
+        bn.add_binary_node("WetGrass", vec!["Rain", "Sprinkler"], vec![
+            (vec![true, true], 0.99),
+            (vec![true, false], 0.80),
+            (vec![false, true], 0.90),
+            (vec![false, false], 0.00),
+        ]).unwrap();
+
+        let wet = bn.get_id_from_name("WetGrass").unwrap();
+        let mut evidence = HashMap::new();
+        evidence.insert(wet, State::True);
+
+        let exact = exact_map(&bn, &evidence);
+        let approx = approximate_map(&bn, &evidence, 5000).unwrap();
+
+        // MAP exacto: Rain=False, Sprinkler=True, WetGrass=True
+        assert_eq!(exact[&bn.get_id_from_name("Rain").unwrap()], State::False);
+        assert_eq!(exact[&bn.get_id_from_name("Sprinkler").unwrap()], State::True);
+        assert_eq!(approx, exact);
+    }
+
+    #[test]
+    fn test_samplers_agree_with_variable_elimination() {
+        let mut bn = BayesianNetwork::new();
+        bn.add_binary_node("Rain", vec![], vec![(vec![], 0.2)]).unwrap();
+        bn.add_binary_node("Sprinkler", vec!["Rain"], vec![
+            (vec![true], 0.01),
+            (vec![false], 0.40),
+        ]).unwrap();
+        bn.add_binary_node("WetGrass", vec!["Rain", "Sprinkler"], vec![
+            (vec![true, true], 0.99),
+            (vec![true, false], 0.80),
+            (vec![false, true], 0.90),
+            (vec![false, false], 0.00),
+        ]).unwrap();
+
+        let rain = bn.get_id_from_name("Rain").unwrap();
+        let wet = bn.get_id_from_name("WetGrass").unwrap();
+        let evidence = HashMap::from([(wet, State::True)]);
+
+        // P(Rain | WetGrass) exacta como referencia en lugar de una constante a mano
+        let exact = bn.variable_elimination(rain, &evidence)[&State::True];
+        let rejection = rejection_sampling(&bn, &evidence, rain, 20000)[&State::True];
+        let weighted = likelihood_sampling(&bn, &evidence, rain, 20000)[&State::True];
+
+        assert!((rejection - exact).abs() < 0.05, "rejection: {}, exacta: {}", rejection, exact);
+        assert!((weighted - exact).abs() < 0.05, "likelihood: {}, exacta: {}", weighted, exact);
+    }
+
+    #[test]
+    fn test_gibbs_sampling_rare_evidence() {
+        // Alarma: la evidencia (Alarm = True) es rara, P ≈ 0.0025
+        let mut bn = BayesianNetwork::new();
+        bn.add_binary_node("Burglary", vec![], vec![(vec![], 0.001)]).unwrap();
+        bn.add_binary_node("Earthquake", vec![], vec![(vec![], 0.002)]).unwrap();
+        bn.add_binary_node("Alarm", vec!["Burglary", "Earthquake"], vec![
+            (vec![true, true], 0.95),
+            (vec![true, false], 0.94),
+            (vec![false, true], 0.29),
+            (vec![false, false], 0.001),
+        ]).unwrap();
+
+        let burglary = bn.get_id_from_name("Burglary").unwrap();
+        let alarm = bn.get_id_from_name("Alarm").unwrap();
+        let evidence = HashMap::from([(alarm, State::True)]);
+
+        let exact = bn.variable_elimination(burglary, &evidence)[&State::True];
+        let gibbs = bn.gibbs_sampling(burglary, &evidence, 20000, 1000);
+        let estimate = gibbs.get(&State::True).copied().unwrap_or(0.0);
+
+        assert!((estimate - exact).abs() < 0.05, "gibbs: {}, exacta: {}", estimate, exact);
+        assert!((gibbs.values().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        // Consultar un nodo observado devuelve la evidencia
+        assert_eq!(bn.gibbs_sampling(alarm, &evidence, 10, 0), HashMap::from([(State::True, 1.0)]));
+    }
+
+    #[test]
+    fn test_approximate_map_impossible_evidence() {
+        let mut bn = BayesianNetwork::new();
+        bn.add_binary_node("A", vec![], vec![(vec![], 0.0)]).unwrap();
+
+        let mut evidence = HashMap::new();
+        evidence.insert(bn.get_id_from_name("A").unwrap(), State::True);
+
+        assert!(approximate_map(&bn, &evidence, 100).is_none());
+    }
+
     // Test adicional para nodos discretos
     #[test]
     fn test_discrete_node_sampling() {
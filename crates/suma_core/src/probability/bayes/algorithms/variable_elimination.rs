@@ -0,0 +1,243 @@
+use crate::probability::bayes::BN_base::{BayesianNetworkBase, State};
+use crate::probability::bayes::BayesianNetwork;
+use super::factor::Factor;
+use std::collections::{HashMap, HashSet};
+
+/// Inferencia exacta P(query | evidence) por eliminación de variables.
+/// Los factores de cada nodo se obtienen de la caché de la red, así que
+/// consultas repetidas con distinta evidencia no reconstruyen las CPTs.
+pub fn variable_elimination(
+    network: &BayesianNetwork,
+    query: usize,
+    evidence: &HashMap<usize, State>,
+) -> HashMap<State, f64> {
+    if let Some(observed) = evidence.get(&query) {
+        return HashMap::from([(observed.clone(), 1.0)]);
+    }
+
+    let mut factors: Vec<Factor> = network
+        .node_factors()
+        .iter()
+        .map(|factor| factor.reduce(evidence))
+        .collect();
+
+    let mut hidden: Vec<usize> = network
+        .get_nodes()
+        .into_iter()
+        .filter(|var| *var != query && !evidence.contains_key(var))
+        .collect();
+    hidden.sort();
+    factors = sum_out_all(factors, &hidden);
+
+    let Some(mut result) = multiply_all(factors) else {
+        return HashMap::new();
+    };
+    result.normalize();
+
+    let mut distribution = HashMap::new();
+    if result.values.values().sum::<f64>() > 0.0 {
+        for assignment in result.assignments() {
+            let probability = result.value(&assignment);
+            if let Some(state) = assignment.into_iter().next() {
+                distribution.insert(state, probability);
+            }
+        }
+    }
+    distribution
+}
+
+/// Explicación más probable (MAP) de `query_vars` dada la evidencia.
+///
+/// Primero se suman las variables ocultas (ni consultadas ni observadas) con el
+/// mismo orden de grado mínimo; luego se busca exhaustivamente el máximo sobre el
+/// factor conjunto de las variables consultadas. Devuelve la asignación y su
+/// probabilidad a posteriori `P(query_vars = asignación | evidence)`. Si la
+/// evidencia es imposible, devuelve una asignación vacía con probabilidad 0.
+pub fn map_query(
+    network: &BayesianNetwork,
+    query_vars: &[usize],
+    evidence: &HashMap<usize, State>,
+) -> (HashMap<usize, State>, f64) {
+    // Las variables consultadas que ya están observadas no se maximizan
+    let mut assignment: HashMap<usize, State> = query_vars
+        .iter()
+        .filter_map(|var| evidence.get(var).map(|state| (*var, state.clone())))
+        .collect();
+
+    let mut factors: Vec<Factor> = network
+        .node_factors()
+        .iter()
+        .map(|factor| factor.reduce(evidence))
+        .collect();
+
+    let mut hidden: Vec<usize> = network
+        .get_nodes()
+        .into_iter()
+        .filter(|var| !query_vars.contains(var) && !evidence.contains_key(var))
+        .collect();
+    hidden.sort();
+    factors = sum_out_all(factors, &hidden);
+
+    let Some(joint) = multiply_all(factors) else {
+        return (assignment, 1.0);
+    };
+    let total: f64 = joint.values.values().sum();
+    if total <= 0.0 {
+        return (HashMap::new(), 0.0);
+    }
+
+    // Recorrido en el orden de los dominios: ante empates gana la primera asignación
+    let mut best: Option<(Vec<State>, f64)> = None;
+    for candidate in joint.assignments() {
+        let value = joint.value(&candidate);
+        if best.as_ref().map_or(true, |(_, best_value)| value > *best_value) {
+            best = Some((candidate, value));
+        }
+    }
+
+    let (states, value) = best.unwrap();
+    assignment.extend(joint.variables.iter().copied().zip(states));
+    (assignment, value / total)
+}
+
+/// Suma las variables `hidden` de la lista de factores, en orden de grado mínimo.
+fn sum_out_all(mut factors: Vec<Factor>, hidden: &[usize]) -> Vec<Factor> {
+    let scopes = factors.iter().map(|f| f.variables.clone()).collect();
+
+    for var in min_degree_order(scopes, hidden) {
+        let (involved, rest): (Vec<Factor>, Vec<Factor>) =
+            factors.into_iter().partition(|f| f.contains(var));
+        factors = rest;
+
+        if let Some(product) = multiply_all(involved) {
+            factors.push(product.sum_out(var));
+        }
+    }
+    factors
+}
+
+/// Orden de eliminación por grado mínimo: en cada paso se elimina la variable con
+/// menos vecinos en el grafo de interacción actual (la que genera el factor más
+/// chico) y sus vecinos quedan conectados entre sí. Los empates van al id menor.
+fn min_degree_order(mut scopes: Vec<Vec<usize>>, candidates: &[usize]) -> Vec<usize> {
+    let mut remaining = candidates.to_vec();
+    let mut order = Vec::with_capacity(remaining.len());
+
+    while !remaining.is_empty() {
+        let neighbors = |var: usize| -> HashSet<usize> {
+            scopes.iter()
+                .filter(|scope| scope.contains(&var))
+                .flatten()
+                .copied()
+                .filter(|&other| other != var)
+                .collect()
+        };
+
+        let (position, var) = remaining.iter()
+            .copied()
+            .enumerate()
+            .min_by_key(|&(_, var)| (neighbors(var).len(), var))
+            .unwrap();
+        remaining.remove(position);
+
+        let merged: Vec<usize> = neighbors(var).into_iter().collect();
+        scopes.retain(|scope| !scope.contains(&var));
+        scopes.push(merged);
+        order.push(var);
+    }
+    order
+}
+
+fn multiply_all(factors: Vec<Factor>) -> Option<Factor> {
+    factors.into_iter().reduce(|acc, factor| acc.product(&factor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_degree_eliminates_leaves_before_hub() {
+        // Estrella: 0 es padre de 1, 2 y 3. Eliminar el centro primero crearía un
+        // factor sobre las tres hojas; por grado mínimo se eliminan antes las hojas.
+        let scopes = vec![vec![0], vec![0, 1], vec![0, 2], vec![0, 3]];
+        assert_eq!(min_degree_order(scopes, &[0, 1, 2]), vec![1, 2, 0]);
+    }
+
+    fn sprinkler() -> BayesianNetwork {
+        let mut bn = BayesianNetwork::new();
+        bn.add_binary_node("Rain", vec![], vec![(vec![], 0.2)]).unwrap();
+        bn.add_binary_node("Sprinkler", vec!["Rain"], vec![
+            (vec![true], 0.01),
+            (vec![false], 0.40),
+        ]).unwrap();
+        bn.add_binary_node("WetGrass", vec!["Rain", "Sprinkler"], vec![
+            (vec![true, true], 0.99),
+            (vec![true, false], 0.80),
+            (vec![false, true], 0.90),
+            (vec![false, false], 0.00),
+        ]).unwrap();
+        bn
+    }
+
+    #[test]
+    fn test_map_query_most_likely_cause() {
+        let bn = sprinkler();
+        let rain = bn.get_id_from_name("Rain").unwrap();
+        let sprinkler = bn.get_id_from_name("Sprinkler").unwrap();
+        let wet = bn.get_id_from_name("WetGrass").unwrap();
+        let evidence = HashMap::from([(wet, State::True)]);
+
+        // P(W) = 0.2·0.01·0.99 + 0.2·0.99·0.8 + 0.8·0.4·0.9 = 0.44838
+        let (assignment, probability) = map_query(&bn, &[rain, sprinkler], &evidence);
+        assert_eq!(assignment, HashMap::from([(rain, State::False), (sprinkler, State::True)]));
+        assert!((probability - 0.288 / 0.44838).abs() < 1e-9);
+
+        // MAP marginal sobre una sola variable (Sprinkler se suma)
+        let (assignment, probability) = map_query(&bn, &[rain], &evidence);
+        assert_eq!(assignment, HashMap::from([(rain, State::False)]));
+        assert!((probability - 0.288 / 0.44838).abs() < 1e-9);
+
+        // Una variable consultada y observada conserva su valor
+        let (assignment, _) = map_query(&bn, &[rain, wet], &evidence);
+        assert_eq!(assignment[&wet], State::True);
+    }
+
+    #[test]
+    fn test_discrete_network_marginal() {
+        let mut bn = BayesianNetwork::new();
+        bn.add_discrete_node("Difficulty", vec![], vec!["Easy", "Hard"], HashMap::from([
+            (vec![], HashMap::from([("Easy", 0.6), ("Hard", 0.4)])),
+        ])).unwrap();
+        bn.add_discrete_node("Intelligence", vec![], vec!["Low", "High"], HashMap::from([
+            (vec![], HashMap::from([("Low", 0.7), ("High", 0.3)])),
+        ])).unwrap();
+        bn.add_discrete_node("Grade", vec!["Difficulty", "Intelligence"], vec!["A", "B", "C"], HashMap::from([
+            (vec!["Easy", "High"], HashMap::from([("A", 0.8), ("B", 0.15), ("C", 0.05)])),
+            (vec!["Easy", "Low"], HashMap::from([("A", 0.5), ("B", 0.3), ("C", 0.2)])),
+            (vec!["Hard", "High"], HashMap::from([("A", 0.6), ("B", 0.25), ("C", 0.15)])),
+            (vec!["Hard", "Low"], HashMap::from([("A", 0.2), ("B", 0.4), ("C", 0.4)])),
+        ])).unwrap();
+
+        let grade = bn.get_id_from_name("Grade").unwrap();
+        let distribution = variable_elimination(&bn, grade, &HashMap::new());
+
+        // P(A) = 0.6·0.3·0.8 + 0.6·0.7·0.5 + 0.4·0.3·0.6 + 0.4·0.7·0.2
+        let a = distribution[&State::Value("A".to_string())];
+        assert!((a - 0.482).abs() < 1e-9, "P(Grade = A) = {}", a);
+        assert!((distribution.values().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        // Evidencia en el hijo: P(Intelligence | Grade = A) por Bayes
+        let intelligence = bn.get_id_from_name("Intelligence").unwrap();
+        let posterior = variable_elimination(&bn, intelligence, &HashMap::from([(grade, State::Value("A".to_string()))]));
+        let high = posterior[&State::Value("High".to_string())];
+        assert!((high - (0.3 * (0.6 * 0.8 + 0.4 * 0.6)) / 0.482).abs() < 1e-9);
+
+        // MAP con nodos discretos: (Easy, Low) tiene 0.6·0.7·0.5 = 0.21 del total 0.482
+        let difficulty = bn.get_id_from_name("Difficulty").unwrap();
+        let (assignment, probability) = map_query(&bn, &[difficulty, intelligence], &HashMap::from([(grade, State::Value("A".to_string()))]));
+        assert_eq!(assignment[&difficulty], State::Value("Easy".to_string()));
+        assert_eq!(assignment[&intelligence], State::Value("Low".to_string()));
+        assert!((probability - 0.21 / 0.482).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+/// Errores al construir o cargar una red bayesiana.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum BNError {
+    #[error("No se pudo leer '{path}': {message}")]
+    Io { path: String, message: String },
+
+    #[error("XMLBIF inválido: {0}")]
+    Parse(String),
+
+    #[error("Variable desconocida: '{0}'")]
+    UnknownVariable(String),
+
+    #[error("Tabla de '{variable}' inválida: {message}")]
+    InvalidTable { variable: String, message: String },
+
+    #[error("Las dependencias entre variables forman un ciclo")]
+    Cycle,
+
+    /// Errores devueltos por `add_discrete_node` / `add_binary_node`.
+    #[error("{0}")]
+    Network(String),
+}
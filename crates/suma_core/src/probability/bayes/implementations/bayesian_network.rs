@@ -2,17 +2,23 @@
 use serde::Serialize;
 use crate::probability::bayes::{BinaryCPT, DiscreteCPT, CPT};
 use std::hash::Hash;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use rust_xlsxwriter::TableFunction;
 use crate::data_structures::dag::DAG;
-use crate::data_structures::graphs::{Directed, GraphBase};
+use crate::data_structures::graphs::{CycleError, Directed, GraphBase};
 use crate::probability::bayes::models::BN_base::*;
+use crate::probability::bayes::algorithms::factor::Factor;
 
 pub struct BayesianNetwork {
     dag: DAG<usize>,
     cpts: HashMap<usize, CPT>,
     name_to_id: HashMap<String, usize>,
     id_to_name: HashMap<usize, String>,
+    // Factores por nodo construidos a partir de las CPTs; se invalidan al mutar la red
+    factor_cache: Mutex<HashMap<usize, Arc<Factor>>>,
+    factor_builds: AtomicUsize,
 }
 
 
@@ -38,6 +44,7 @@ impl BayesianNetworkBase for BayesianNetwork {
     }
 
     fn get_mut_cpt(&mut self, node: usize) -> Option<&mut (dyn CPTBase + 'static)> {
+        self.invalidate_factors();
         self.cpts.get_mut(&node).map(|cpt| {
             let cpt_ref: &mut CPT = cpt;
             cpt_ref as &mut dyn CPTBase
@@ -47,6 +54,7 @@ impl BayesianNetworkBase for BayesianNetwork {
     fn remove_node(&mut self, node: usize) -> Option<()> {
         if self.dag.remove_node(node).is_some() {
             self.cpts.remove(&node);
+            self.invalidate_factors();
             Some(())
         } else {
             None
@@ -61,6 +69,8 @@ impl BayesianNetwork {
             cpts: HashMap::new(),
             name_to_id : HashMap::new(),
             id_to_name : HashMap::new(),
+            factor_cache: Mutex::new(HashMap::new()),
+            factor_builds: AtomicUsize::new(0),
         }
     }
 
@@ -81,10 +91,12 @@ impl BayesianNetwork {
             cpts,
             name_to_id,
             id_to_name,
+            factor_cache: Mutex::new(HashMap::new()),
+            factor_builds: AtomicUsize::new(0),
         }
     }
 
-    pub fn topological_order(&self) -> Result<Vec<usize>, &str> {
+    pub fn topological_order(&self) -> Result<Vec<usize>, CycleError<usize>> {
         crate::data_structures::graphs::topological_sort(&self.dag)
     }
 
@@ -106,6 +118,111 @@ impl BayesianNetwork {
         super::super::algorithms::sampling::likelihood_sampling(self, evidence, query, n_samples)
     }
 
+    /// Gibbs sampling: útil cuando la evidencia es tan rara que rejection sampling
+    /// descarta casi todas las muestras.
+    pub fn gibbs_sampling(
+        &self,
+        query: usize,
+        evidence: &HashMap<usize, State>,
+        n_samples: usize,
+        burn_in: usize
+    ) -> HashMap<State, f64> {
+        super::super::algorithms::sampling::gibbs_sampling(self, evidence, query, n_samples, burn_in)
+    }
+
+    pub fn approximate_map(
+        &self,
+        evidence: &HashMap<usize, State>,
+        n_samples: usize
+    ) -> Option<HashMap<usize, State>> {
+        super::super::algorithms::sampling::approximate_map(self, evidence, n_samples)
+    }
+
+    /// Inferencia exacta P(query | evidence) por eliminación de variables.
+    pub fn variable_elimination(
+        &self,
+        query: usize,
+        evidence: &HashMap<usize, State>
+    ) -> HashMap<State, f64> {
+        super::super::algorithms::variable_elimination::variable_elimination(self, query, evidence)
+    }
+
+    /// Asignación más probable de `query_vars` dada la evidencia, con su probabilidad.
+    pub fn map_query(
+        &self,
+        query_vars: &[usize],
+        evidence: &HashMap<usize, State>
+    ) -> (HashMap<usize, State>, f64) {
+        super::super::algorithms::variable_elimination::map_query(self, query_vars, evidence)
+    }
+
+    /// Indica si `a` y `b` son condicionalmente independientes dado `given`
+    /// según la estructura de la red (d-separación).
+    pub fn is_d_separated(&self, a: usize, b: usize, given: &HashSet<usize>) -> bool {
+        super::super::algorithms::d_separation::is_d_separated(self, a, b, given)
+    }
+
+    /// Factores de todos los nodos. Se construyen desde las CPTs sólo la primera
+    /// vez y se reutilizan hasta que la red cambie.
+    pub(crate) fn node_factors(&self) -> Vec<Arc<Factor>> {
+        let mut cache = self.factor_cache.lock().unwrap();
+        let mut nodes = self.get_nodes();
+        nodes.sort();
+
+        nodes
+            .into_iter()
+            .map(|node| {
+                cache
+                    .entry(node)
+                    .or_insert_with(|| {
+                        self.factor_builds.fetch_add(1, Ordering::Relaxed);
+                        Arc::new(self.build_factor(node))
+                    })
+                    .clone()
+            })
+            .collect()
+    }
+
+    /// Número de factores construidos desde las CPTs (útil para medir la caché).
+    pub fn factor_builds(&self) -> usize {
+        self.factor_builds.load(Ordering::Relaxed)
+    }
+
+    /// Factor P(node | padres) con los padres ordenados por id, igual que `get_parent_values`.
+    fn build_factor(&self, node: usize) -> Factor {
+        let mut parents = self.get_parents(node);
+        parents.sort();
+
+        let node_values = self.get_cpt(node)
+            .map(|cpt| cpt.possible_values())
+            .unwrap_or_default();
+
+        let mut values = HashMap::new();
+        for combo in self.parent_state_combinations(&parents) {
+            for value in &node_values {
+                let probability = self
+                    .get_conditional_probability(node, &combo, value.clone())
+                    .unwrap_or(0.0);
+                let mut assignment = combo.clone();
+                assignment.push(value.clone());
+                values.insert(assignment, probability);
+            }
+        }
+
+        let mut variables = parents;
+        variables.push(node);
+        let domains = variables
+            .iter()
+            .map(|v| self.get_cpt(*v).map(|cpt| cpt.possible_values()).unwrap_or_default())
+            .collect();
+
+        Factor::new(variables, domains, values)
+    }
+
+    fn invalidate_factors(&mut self) {
+        self.factor_cache.get_mut().unwrap().clear();
+    }
+
     pub fn get_parent_values(
         &self,
         node: &usize,
@@ -143,6 +260,7 @@ impl BayesianNetwork {
     }
 
     fn add_node_dag(&mut self, node: usize, cpt: CPT) {
+        self.invalidate_factors();
         self.dag.add_node(node);
         self.cpts.insert(node, cpt);
     }
@@ -151,10 +269,12 @@ impl BayesianNetwork {
         let parent = self.name_to_id.get(parent).expect("Parent node not found.");
         let child = self.name_to_id.get(child).expect("Child node not found.");
         self.dag.add_edge(*parent, *child);
+        self.invalidate_factors();
     }
 
     pub fn add_edge_by_id(&mut self, parent: usize, child: usize) {
         self.dag.add_edge(parent, child);
+        self.invalidate_factors();
     }
 
     pub fn get_id_from_name(&self, name: &str) -> Option<usize> {
@@ -165,6 +285,8 @@ impl BayesianNetwork {
         self.id_to_name.get(&node_id)
     }
 
+    /// P(node = value | padres = parent_values) según la CPT del nodo.
+    /// Devuelve `None` si el nodo no tiene CPT o si la combinación de padres no está en la tabla.
     pub fn get_conditional_probability(
         &self,
         node: usize,
@@ -189,6 +311,7 @@ impl BayesianNetwork {
         }
 
         let node_id = self.next_node_id();
+        self.invalidate_factors();
         self.dag.add_node(node_id);
         self.cpts.insert(node_id, cpt);
         self.name_to_id.insert(name.to_string(), node_id);
@@ -286,6 +409,15 @@ impl BayesianNetwork {
             internal_table.insert(parent_vals_state, distribution_state);
         }
 
+        // Validación de completitud: cada combinación de estados de los padres debe estar presente
+        let missing: Vec<Vec<State>> = self.parent_state_combinations(&parent_ids)
+            .into_iter()
+            .filter(|combo| !internal_table.contains_key(combo))
+            .collect();
+        if !missing.is_empty() {
+            return Err(format!("Missing parent combinations in CPT for node '{}': {:?}", name, missing));
+        }
+
         let cpt = CPT::Discrete(DiscreteCPT {
             node_possible_values: internal_possible_values,
             table: internal_table
@@ -305,6 +437,28 @@ impl BayesianNetwork {
 
 
 
+    /// Producto cartesiano de los estados posibles de los padres, en el orden dado.
+    pub fn parent_state_combinations(&self, parent_ids: &[usize]) -> Vec<Vec<State>> {
+        let mut combinations: Vec<Vec<State>> = vec![vec![]];
+        for parent in parent_ids {
+            let states = self.get_cpt(*parent)
+                .map(|cpt| cpt.possible_values())
+                .unwrap_or_default();
+
+            combinations = combinations
+                .into_iter()
+                .flat_map(|prefix| {
+                    states.iter().map(move |state| {
+                        let mut combo = prefix.clone();
+                        combo.push(state.clone());
+                        combo
+                    })
+                })
+                .collect();
+        }
+        combinations
+    }
+
     pub fn print_ids(&self) {
         for (name, id) in &self.name_to_id {
             println!("Node Name: {}, ID: {}", name, id);
@@ -620,6 +774,35 @@ mod tests {
         ).unwrap_err(); // Esto debería fallar porque la suma no es 1.0
     }
 
+    #[test]
+    fn test_missing_parent_combination() {
+        let mut bn = BayesianNetwork::new();
+        bn.add_binary_node("Rain", vec![], vec![(vec![], 0.2)]).unwrap();
+        bn.add_discrete_node(
+            "Traffic",
+            vec![],
+            vec!["Low", "High"],
+            HashMap::from([(vec![], HashMap::from([("Low", 0.5), ("High", 0.5)]))])
+        ).unwrap();
+
+        // Falta la combinación (Rain=False, Traffic=High)
+        let err = bn.add_discrete_node(
+            "Delay",
+            vec!["Rain", "Traffic"],
+            vec!["None", "Short", "Long"],
+            HashMap::from([
+                (vec!["true", "Low"], HashMap::from([("None", 0.5), ("Short", 0.3), ("Long", 0.2)])),
+                (vec!["true", "High"], HashMap::from([("None", 0.1), ("Short", 0.4), ("Long", 0.5)])),
+                (vec!["false", "Low"], HashMap::from([("None", 0.8), ("Short", 0.15), ("Long", 0.05)])),
+            ])
+        ).unwrap_err();
+
+        let expected = format!("{:?}", vec![State::False, State::Value("High".to_string())]);
+        assert!(err.contains(&expected), "Error inesperado: {}", err);
+        assert!(!err.contains(&format!("{:?}", vec![State::True, State::Value("Low".to_string())])));
+        assert!(bn.get_id_from_name("Delay").is_none());
+    }
+
     // Test de make_states
     #[test]
     fn test_make_states() {
@@ -686,6 +869,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_variable_elimination_exact() {
+        let bn = setup_simple_network().unwrap();
+        let rain = bn.get_id_from_name("Rain").unwrap();
+        let wetgrass = bn.get_id_from_name("WetGrass").unwrap();
+
+        // P(WetGrass | Rain) = 0.01 * 0.99 + 0.99 * 0.80
+        let distribution = bn.variable_elimination(wetgrass, &HashMap::from([(rain, State::True)]));
+        assert!((distribution[&State::True] - 0.8019).abs() < 1e-9);
+        assert!((distribution[&State::False] - 0.1981).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_variable_elimination_reuses_cached_factors() {
+        let mut bn = setup_simple_network().unwrap();
+        let rain = bn.get_id_from_name("Rain").unwrap();
+        let wetgrass = bn.get_id_from_name("WetGrass").unwrap();
+
+        bn.variable_elimination(rain, &HashMap::from([(wetgrass, State::True)]));
+        bn.variable_elimination(wetgrass, &HashMap::from([(rain, State::False)]));
+        assert_eq!(bn.factor_builds(), 3);
+
+        // Modificar una CPT invalida la caché
+        bn.get_mut_cpt(rain);
+        bn.variable_elimination(wetgrass, &HashMap::new());
+        assert_eq!(bn.factor_builds(), 6);
+    }
+
     // Test de print_ids (aunque es difícil testear output, al menos verificar que no panic)
     #[test]
     fn test_print_ids_no_panic() {
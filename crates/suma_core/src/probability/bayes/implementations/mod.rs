@@ -1,5 +1,6 @@
 pub mod bayesian_network;
 pub mod CPTs;
+pub mod xmlbif;
 
 pub use bayesian_network::*;
 pub use CPTs::*;
\ No newline at end of file
@@ -0,0 +1,311 @@
+//! Carga de redes bayesianas en formato XMLBIF (0.3), el de las redes de
+//! referencia como Asia o Alarm.
+//!
+//! Solo se leen las etiquetas que definen la red: `VARIABLE` (`NAME`, `OUTCOME`)
+//! y `DEFINITION` (`FOR`, `GIVEN`, `TABLE`). El resto (`PROPERTY`, comentarios,
+//! la cabecera `<?xml ...?>`) se ignora.
+
+use std::collections::HashMap;
+use crate::probability::bayes::error::BNError;
+use crate::probability::bayes::BayesianNetwork;
+
+struct Definition {
+    parents: Vec<String>,
+    table: Vec<f64>,
+}
+
+impl BayesianNetwork {
+    /// Lee una red desde un archivo XMLBIF. Ver [`BayesianNetwork::from_xmlbif_str`].
+    pub fn from_xmlbif(path: &str) -> Result<Self, BNError> {
+        let content = std::fs::read_to_string(path).map_err(|e| BNError::Io {
+            path: path.to_string(),
+            message: e.to_string(),
+        })?;
+        Self::from_xmlbif_str(&content)
+    }
+
+    /// Construye una red a partir del contenido de un XMLBIF.
+    ///
+    /// Los nodos se agregan en orden topológico (cada variable después de sus
+    /// padres), así que los ids siguen ese orden y no el del archivo. Cada fila de
+    /// la tabla se valida con `add_discrete_node` (debe sumar 1).
+    pub fn from_xmlbif_str(content: &str) -> Result<Self, BNError> {
+        let content = strip_comments(content);
+
+        let mut outcomes: HashMap<String, Vec<String>> = HashMap::new();
+        let mut declared = Vec::new();
+        for variable in elements(&content, "VARIABLE")? {
+            let name = single_text(variable, "NAME")?;
+            let states: Vec<String> = elements(variable, "OUTCOME")?.into_iter().map(text).collect();
+            if states.is_empty() {
+                return Err(BNError::Parse(format!("la variable '{}' no tiene OUTCOME", name)));
+            }
+            if outcomes.insert(name.clone(), states).is_some() {
+                return Err(BNError::Parse(format!("variable '{}' declarada dos veces", name)));
+            }
+            declared.push(name);
+        }
+
+        let mut definitions: HashMap<String, Definition> = HashMap::new();
+        for definition in elements(&content, "DEFINITION")? {
+            let variable = single_text(definition, "FOR")?;
+            let parents: Vec<String> = elements(definition, "GIVEN")?.into_iter().map(text).collect();
+            let table = single_text(definition, "TABLE")?
+                .split_whitespace()
+                .map(|token| token.parse::<f64>().map_err(|_| BNError::InvalidTable {
+                    variable: variable.clone(),
+                    message: format!("'{}' no es un número", token),
+                }))
+                .collect::<Result<Vec<f64>, BNError>>()?;
+
+            for name in parents.iter().chain(std::iter::once(&variable)) {
+                if !outcomes.contains_key(name) {
+                    return Err(BNError::UnknownVariable(name.clone()));
+                }
+            }
+            // Igual que con VARIABLE: una segunda tabla no reemplaza en silencio a la primera
+            if definitions.contains_key(&variable) {
+                return Err(BNError::Parse(format!("DEFINITION de '{}' repetida", variable)));
+            }
+            definitions.insert(variable, Definition { parents, table });
+        }
+
+        let mut network = BayesianNetwork::new();
+        for name in topological_order(&declared, &definitions)? {
+            let definition = definitions.get(&name)
+                .ok_or_else(|| BNError::Parse(format!("falta la DEFINITION de '{}'", name)))?;
+            add_variable(&mut network, &name, definition, &outcomes)?;
+        }
+        Ok(network)
+    }
+}
+
+/// Agrega una variable a partir de su tabla XMLBIF. En el archivo las
+/// combinaciones de padres siguen el orden de `GIVEN` (el último varía más rápido)
+/// y dentro de cada una van los estados de la variable; la red, en cambio,
+/// indexa las CPTs con los padres ordenados por id.
+fn add_variable(
+    network: &mut BayesianNetwork,
+    name: &str,
+    definition: &Definition,
+    outcomes: &HashMap<String, Vec<String>>,
+) -> Result<(), BNError> {
+    let states = &outcomes[name];
+    let parent_states: Vec<&Vec<String>> = definition.parents.iter().map(|p| &outcomes[p]).collect();
+
+    let rows: usize = parent_states.iter().map(|s| s.len()).product();
+    if definition.table.len() != rows * states.len() {
+        return Err(BNError::InvalidTable {
+            variable: name.to_string(),
+            message: format!("se esperaban {} valores y hay {}", rows * states.len(), definition.table.len()),
+        });
+    }
+
+    // Posición de cada padre (orden GIVEN) una vez ordenados por id
+    let mut by_id: Vec<usize> = (0..definition.parents.len()).collect();
+    by_id.sort_by_key(|&k| network.get_id_from_name(&definition.parents[k]));
+
+    let mut table: HashMap<Vec<&str>, HashMap<&str, f64>> = HashMap::new();
+    for row in 0..rows {
+        // Índices de estado de cada padre (mixed radix, el último más rápido)
+        let mut remainder = row;
+        let mut combo = vec![0; parent_states.len()];
+        for (k, states_k) in parent_states.iter().enumerate().rev() {
+            combo[k] = remainder % states_k.len();
+            remainder /= states_k.len();
+        }
+
+        let key: Vec<&str> = by_id.iter().map(|&k| parent_states[k][combo[k]].as_str()).collect();
+        let distribution = states.iter()
+            .enumerate()
+            .map(|(s, state)| (state.as_str(), definition.table[row * states.len() + s]))
+            .collect();
+        table.insert(key, distribution);
+    }
+
+    let parent_names: Vec<&str> = by_id.iter().map(|&k| definition.parents[k].as_str()).collect();
+    let values: Vec<&str> = states.iter().map(String::as_str).collect();
+    network.add_discrete_node(name, parent_names, values, table).map_err(BNError::Network)?;
+    Ok(())
+}
+
+// Orden de declaración, pero cada variable después de todos sus padres
+fn topological_order(declared: &[String], definitions: &HashMap<String, Definition>) -> Result<Vec<String>, BNError> {
+    let mut order: Vec<String> = Vec::with_capacity(declared.len());
+    let mut pending: Vec<&String> = declared.iter().collect();
+
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|name| {
+            definitions.get(*name).map_or(true, |d| d.parents.iter().all(|p| order.contains(p)))
+        });
+        match ready {
+            Some(index) => order.push(pending.remove(index).clone()),
+            None => return Err(BNError::Cycle),
+        }
+    }
+    Ok(order)
+}
+
+// --- Lectura mínima de XML ---
+
+fn strip_comments(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find("<!--") {
+        out.push_str(&rest[..start]);
+        rest = rest[start..].find("-->").map_or("", |end| &rest[start + end + 3..]);
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Contenido de cada `<TAG ...>...</TAG>` de primer nivel (sin distinguir mayúsculas).
+fn elements<'a>(content: &'a str, tag: &str) -> Result<Vec<&'a str>, BNError> {
+    // `to_ascii_uppercase` conserva las posiciones en bytes
+    let upper = content.to_ascii_uppercase();
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+
+    let mut found = Vec::new();
+    let mut cursor = 0;
+    while let Some(offset) = upper[cursor..].find(&open) {
+        let start = cursor + offset;
+        let after_name = start + open.len();
+        // Evita confundir <GIVEN> con <GIVENX> y similares
+        if !upper[after_name..].starts_with(|c: char| c == '>' || c.is_whitespace()) {
+            cursor = after_name;
+            continue;
+        }
+        let body_start = after_name + upper[after_name..].find('>')
+            .ok_or_else(|| BNError::Parse(format!("etiqueta <{}> sin cerrar", tag)))? + 1;
+        let body_end = body_start + upper[body_start..].find(&close)
+            .ok_or_else(|| BNError::Parse(format!("falta </{}>", tag)))?;
+        found.push(&content[body_start..body_end]);
+        cursor = body_end + close.len();
+    }
+    Ok(found)
+}
+
+fn single_text(content: &str, tag: &str) -> Result<String, BNError> {
+    elements(content, tag)?
+        .into_iter()
+        .next()
+        .map(text)
+        .ok_or_else(|| BNError::Parse(format!("falta <{}>", tag)))
+}
+
+fn text(raw: &str) -> String {
+    raw.trim()
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability::bayes::BN_base::State;
+    use std::io::Write;
+
+    // Fragmento de la red Asia: "either" se define antes que sus padres y con
+    // GIVEN en orden distinto al de los ids.
+    const ASIA_FRAGMENT: &str = r#"<?xml version="1.0"?>
+<!-- Asia (recortada) -->
+<BIF VERSION="0.3">
+<NETWORK>
+<NAME>Asia</NAME>
+<VARIABLE TYPE="nature">
+    <NAME>either</NAME>
+    <OUTCOME>yes</OUTCOME>
+    <OUTCOME>no</OUTCOME>
+</VARIABLE>
+<VARIABLE TYPE="nature">
+    <NAME>tub</NAME>
+    <OUTCOME>yes</OUTCOME>
+    <OUTCOME>no</OUTCOME>
+    <PROPERTY>position = (0, 0)</PROPERTY>
+</VARIABLE>
+<VARIABLE TYPE="nature">
+    <NAME>lung</NAME>
+    <OUTCOME>yes</OUTCOME>
+    <OUTCOME>no</OUTCOME>
+</VARIABLE>
+<DEFINITION>
+    <FOR>either</FOR>
+    <GIVEN>lung</GIVEN>
+    <GIVEN>tub</GIVEN>
+    <TABLE>1.0 0.0 1.0 0.0 1.0 0.0 0.0 1.0</TABLE>
+</DEFINITION>
+<DEFINITION>
+    <FOR>tub</FOR>
+    <TABLE>0.05 0.95</TABLE>
+</DEFINITION>
+<DEFINITION>
+    <FOR>lung</FOR>
+    <TABLE>0.1 0.9</TABLE>
+</DEFINITION>
+</NETWORK>
+</BIF>"#;
+
+    fn state(name: &str) -> State {
+        State::Value(name.to_string())
+    }
+
+    #[test]
+    fn test_parse_asia_fragment() {
+        let bn = BayesianNetwork::from_xmlbif_str(ASIA_FRAGMENT).unwrap();
+        let tub = bn.get_id_from_name("tub").unwrap();
+        let lung = bn.get_id_from_name("lung").unwrap();
+        let either = bn.get_id_from_name("either").unwrap();
+
+        // Los padres se agregan antes que el hijo
+        assert!(tub < either && lung < either);
+
+        // either = lung OR tub; P(either) = 1 - 0.95 · 0.9
+        let distribution = bn.variable_elimination(either, &HashMap::new());
+        assert!((distribution[&state("yes")] - (1.0 - 0.95 * 0.9)).abs() < 1e-9);
+
+        // La tabla respeta el orden GIVEN (lung, tub) aunque los ids sean (tub, lung)
+        let only_tub = bn.variable_elimination(either, &HashMap::from([(lung, state("no")), (tub, state("yes"))]));
+        assert!((only_tub[&state("yes")] - 1.0).abs() < 1e-9);
+        let neither = bn.variable_elimination(either, &HashMap::from([(lung, state("no")), (tub, state("no"))]));
+        assert!((neither[&state("no")] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(ASIA_FRAGMENT.as_bytes()).unwrap();
+
+        let bn = BayesianNetwork::from_xmlbif(file.path().to_str().unwrap()).unwrap();
+        assert!(bn.get_id_from_name("either").is_some());
+
+        assert!(matches!(BayesianNetwork::from_xmlbif("/no/existe.xml"), Err(BNError::Io { .. })));
+    }
+
+    #[test]
+    fn test_invalid_tables() {
+        let bad_sum = ASIA_FRAGMENT.replace("<TABLE>0.1 0.9</TABLE>", "<TABLE>0.2 0.9</TABLE>");
+        assert!(matches!(BayesianNetwork::from_xmlbif_str(&bad_sum), Err(BNError::Network(_))));
+
+        let short = ASIA_FRAGMENT.replace("<TABLE>0.1 0.9</TABLE>", "<TABLE>0.1</TABLE>");
+        assert!(matches!(BayesianNetwork::from_xmlbif_str(&short), Err(BNError::InvalidTable { .. })));
+
+        let unknown = ASIA_FRAGMENT.replace("<GIVEN>tub</GIVEN>", "<GIVEN>smoke</GIVEN>");
+        assert_eq!(
+            BayesianNetwork::from_xmlbif_str(&unknown).err(),
+            Some(BNError::UnknownVariable("smoke".to_string()))
+        );
+
+        let duplicated = ASIA_FRAGMENT.replace(
+            "</NETWORK>",
+            "<DEFINITION><FOR>lung</FOR><TABLE>0.5 0.5</TABLE></DEFINITION>\n</NETWORK>",
+        );
+        assert_eq!(
+            BayesianNetwork::from_xmlbif_str(&duplicated).err(),
+            Some(BNError::Parse("DEFINITION de 'lung' repetida".to_string()))
+        );
+    }
+}
@@ -1,7 +1,9 @@
 pub mod implementations;
 pub mod algorithms;
 pub mod models;
+pub mod error;
 
 pub use implementations::*;
 pub use algorithms::*;
-pub use models::*;
\ No newline at end of file
+pub use models::*;
+pub use error::BNError;
\ No newline at end of file
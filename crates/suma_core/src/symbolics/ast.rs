@@ -13,7 +13,9 @@ pub enum Expr {
     Mul(Box<Expr>, Box<Expr>),
     Div(Box<Expr>, Box<Expr>),
     Neg(Box<Expr>),
-    // Expandir con Pow, Sin, Cos, etc.
+    Pow(Box<Expr>, Box<Expr>),
+    // Llamada a función de un argumento: sin(x), ln(x), ...
+    Func(String, Box<Expr>),
 }
 
 // Constructor rápido para variables
@@ -31,6 +33,8 @@ impl fmt::Display for Expr {
             Expr::Mul(lhs, rhs) => write!(f, "({} * {})", lhs, rhs),
             Expr::Div(lhs, rhs) => write!(f, "({} / {})", lhs, rhs),
             Expr::Neg(expr) => write!(f, "-{}", expr),
+            Expr::Pow(base, exp) => write!(f, "({} ^ {})", base, exp),
+            Expr::Func(name, arg) => write!(f, "{}({})", name, arg),
         }
     }
 }
\ No newline at end of file
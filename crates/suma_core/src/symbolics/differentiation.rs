@@ -0,0 +1,161 @@
+use super::ast::Expr;
+use crate::linear_algebra::DenseMatrix;
+
+/// Vector de derivadas parciales de `expr` respecto a cada variable de `vars`.
+pub fn gradient(expr: &Expr, vars: &[&str]) -> Vec<Expr> {
+    vars.iter().map(|var| expr.differentiate(var)).collect()
+}
+
+/// Matriz jacobiana: la fila `i` es el gradiente de `exprs[i]` respecto a `vars`.
+pub fn jacobian(exprs: &[Expr], vars: &[&str]) -> DenseMatrix<Expr> {
+    let data = exprs.iter().flat_map(|expr| gradient(expr, vars)).collect();
+    DenseMatrix::new(exprs.len(), vars.len(), data)
+}
+
+impl Expr {
+    /// Derivada simbólica respecto a `var`, ya simplificada.
+    /// Para funciones desconocidas `f(u)` se aplica la regla de la cadena con `f'(u)`,
+    /// que falla al evaluarse con `EvalError::UnknownFunction`.
+    pub fn differentiate(&self, var: &str) -> Expr {
+        self.derive(var).simplify()
+    }
+
+    /// Indica si la variable aparece en la expresión.
+    pub fn depends_on(&self, var: &str) -> bool {
+        match self {
+            Expr::Const(_) => false,
+            Expr::Var(name) => name == var,
+            Expr::Add(l, r) | Expr::Sub(l, r) | Expr::Mul(l, r) | Expr::Div(l, r) | Expr::Pow(l, r) => {
+                l.depends_on(var) || r.depends_on(var)
+            }
+            Expr::Neg(inner) | Expr::Func(_, inner) => inner.depends_on(var),
+        }
+    }
+
+    fn derive(&self, var: &str) -> Expr {
+        match self {
+            Expr::Const(_) => Expr::Const(0.0),
+            Expr::Var(name) => Expr::Const(if name == var { 1.0 } else { 0.0 }),
+
+            Expr::Add(u, v) => u.derive(var) + v.derive(var),
+            Expr::Sub(u, v) => u.derive(var) - v.derive(var),
+            Expr::Neg(u) => -u.derive(var),
+
+            // (uv)' = u'v + uv'
+            Expr::Mul(u, v) => (u.derive(var) * (**v).clone()) + ((**u).clone() * v.derive(var)),
+
+            // (u/v)' = (u'v - uv') / v^2
+            Expr::Div(u, v) => {
+                let numerator = (u.derive(var) * (**v).clone()) - ((**u).clone() * v.derive(var));
+                numerator / pow((**v).clone(), Expr::Const(2.0))
+            }
+
+            Expr::Pow(u, v) => {
+                if !v.depends_on(var) {
+                    // (u^n)' = n * u^(n-1) * u'
+                    let n = (**v).clone();
+                    n.clone() * pow((**u).clone(), n - 1.0) * u.derive(var)
+                } else if !u.depends_on(var) {
+                    // (a^v)' = a^v * ln(a) * v'
+                    self.clone() * func("ln", (**u).clone()) * v.derive(var)
+                } else {
+                    // (u^v)' = u^v * (v' ln(u) + v u' / u)
+                    let inner = (v.derive(var) * func("ln", (**u).clone()))
+                        + ((**v).clone() * u.derive(var) / (**u).clone());
+                    self.clone() * inner
+                }
+            }
+
+            Expr::Func(name, u) => {
+                let u = (**u).clone();
+                let outer = match name.as_str() {
+                    "sin" => func("cos", u.clone()),
+                    "cos" => -func("sin", u.clone()),
+                    "tan" => Expr::Const(1.0) / pow(func("cos", u.clone()), Expr::Const(2.0)),
+                    "asin" => Expr::Const(1.0) / func("sqrt", Expr::Const(1.0) - pow(u.clone(), Expr::Const(2.0))),
+                    "acos" => -(Expr::Const(1.0) / func("sqrt", Expr::Const(1.0) - pow(u.clone(), Expr::Const(2.0)))),
+                    "atan" => Expr::Const(1.0) / (Expr::Const(1.0) + pow(u.clone(), Expr::Const(2.0))),
+                    "sinh" => func("cosh", u.clone()),
+                    "cosh" => func("sinh", u.clone()),
+                    "tanh" => Expr::Const(1.0) - pow(func("tanh", u.clone()), Expr::Const(2.0)),
+                    "exp" => func("exp", u.clone()),
+                    "ln" => Expr::Const(1.0) / u.clone(),
+                    "log" => Expr::Const(1.0) / (u.clone() * func("ln", Expr::Const(10.0))),
+                    "sqrt" => Expr::Const(1.0) / (Expr::Const(2.0) * func("sqrt", u.clone())),
+                    "abs" => u.clone() / func("abs", u.clone()),
+                    other => func(&format!("{}'", other), u.clone()),
+                };
+                outer * u.derive(var)
+            }
+        }
+    }
+}
+
+fn pow(base: Expr, exp: Expr) -> Expr {
+    Expr::Pow(Box::new(base), Box::new(exp))
+}
+
+fn func(name: &str, arg: Expr) -> Expr {
+    Expr::Func(name.to_string(), Box::new(arg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gradient, jacobian};
+    use crate::symbolics::context::Context;
+    use crate::symbolics::parse;
+
+    fn derivative_at(input: &str, x: f64) -> f64 {
+        let mut ctx = Context::new();
+        ctx.set("x", x);
+        parse(input).unwrap().differentiate("x").evaluate(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_polynomial_derivative() {
+        // d/dx (3x^2 + 2x + 1) = 6x + 2
+        assert_eq!(derivative_at("3*x^2 + 2*x + 1", 2.0), 14.0);
+    }
+
+    #[test]
+    fn test_chain_and_quotient_rules() {
+        // d/dx sin(x^2) = 2x cos(x^2)
+        assert!((derivative_at("sin(x^2)", 1.5) - 3.0 * (2.25f64).cos()).abs() < 1e-12);
+        // d/dx 1/x = -1/x^2
+        assert!((derivative_at("1 / x", 2.0) + 0.25).abs() < 1e-12);
+        // d/dx x^x = x^x (ln x + 1)
+        assert!((derivative_at("x^x", 2.0) - 4.0 * (2f64.ln() + 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_gradient_of_squares() {
+        let expr = parse("x^2 + y^2").unwrap();
+        let grad = gradient(&expr, &["x", "y"]);
+        assert_eq!(grad.len(), 2);
+
+        let mut ctx = Context::new();
+        ctx.set("x", 3.0);
+        ctx.set("y", -1.5);
+        assert_eq!(grad[0].evaluate(&ctx).unwrap(), 6.0);
+        assert_eq!(grad[1].evaluate(&ctx).unwrap(), -3.0);
+    }
+
+    #[test]
+    fn test_jacobian_2x2() {
+        // F(x, y) = (x * y, x + 3 * y)
+        let exprs = [parse("x * y").unwrap(), parse("x + 3 * y").unwrap()];
+        let jac = jacobian(&exprs, &["x", "y"]);
+
+        assert_eq!((jac.rows, jac.cols), (2, 2));
+        assert_eq!(jac.get(0, 0), parse("y").unwrap());
+        assert_eq!(jac.get(0, 1), parse("x").unwrap());
+        assert_eq!(jac.get(1, 0), parse("1").unwrap());
+        assert_eq!(jac.get(1, 1), parse("3").unwrap());
+    }
+
+    #[test]
+    fn test_other_variables_are_constants() {
+        let expr = parse("a * x + a^2").unwrap();
+        assert_eq!(expr.differentiate("x"), parse("a").unwrap());
+    }
+}
@@ -4,6 +4,11 @@ use std::{error::Error, fmt};
 pub enum EvalError {
     VariableNotFound(String),
     DivisionByZero,
+    UnknownFunction(String),
+    // La expresión produce un valor no finito (NaN o infinito) en ese punto
+    UndefinedAt(f64),
+    InvalidArgument(String),
+    NoConvergence(usize),
     // Futuro: Podríamos agregar errores de sintaxis o tipos incompatibles aquí
 }
 
@@ -13,6 +18,10 @@ impl fmt::Display for EvalError {
         match self {
             EvalError::VariableNotFound(v) => write!(f, "Variable no definida: '{}'", v),
             EvalError::DivisionByZero => write!(f, "División por cero detectada"),
+            EvalError::UnknownFunction(name) => write!(f, "Función desconocida: '{}'", name),
+            EvalError::UndefinedAt(x) => write!(f, "La expresión no está definida en {}", x),
+            EvalError::InvalidArgument(msg) => write!(f, "Argumento inválido: {}", msg),
+            EvalError::NoConvergence(iter) => write!(f, "No se alcanzó convergencia tras {} iteraciones", iter),
         }
     }
 }
@@ -28,6 +37,58 @@ impl Error for EvalError {
     }
 }
 
+/// Error al convertir texto en una expresión simbólica.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    /// Posición (en caracteres) donde se detectó el error.
+    pub position: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(position: usize, message: impl Into<String>) -> Self {
+        ParseError { position, message: message.into() }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Error de sintaxis en la posición {}: {}", self.position, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error de las manipulaciones simbólicas (despejes, etc.).
+#[derive(Debug, PartialEq, Clone)]
+pub enum SymbolicError {
+    // La variable aparece dentro de un producto de términos con ella, una potencia,
+    // un denominador o una función
+    NonLinear(String),
+    // Coeficiente nulo: la ecuación es una identidad o una contradicción
+    InfiniteSolutions(String),
+    NoSolution(String),
+    // La variable no aparece y lo que queda depende de otros símbolos: la ecuación
+    // vale para todo valor si la condición es 0 y para ninguno si no (ej: `y + 1`)
+    DependsOnParameters { var: String, condition: String },
+}
+
+impl fmt::Display for SymbolicError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SymbolicError::NonLinear(v) => write!(f, "La ecuación no es lineal en '{}'", v),
+            SymbolicError::InfiniteSolutions(v) => write!(f, "Cualquier valor de '{}' satisface la ecuación", v),
+            SymbolicError::NoSolution(v) => write!(f, "Ningún valor de '{}' satisface la ecuación", v),
+            SymbolicError::DependsOnParameters { var, condition } => write!(
+                f, "La ecuación no depende de '{}': se cumple para todo valor si {} = 0 y para ninguno si no",
+                var, condition
+            ),
+        }
+    }
+}
+
+impl Error for SymbolicError {}
+
 #[cfg(test)]
 mod tests {
     use super::EvalError;
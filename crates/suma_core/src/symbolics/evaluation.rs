@@ -49,10 +49,43 @@ impl Expr {
                 let val = expr.evaluate(ctx)?;
                 Ok(-val)
             },
+
+            Expr::Pow(base, exp) => {
+                let b = base.evaluate(ctx)?;
+                let e = exp.evaluate(ctx)?;
+                Ok(b.powf(e))
+            },
+
+            Expr::Func(name, arg) => {
+                let x = arg.evaluate(ctx)?;
+                apply_function(name, x)
+            },
         }
     }
 }
 
+/// Funciones elementales reconocidas por `evaluate`.
+fn apply_function(name: &str, x: f64) -> Result<f64, EvalError> {
+    let value = match name {
+        "sin" => x.sin(),
+        "cos" => x.cos(),
+        "tan" => x.tan(),
+        "asin" => x.asin(),
+        "acos" => x.acos(),
+        "atan" => x.atan(),
+        "sinh" => x.sinh(),
+        "cosh" => x.cosh(),
+        "tanh" => x.tanh(),
+        "exp" => x.exp(),
+        "ln" => x.ln(),
+        "log" => x.log10(),
+        "sqrt" => x.sqrt(),
+        "abs" => x.abs(),
+        _ => return Err(EvalError::UnknownFunction(name.to_string())),
+    };
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::symbolics::ast::{var, Expr};
@@ -94,4 +127,19 @@ fn test_missing_variable_error() {
         _ => panic!("Debería haber fallado por variable inexistente"),
     }
 }
+
+#[test]
+fn test_pow_and_functions() {
+    let mut ctx = Context::new();
+    ctx.set("x", 2.0);
+
+    let expr = Expr::Pow(Box::new(var("x")), Box::new(Expr::Const(3.0)));
+    assert_eq!(expr.evaluate(&ctx).unwrap(), 8.0);
+
+    let expr = Expr::Func("exp".to_string(), Box::new(Expr::Const(0.0)));
+    assert_eq!(expr.evaluate(&ctx).unwrap(), 1.0);
+
+    let expr = Expr::Func("foo".to_string(), Box::new(var("x")));
+    assert_eq!(expr.evaluate(&ctx), Err(EvalError::UnknownFunction("foo".to_string())));
+}
 }
\ No newline at end of file
@@ -0,0 +1,64 @@
+use super::ast::Expr;
+use super::context::Context;
+use super::error::EvalError;
+
+impl Expr {
+    /// Integral definida de la expresión respecto a `var` en [a, b] mediante la regla de Simpson
+    /// con `n` subintervalos (`n` debe ser par y positivo).
+    /// Falla si el integrando no está definido en alguno de los puntos de muestreo.
+    pub fn integrate_numeric(&self, var: &str, a: f64, b: f64, n: usize) -> Result<f64, EvalError> {
+        if n == 0 || n % 2 != 0 {
+            return Err(EvalError::InvalidArgument(format!(
+                "la regla de Simpson requiere un número par de subintervalos (n = {})", n
+            )));
+        }
+
+        let h = (b - a) / n as f64;
+        let mut ctx = Context::new();
+        let mut sample = |x: f64| -> Result<f64, EvalError> {
+            ctx.set(var, x);
+            let value = match self.evaluate(&ctx) {
+                Err(EvalError::DivisionByZero) => return Err(EvalError::UndefinedAt(x)),
+                other => other?,
+            };
+            if value.is_finite() { Ok(value) } else { Err(EvalError::UndefinedAt(x)) }
+        };
+
+        let mut sum = sample(a)? + sample(b)?;
+        for i in 1..n {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            sum += weight * sample(a + i as f64 * h)?;
+        }
+
+        Ok(sum * h / 3.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::symbolics::ast::var;
+    use crate::symbolics::error::EvalError;
+    use crate::symbolics::parse;
+
+    #[test]
+    fn test_integrate_x_squared() {
+        let expr = parse("x^2").unwrap();
+        let area = expr.integrate_numeric("x", 0.0, 1.0, 10).unwrap();
+        assert!((area - 1.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_integrate_undefined_point() {
+        let expr = parse("1 / x").unwrap();
+        assert_eq!(expr.integrate_numeric("x", 0.0, 1.0, 4), Err(EvalError::UndefinedAt(0.0)));
+
+        let expr = parse("ln(x)").unwrap();
+        assert!(matches!(expr.integrate_numeric("x", -1.0, 1.0, 4), Err(EvalError::UndefinedAt(_))));
+    }
+
+    #[test]
+    fn test_integrate_requires_even_intervals() {
+        let expr = var("x");
+        assert!(matches!(expr.integrate_numeric("x", 0.0, 1.0, 3), Err(EvalError::InvalidArgument(_))));
+    }
+}
@@ -5,5 +5,14 @@ pub mod evaluation;
 pub mod error;
 pub mod simplification;
 pub mod substitution;
+pub mod parser;
+pub mod notation;
+pub mod integration;
+pub mod differentiation;
+pub mod roots;
+pub mod solve;
 
-pub use context::Context;
\ No newline at end of file
+pub use context::Context;
+pub use parser::parse;
+pub use differentiation::{gradient, jacobian};
+pub use solve::solve_linear;
\ No newline at end of file
@@ -0,0 +1,211 @@
+use super::ast::Expr;
+
+impl Expr {
+    /// Convierte el AST a notación prefija, p. ej. `ADD(MUL(2, x), 1)`.
+    pub fn to_prefix_notation(&self) -> String {
+        match self {
+            Expr::Const(c) => c.to_string(),
+            Expr::Var(v) => v.clone(),
+            Expr::Add(lhs, rhs) => format!("ADD({}, {})", lhs.to_prefix_notation(), rhs.to_prefix_notation()),
+            Expr::Sub(lhs, rhs) => format!("SUB({}, {})", lhs.to_prefix_notation(), rhs.to_prefix_notation()),
+            Expr::Mul(lhs, rhs) => format!("MUL({}, {})", lhs.to_prefix_notation(), rhs.to_prefix_notation()),
+            Expr::Div(lhs, rhs) => format!("DIV({}, {})", lhs.to_prefix_notation(), rhs.to_prefix_notation()),
+            Expr::Pow(base, exp) => format!("POW({}, {})", base.to_prefix_notation(), exp.to_prefix_notation()),
+            Expr::Neg(inner) => format!("NEG({})", inner.to_prefix_notation()),
+            Expr::Func(name, arg) => format!("{}({})", name.to_uppercase(), arg.to_prefix_notation()),
+        }
+    }
+
+    /// Notación infija con los paréntesis mínimos según la precedencia.
+    /// `symbolics::parse` lee el resultado como una expresión equivalente (mismo
+    /// valor); el árbol coincide salvo en las constantes negativas, que vuelven
+    /// como `Neg(Const)`. Las constantes no finitas (`inf`, `NaN`) no tienen
+    /// forma infija válida.
+    pub fn to_infix_notation(&self) -> String {
+        match self {
+            Expr::Const(c) => c.to_string(),
+            Expr::Var(v) => v.clone(),
+            Expr::Add(lhs, rhs) => format!("{} + {}", self.left_operand(lhs), self.right_operand(rhs)),
+            Expr::Sub(lhs, rhs) => format!("{} - {}", self.left_operand(lhs), self.right_operand(rhs)),
+            Expr::Mul(lhs, rhs) => format!("{} * {}", self.left_operand(lhs), self.right_operand(rhs)),
+            Expr::Div(lhs, rhs) => format!("{} / {}", self.left_operand(lhs), self.right_operand(rhs)),
+            // La potencia asocia por la derecha
+            Expr::Pow(base, exp) => {
+                let base = wrap_if(base, base.precedence() <= self.precedence());
+                let exp = wrap_if(exp, exp.precedence() < PREC_NEG);
+                format!("{}^{}", base, exp)
+            }
+            Expr::Neg(inner) => format!("-{}", wrap_if(inner, inner.precedence() < PREC_NEG)),
+            Expr::Func(name, arg) => format!("{}({})", name, arg.to_infix_notation()),
+        }
+    }
+
+    /// Notación LaTeX: `\frac{}{}` para la división, yuxtaposición para
+    /// coeficiente por variable (`2x`) y `\cdot` en el resto de productos.
+    pub fn to_latex(&self) -> String {
+        match self {
+            Expr::Const(c) => c.to_string(),
+            Expr::Var(v) => v.clone(),
+            Expr::Add(lhs, rhs) => format!("{} + {}", self.latex_left(lhs), self.latex_right(rhs)),
+            Expr::Sub(lhs, rhs) => format!("{} - {}", self.latex_left(lhs), self.latex_right(rhs)),
+            Expr::Mul(lhs, rhs) => {
+                // `2 3^{x}` se leería como 23^{x}: con base constante se usa \cdot
+                let juxtapose = matches!(**lhs, Expr::Const(c) if c >= 0.0)
+                    && match &**rhs {
+                        Expr::Var(_) | Expr::Func(..) => true,
+                        Expr::Pow(base, _) => !matches!(**base, Expr::Const(_)),
+                        _ => false,
+                    };
+                if juxtapose {
+                    format!("{}{}", lhs.to_latex(), self.latex_right(rhs))
+                } else {
+                    format!("{} \\cdot {}", self.latex_left(lhs), self.latex_right(rhs))
+                }
+            }
+            // La barra de fracción ya agrupa: no hacen falta paréntesis
+            Expr::Div(lhs, rhs) => format!("\\frac{{{}}}{{{}}}", lhs.to_latex(), rhs.to_latex()),
+            // Las llaves agrupan el exponente; la base sí usa la precedencia normal
+            Expr::Pow(base, exp) => {
+                let base = latex_wrap_if(base, base.precedence() <= self.precedence());
+                format!("{}^{{{}}}", base, exp.to_latex())
+            }
+            Expr::Neg(inner) => format!("-{}", latex_wrap_if(inner, inner.latex_precedence() < PREC_NEG)),
+            Expr::Func(name, arg) => match name.as_str() {
+                "sqrt" => format!("\\sqrt{{{}}}", arg.to_latex()),
+                "abs" => format!("\\left|{}\\right|", arg.to_latex()),
+                "log" => format!("\\log_{{10}}\\left({}\\right)", arg.to_latex()),
+                "sin" | "cos" | "tan" | "sinh" | "cosh" | "tanh" | "exp" | "ln" => {
+                    format!("\\{}\\left({}\\right)", name, arg.to_latex())
+                }
+                "asin" | "acos" | "atan" => {
+                    format!("\\arc{}\\left({}\\right)", &name[1..], arg.to_latex())
+                }
+                _ => format!("\\operatorname{{{}}}\\left({}\\right)", name, arg.to_latex()),
+            },
+        }
+    }
+
+    // En LaTeX la fracción se comporta como un átomo frente a +, - y *
+    fn latex_precedence(&self) -> u8 {
+        match self {
+            Expr::Div(..) => 5,
+            _ => self.precedence(),
+        }
+    }
+
+    fn latex_left(&self, child: &Expr) -> String {
+        latex_wrap_if(child, child.latex_precedence() < self.precedence())
+    }
+
+    // A la derecha de +, - o \cdot una negación va entre paréntesis: `x - \left(-y\right)`
+    fn latex_right(&self, child: &Expr) -> String {
+        let precedence = child.latex_precedence();
+        latex_wrap_if(child, precedence <= self.precedence() || precedence == PREC_NEG)
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Add(..) | Expr::Sub(..) => 1,
+            Expr::Mul(..) | Expr::Div(..) => 2,
+            Expr::Neg(_) => PREC_NEG,
+            Expr::Const(c) if *c < 0.0 => PREC_NEG,
+            Expr::Pow(..) => 4,
+            Expr::Const(_) | Expr::Var(_) | Expr::Func(..) => 5,
+        }
+    }
+
+    fn left_operand(&self, child: &Expr) -> String {
+        wrap_if(child, child.precedence() < self.precedence())
+    }
+
+    fn right_operand(&self, child: &Expr) -> String {
+        wrap_if(child, child.precedence() <= self.precedence())
+    }
+}
+
+const PREC_NEG: u8 = 3;
+
+fn wrap_if(expr: &Expr, parens: bool) -> String {
+    if parens {
+        format!("({})", expr.to_infix_notation())
+    } else {
+        expr.to_infix_notation()
+    }
+}
+
+fn latex_wrap_if(expr: &Expr, parens: bool) -> String {
+    if parens {
+        format!("\\left({}\\right)", expr.to_latex())
+    } else {
+        expr.to_latex()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::symbolics::parse;
+
+    #[test]
+    fn test_notation_round_trip() {
+        for input in ["2 * x + 3 * (y - 1)", "a - (b - c)", "-x^2", "(-x)^2", "2^3^2", "(2^3)^2", "sin(x) / (1 + x)"] {
+            let expr = parse(input).unwrap();
+            assert_eq!(expr.to_infix_notation(), input);
+            assert_eq!(parse(&expr.to_infix_notation()).unwrap(), expr);
+        }
+    }
+
+    #[test]
+    fn test_infix_round_trip_of_built_trees() {
+        use crate::symbolics::{ast::Expr, Context};
+
+        let c = |v: f64| Box::new(Expr::Const(v));
+        let x = || Box::new(Expr::Var("x".into()));
+        let cases = [
+            Expr::Mul(x(), c(-2.0)),
+            Expr::Sub(x(), c(-2.0)),
+            Expr::Pow(c(-2.0), x()),
+            Expr::Pow(x(), Box::new(Expr::Neg(x()))),
+            Expr::Neg(Box::new(Expr::Neg(x()))),
+            Expr::Div(x(), Box::new(Expr::Mul(c(3.0), x()))),
+        ];
+
+        let mut ctx = Context::new();
+        ctx.set("x", 3.0);
+        for expr in cases {
+            let text = expr.to_infix_notation();
+            let reparsed = parse(&text).unwrap_or_else(|e| panic!("'{}' no se pudo leer: {}", text, e));
+            assert_eq!(reparsed.evaluate(&ctx).unwrap(), expr.evaluate(&ctx).unwrap(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_prefix_notation() {
+        let expr = parse("2*x + 3*(y - 1)").unwrap();
+        assert_eq!(expr.to_prefix_notation(), "ADD(MUL(2, x), MUL(3, SUB(y, 1)))");
+    }
+
+    #[test]
+    fn test_latex_output() {
+        let cases = [
+            ("2 * x + 3 * (y - 1)", r"2x + 3 \cdot \left(y - 1\right)"),
+            ("(a + b) / (c - 1)", r"\frac{a + b}{c - 1}"),
+            ("-(x / 2)", r"-\frac{x}{2}"),
+            ("-(a + b)", r"-\left(a + b\right)"),
+            ("(x / y)^2", r"\left(\frac{x}{y}\right)^{2}"),
+            ("x^(n + 1)", r"x^{n + 1}"),
+            ("x * y / 2", r"\frac{x \cdot y}{2}"),
+            ("3 * sin(x)^2", r"3\sin\left(x\right)^{2}"),
+            ("sqrt(x) + atan(y) + foo(z)", r"\sqrt{x} + \arctan\left(y\right) + \operatorname{foo}\left(z\right)"),
+            ("2 * 3^x", r"2 \cdot 3^{x}"),
+            ("2 * 0.5^x", r"2 \cdot 0.5^{x}"),
+            ("2 * y^x", r"2y^{x}"),
+            ("x - (-y)", r"x - \left(-y\right)"),
+            ("x + (-y)", r"x + \left(-y\right)"),
+            ("2 * (-x)", r"2 \cdot \left(-x\right)"),
+            ("-x * 2", r"-x \cdot 2"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse(input).unwrap().to_latex(), expected, "entrada: {}", input);
+        }
+    }
+}
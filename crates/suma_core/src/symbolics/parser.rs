@@ -0,0 +1,246 @@
+// src/symbolics/parser.rs
+use super::ast::Expr;
+use super::error::ParseError;
+
+/// Convierte un texto como `2*x + 3*(y - 1)` en un `Expr`.
+///
+/// Precedencia (de menor a mayor): `+ -`, `* /`, negación unaria, `^`.
+/// La potencia es asociativa por la derecha: `2^3^2 = 2^(3^2)`.
+/// Un identificador seguido de `(` se interpreta como llamada a función.
+pub fn parse(input: &str) -> Result<Expr, ParseError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser { tokens, pos: 0, end: input.chars().count() };
+    let expr = parser.expression()?;
+
+    match parser.peek() {
+        None => Ok(expr),
+        Some((token, position)) => Err(ParseError::new(
+            *position,
+            format!("Símbolo inesperado '{}'", token),
+        )),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(char),
+    LParen,
+    RParen,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Ident(name) => write!(f, "{}", name),
+            Token::Op(c) => write!(f, "{}", c),
+            Token::LParen => write!(f, "("),
+            Token::RParen => write!(f, ")"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '+' | '-' | '*' | '/' | '^' => {
+                tokens.push((Token::Op(c), i));
+                i += 1;
+            }
+            '(' => {
+                tokens.push((Token::LParen, i));
+                i += 1;
+            }
+            ')' => {
+                tokens.push((Token::RParen, i));
+                i += 1;
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let value = text
+                    .parse::<f64>()
+                    .map_err(|_| ParseError::new(start, format!("Número inválido '{}'", text)))?;
+                tokens.push((Token::Number(value), start));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push((Token::Ident(chars[start..i].iter().collect()), start));
+            }
+            other => {
+                return Err(ParseError::new(i, format!("Carácter no reconocido '{}'", other)));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    // Posición de fin de entrada, para reportar expresiones incompletas
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<(Token, usize)> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat_op(&mut self, ops: &[char]) -> Option<char> {
+        match self.peek() {
+            Some((Token::Op(c), _)) if ops.contains(c) => {
+                let c = *c;
+                self.pos += 1;
+                Some(c)
+            }
+            _ => None,
+        }
+    }
+
+    // expression := term (('+' | '-') term)*
+    fn expression(&mut self) -> Result<Expr, ParseError> {
+        let mut lhs = self.term()?;
+        while let Some(op) = self.eat_op(&['+', '-']) {
+            let rhs = self.term()?;
+            lhs = match op {
+                '+' => Expr::Add(Box::new(lhs), Box::new(rhs)),
+                _ => Expr::Sub(Box::new(lhs), Box::new(rhs)),
+            };
+        }
+        Ok(lhs)
+    }
+
+    // term := unary (('*' | '/') unary)*
+    fn term(&mut self) -> Result<Expr, ParseError> {
+        let mut lhs = self.unary()?;
+        while let Some(op) = self.eat_op(&['*', '/']) {
+            let rhs = self.unary()?;
+            lhs = match op {
+                '*' => Expr::Mul(Box::new(lhs), Box::new(rhs)),
+                _ => Expr::Div(Box::new(lhs), Box::new(rhs)),
+            };
+        }
+        Ok(lhs)
+    }
+
+    // unary := '-' unary | '+' unary | power
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        match self.eat_op(&['-', '+']) {
+            Some('-') => Ok(Expr::Neg(Box::new(self.unary()?))),
+            Some(_) => self.unary(),
+            None => self.power(),
+        }
+    }
+
+    // power := primary ('^' unary)?
+    fn power(&mut self) -> Result<Expr, ParseError> {
+        let base = self.primary()?;
+        if self.eat_op(&['^']).is_some() {
+            let exponent = self.unary()?;
+            return Ok(Expr::Pow(Box::new(base), Box::new(exponent)));
+        }
+        Ok(base)
+    }
+
+    // primary := número | identificador | identificador '(' expression ')' | '(' expression ')'
+    fn primary(&mut self) -> Result<Expr, ParseError> {
+        match self.next() {
+            Some((Token::Number(value), _)) => Ok(Expr::Const(value)),
+            Some((Token::Ident(name), _)) => {
+                if matches!(self.peek(), Some((Token::LParen, _))) {
+                    self.pos += 1;
+                    let arg = self.expression()?;
+                    self.expect_rparen()?;
+                    Ok(Expr::Func(name, Box::new(arg)))
+                } else {
+                    Ok(Expr::Var(name))
+                }
+            }
+            Some((Token::LParen, _)) => {
+                let inner = self.expression()?;
+                self.expect_rparen()?;
+                Ok(inner)
+            }
+            Some((token, position)) => Err(ParseError::new(
+                position,
+                format!("Se esperaba un número, variable o '(' pero se encontró '{}'", token),
+            )),
+            None => Err(ParseError::new(self.end, "Expresión incompleta")),
+        }
+    }
+
+    fn expect_rparen(&mut self) -> Result<(), ParseError> {
+        match self.next() {
+            Some((Token::RParen, _)) => Ok(()),
+            Some((token, position)) => Err(ParseError::new(
+                position,
+                format!("Se esperaba ')' pero se encontró '{}'", token),
+            )),
+            None => Err(ParseError::new(self.end, "Falta ')'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::symbolics::ast::var;
+    use crate::symbolics::context::Context;
+
+    #[test]
+    fn test_parse_and_evaluate() {
+        let expr = parse("2*x + 3*(y - 1)").unwrap();
+        assert_eq!(expr, (Expr::from(2.0) * var("x")) + (Expr::from(3.0) * (var("y") - 1.0)));
+
+        let mut ctx = Context::new();
+        ctx.set("x", 4.0);
+        ctx.set("y", 2.0);
+        assert_eq!(expr.evaluate(&ctx).unwrap(), 11.0);
+    }
+
+    #[test]
+    fn test_precedence_and_associativity() {
+        let ctx = Context::new();
+        assert_eq!(parse("2 + 3 * 4").unwrap().evaluate(&ctx).unwrap(), 14.0);
+        assert_eq!(parse("10 - 4 - 3").unwrap().evaluate(&ctx).unwrap(), 3.0);
+        assert_eq!(parse("2 ^ 3 ^ 2").unwrap().evaluate(&ctx).unwrap(), 512.0);
+        assert_eq!(parse("-2 ^ 2").unwrap().evaluate(&ctx).unwrap(), -4.0);
+    }
+
+    #[test]
+    fn test_function_call() {
+        let expr = parse("sin(x) + sqrt(4)").unwrap();
+        let mut ctx = Context::new();
+        ctx.set("x", 0.0);
+        assert_eq!(expr.evaluate(&ctx).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("2 * (x + 1").unwrap_err().position, 10);
+        assert_eq!(parse("2 + * 3").unwrap_err().position, 4);
+        assert_eq!(parse("x $ y").unwrap_err().position, 2);
+    }
+}
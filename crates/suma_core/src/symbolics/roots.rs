@@ -0,0 +1,67 @@
+use super::ast::Expr;
+use super::context::Context;
+use super::error::EvalError;
+
+impl Expr {
+    /// Busca una raíz de la expresión respecto a `var` con el método de Newton-Raphson,
+    /// partiendo de `guess`. La derivada se obtiene con `differentiate`.
+    /// Termina cuando |f(x)| o el paso son menores que `tol`.
+    pub fn find_root(&self, var: &str, guess: f64, tol: f64, max_iter: usize) -> Result<f64, EvalError> {
+        let derivative = self.differentiate(var);
+        let mut ctx = Context::new();
+        let mut x = guess;
+
+        for _ in 0..max_iter {
+            ctx.set(var, x);
+            let fx = self.evaluate(&ctx)?;
+            if !fx.is_finite() {
+                return Err(EvalError::UndefinedAt(x));
+            }
+            if fx.abs() < tol {
+                return Ok(x);
+            }
+
+            let dfx = derivative.evaluate(&ctx)?;
+            if dfx == 0.0 {
+                return Err(EvalError::DivisionByZero);
+            }
+
+            let next = x - fx / dfx;
+            if !next.is_finite() {
+                return Err(EvalError::UndefinedAt(x));
+            }
+            if (next - x).abs() < tol {
+                return Ok(next);
+            }
+            x = next;
+        }
+
+        Err(EvalError::NoConvergence(max_iter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::symbolics::error::EvalError;
+    use crate::symbolics::parse;
+
+    #[test]
+    fn test_square_root_of_two() {
+        let expr = parse("x^2 - 2").unwrap();
+        let root = expr.find_root("x", 1.0, 1e-12, 50).unwrap();
+        assert!((root - 2f64.sqrt()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_zero_derivative() {
+        // f'(0) = 0 en x^2 + 1
+        let expr = parse("x^2 + 1").unwrap();
+        assert_eq!(expr.find_root("x", 0.0, 1e-9, 50), Err(EvalError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_no_convergence() {
+        let expr = parse("x^2 + 1").unwrap();
+        assert_eq!(expr.find_root("x", 0.5, 1e-12, 5), Err(EvalError::NoConvergence(5)));
+    }
+}
@@ -26,6 +26,9 @@ impl Expr {
                     // (-a) + a = 0
                     (Expr::Neg(a_inner), b) if *a_inner == b => Expr::Const(0.0),
 
+                    // 4. Identidad pitagórica: sin²(x) + cos²(x) = 1
+                    (a, b) if is_pythagorean_pair(&a, &b) => Expr::Const(1.0),
+
                     // Default
                     (new_l, new_r) => Expr::Add(Box::new(new_l), Box::new(new_r)),
                 }
@@ -122,8 +125,59 @@ impl Expr {
                     new_inner => Expr::Neg(Box::new(new_inner)),
                 }
             },
+
+            Expr::Pow(base, exp) => {
+                let b = base.simplify();
+                let e = exp.simplify();
+
+                match (b, e) {
+                    // Constant folding solo si el resultado es un número real finito
+                    (Expr::Const(c1), Expr::Const(c2)) if c1.powf(c2).is_finite() => Expr::Const(c1.powf(c2)),
+                    // x^0 = 1
+                    (_, Expr::Const(c)) if c == 0.0 => Expr::Const(1.0),
+                    // x^1 = x
+                    (expr, Expr::Const(c)) if c == 1.0 => expr,
+                    // 1^x = 1
+                    (Expr::Const(c), _) if c == 1.0 => Expr::Const(1.0),
+
+                    (new_b, new_e) => Expr::Pow(Box::new(new_b), Box::new(new_e)),
+                }
+            },
+
+            Expr::Func(name, arg) => {
+                let a = arg.simplify();
+                match (name.as_str(), a) {
+                    ("sin", Expr::Const(c)) if c == 0.0 => Expr::Const(0.0),
+                    ("cos", Expr::Const(c)) if c == 0.0 => Expr::Const(1.0),
+                    ("exp", Expr::Const(c)) if c == 0.0 => Expr::Const(1.0),
+                    ("ln", Expr::Const(c)) if c == 1.0 => Expr::Const(0.0),
+                    // ln(exp(x)) = x
+                    ("ln", Expr::Func(inner_name, inner)) if inner_name == "exp" => *inner,
+
+                    (_, new_arg) => Expr::Func(name.clone(), Box::new(new_arg)),
+                }
+            },
+        }
+    }
+}
+
+/// Detecta `sin(x)^2` y `cos(x)^2` con el mismo argumento, en cualquier orden.
+fn is_pythagorean_pair(a: &Expr, b: &Expr) -> bool {
+    fn squared_func<'a>(expr: &'a Expr, func: &str) -> Option<&'a Expr> {
+        match expr {
+            Expr::Pow(base, exp) if **exp == Expr::Const(2.0) => match &**base {
+                Expr::Func(name, arg) if name == func => Some(arg),
+                _ => None,
+            },
+            _ => None,
         }
     }
+
+    let matches = |x: &Expr, y: &Expr| match (squared_func(x, "sin"), squared_func(y, "cos")) {
+        (Some(s), Some(c)) => s == c,
+        _ => false,
+    };
+    matches(a, b) || matches(b, a)
 }
 
 #[cfg(test)]
@@ -154,6 +208,69 @@ mod tests {
         assert_eq!(simplified, Expr::Const(0.0));
     }
 
+    fn func(name: &str, arg: Expr) -> Expr {
+        Expr::Func(name.to_string(), Box::new(arg))
+    }
+
+    fn square(expr: Expr) -> Expr {
+        Expr::Pow(Box::new(expr), Box::new(Expr::Const(2.0)))
+    }
+
+    #[test]
+    fn test_function_identities() {
+        assert_eq!(func("sin", Expr::from(0.0)).simplify(), Expr::Const(0.0));
+        assert_eq!(func("cos", Expr::from(0.0)).simplify(), Expr::Const(1.0));
+        assert_eq!(func("ln", Expr::from(1.0)).simplify(), Expr::Const(0.0));
+        assert_eq!(func("exp", Expr::from(0.0)).simplify(), Expr::Const(1.0));
+        // El argumento se simplifica antes: exp(x - x) -> exp(0) -> 1
+        assert_eq!(func("exp", var("x") - var("x")).simplify(), Expr::Const(1.0));
+    }
+
+    #[test]
+    fn test_ln_of_exp() {
+        let expr = func("ln", func("exp", var("x") * 2.0));
+        assert_eq!(expr.simplify(), var("x") * 2.0);
+    }
+
+    #[test]
+    fn test_pythagorean_identity() {
+        let x = var("x") + 1.0;
+        let expr = square(func("sin", x.clone())) + square(func("cos", x.clone()));
+        assert_eq!(expr.simplify(), Expr::Const(1.0));
+
+        let swapped = square(func("cos", x.clone())) + square(func("sin", x));
+        assert_eq!(swapped.simplify(), Expr::Const(1.0));
+    }
+
+    #[test]
+    fn test_non_matching_functions_unchanged() {
+        let untouched = vec![
+            func("sin", Expr::from(1.0)),
+            func("ln", var("x")),
+            func("exp", func("ln", var("x"))),
+            square(func("sin", var("x"))) + square(func("cos", var("y"))),
+            square(func("sin", var("x"))) + square(func("sin", var("x"))),
+        ];
+        for expr in untouched {
+            assert_eq!(expr.simplify(), expr);
+        }
+    }
+
+    #[test]
+    fn test_power_rules() {
+        let pow = |b: Expr, e: Expr| Expr::Pow(Box::new(b), Box::new(e));
+
+        assert_eq!(pow(var("x"), Expr::from(0.0)).simplify(), Expr::Const(1.0));
+        assert_eq!(pow(var("x") + 0.0, Expr::from(1.0)).simplify(), var("x"));
+        assert_eq!(pow(Expr::from(1.0), var("y")).simplify(), Expr::Const(1.0));
+        // El exponente se simplifica antes: 2^(1 + 2) -> 8
+        assert_eq!(pow(Expr::from(2.0), Expr::from(1.0) + 2.0).simplify(), Expr::Const(8.0));
+        // Sin plegar si el resultado no es real
+        let complex = pow(Expr::from(-8.0), Expr::from(0.5));
+        assert_eq!(complex.simplify(), complex);
+        assert_eq!(pow(var("x"), Expr::from(3.0)).simplify(), pow(var("x"), Expr::from(3.0)));
+    }
+
     #[test]
     fn test_complex_reduction() {
         // 1 * x + (3 - 3) -> x + 0 -> x
@@ -0,0 +1,118 @@
+use super::ast::Expr;
+use super::error::SymbolicError;
+
+/// Despeja `var` de la ecuación `equation = 0`, suponiéndola lineal en `var`:
+/// la escribe como `a·var + b` (con `a` y `b` libres de `var`) y devuelve `-b / a`
+/// simplificado. Las demás variables se tratan como parámetros.
+pub fn solve_linear(equation: &Expr, var: &str) -> Result<Expr, SymbolicError> {
+    // Simplificar antes quita casos como x^1 o x * 0
+    let (coefficient, constant) = linear_parts(&equation.simplify(), var)?;
+    let coefficient = coefficient.simplify();
+    let constant = constant.simplify();
+
+    if coefficient == Expr::Const(0.0) {
+        return Err(match constant {
+            Expr::Const(c) if c == 0.0 => SymbolicError::InfiniteSolutions(var.to_string()),
+            Expr::Const(_) => SymbolicError::NoSolution(var.to_string()),
+            // Con parámetros no se puede decidir entre los dos casos anteriores
+            symbolic => SymbolicError::DependsOnParameters {
+                var: var.to_string(),
+                condition: symbolic.to_infix_notation(),
+            },
+        });
+    }
+
+    Ok(Expr::Div(Box::new(Expr::Neg(Box::new(constant))), Box::new(coefficient)).simplify())
+}
+
+/// Descompone `expr` en `(coeficiente, término independiente)` respecto a `var`.
+fn linear_parts(expr: &Expr, var: &str) -> Result<(Expr, Expr), SymbolicError> {
+    let non_linear = || SymbolicError::NonLinear(var.to_string());
+
+    match expr {
+        Expr::Var(name) if name == var => Ok((Expr::Const(1.0), Expr::Const(0.0))),
+        _ if !expr.depends_on(var) => Ok((Expr::Const(0.0), expr.clone())),
+
+        Expr::Add(lhs, rhs) | Expr::Sub(lhs, rhs) => {
+            let (a1, b1) = linear_parts(lhs, var)?;
+            let (a2, b2) = linear_parts(rhs, var)?;
+            Ok(match expr {
+                Expr::Add(..) => (a1 + a2, b1 + b2),
+                _ => (a1 - a2, b1 - b2),
+            })
+        }
+        Expr::Neg(inner) => {
+            let (a, b) = linear_parts(inner, var)?;
+            Ok((Expr::Neg(Box::new(a)), Expr::Neg(Box::new(b))))
+        }
+        // (a1·x + b1)(a2·x + b2) es lineal solo si uno de los factores no depende de x
+        Expr::Mul(lhs, rhs) => {
+            let (a1, b1) = linear_parts(lhs, var)?;
+            let (a2, b2) = linear_parts(rhs, var)?;
+            if a1.simplify() != Expr::Const(0.0) && a2.simplify() != Expr::Const(0.0) {
+                return Err(non_linear());
+            }
+            Ok((a1 * b2.clone() + b1.clone() * a2, b1 * b2))
+        }
+        Expr::Div(lhs, rhs) if !rhs.depends_on(var) => {
+            let (a, b) = linear_parts(lhs, var)?;
+            Ok((a / (**rhs).clone(), b / (**rhs).clone()))
+        }
+        _ => Err(non_linear()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::symbolics::{parse, Context};
+
+    #[test]
+    fn test_solve_numeric_equation() {
+        // 3x + 6 = 0 -> x = -2
+        let equation = parse("3 * x + 6").unwrap();
+        assert_eq!(solve_linear(&equation, "x").unwrap(), Expr::Const(-2.0));
+
+        // 2(x - 1) - (x + 4) / 2 = 0 -> x = 8/3
+        let equation = parse("2 * (x - 1) - (x + 4) / 2").unwrap();
+        let value = solve_linear(&equation, "x").unwrap().evaluate(&Context::new()).unwrap();
+        assert!((value - 8.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_solve_with_parameters() {
+        // a·x - b = 0 -> x = b / a
+        let equation = parse("a * x - b").unwrap();
+        let solution = solve_linear(&equation, "x").unwrap();
+
+        let mut ctx = Context::new();
+        ctx.set("a", 4.0);
+        ctx.set("b", 10.0);
+        assert_eq!(solution.evaluate(&ctx).unwrap(), 2.5);
+        assert!(!solution.depends_on("x"));
+    }
+
+    #[test]
+    fn test_solve_rejects_non_linear() {
+        for input in ["x * x + 1", "x^2 - 4", "1 / x - 2", "sin(x)", "x * (x + y)"] {
+            let equation = parse(input).unwrap();
+            assert_eq!(solve_linear(&equation, "x"), Err(SymbolicError::NonLinear("x".to_string())), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_solve_degenerate_equations() {
+        assert_eq!(
+            solve_linear(&parse("x - x").unwrap(), "x"),
+            Err(SymbolicError::InfiniteSolutions("x".to_string()))
+        );
+        assert_eq!(
+            solve_linear(&parse("x - x + 3").unwrap(), "x"),
+            Err(SymbolicError::NoSolution("x".to_string()))
+        );
+        assert_eq!(
+            solve_linear(&parse("y + 1").unwrap(), "x"),
+            Err(SymbolicError::DependsOnParameters { var: "x".to_string(), condition: "y + 1".to_string() })
+        );
+    }
+}
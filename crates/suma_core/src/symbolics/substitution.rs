@@ -43,6 +43,16 @@ impl Expr {
             Expr::Neg(inner) => Expr::Neg(
                 Box::new(inner.substitute(target_var, replacement))
             ),
+
+            Expr::Pow(base, exp) => Expr::Pow(
+                Box::new(base.substitute(target_var, replacement)),
+                Box::new(exp.substitute(target_var, replacement)),
+            ),
+
+            Expr::Func(name, arg) => Expr::Func(
+                name.clone(),
+                Box::new(arg.substitute(target_var, replacement)),
+            ),
         }
     }
 }
@@ -6,11 +6,13 @@ pub mod data_structures;
 pub mod conversions;
 pub mod matrixes;
 pub mod networking;
+pub mod optimization;
 
 pub fn register_modules(parent: &Bound<'_, PyModule>) -> PyResult<()> {
     boolean_algebra::register(parent)?;
     data_structures::register(parent)?;
     conversions::register(parent)?;
     networking::register(parent)?;
+    optimization::register(parent)?;
     Ok(())  
 }
@@ -0,0 +1,124 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::HashMap;
+
+use suma_core::optimization::linear::algorithms::simplex::solve_primal;
+use suma_core::optimization::linear::model::{
+    Constraint, LinearExpression, LinearProblem, Objective, OptimizationDirection, Relation,
+};
+
+/// Problema de programación lineal, resuelto con el Simplex del Core.
+#[pyclass(name = "LinearProblem")]
+pub struct PyLinearProblem {
+    inner: LinearProblem,
+}
+
+#[pymethods]
+impl PyLinearProblem {
+    #[new]
+    #[pyo3(signature = (name = "LP"))]
+    #[pyo3(text_signature = "(name='LP')")]
+    fn new(name: &str) -> Self {
+        PyLinearProblem {
+            inner: LinearProblem::new(name, Objective::maximize(LinearExpression::new())),
+        }
+    }
+
+    /// Define (o reemplaza) la función objetivo, ej: `add_objective({"x": 30, "y": 50}, "maximize")`
+    #[pyo3(signature = (coefficients, direction = "maximize", constant = 0.0))]
+    #[pyo3(text_signature = "($self, coefficients, direction='maximize', constant=0.0)")]
+    fn add_objective(&mut self, coefficients: HashMap<String, f64>, direction: &str, constant: f64) -> PyResult<()> {
+        let direction = match direction.to_lowercase().as_str() {
+            "max" | "maximize" => OptimizationDirection::Maximize,
+            "min" | "minimize" => OptimizationDirection::Minimize,
+            other => return Err(PyValueError::new_err(format!(
+                "Dirección inválida '{}', se esperaba 'maximize' o 'minimize'", other
+            ))),
+        };
+
+        let mut expression = to_expression(coefficients);
+        expression.set_constant(constant);
+        self.inner.objective = Objective::new(direction, expression);
+        Ok(())
+    }
+
+    /// Agrega una restricción, ej: `add_constraint({"x": 1, "y": 2}, "<=", 20)`.
+    /// Sin nombre se usa `c{i}`, como en los modelos Codex.
+    #[pyo3(signature = (coefficients, relation, rhs, name = None))]
+    #[pyo3(text_signature = "($self, coefficients, relation, rhs, name=None)")]
+    fn add_constraint(
+        &mut self,
+        coefficients: HashMap<String, f64>,
+        relation: &str,
+        rhs: f64,
+        name: Option<String>,
+    ) -> PyResult<()> {
+        let relation = match relation {
+            "<=" => Relation::LessOrEqual,
+            ">=" => Relation::GreaterOrEqual,
+            "=" | "==" => Relation::Equal,
+            other => return Err(PyValueError::new_err(format!(
+                "Relación no soportada '{}', se esperaba '<=', '>=' o '='", other
+            ))),
+        };
+
+        let name = name.unwrap_or_else(|| format!("c{}", self.inner.constraints.len()));
+        let constraint = Constraint::new(to_expression(coefficients), relation, rhs).with_name(&name);
+        self.inner.add_constraint(constraint);
+        Ok(())
+    }
+
+    /// Resuelve con Simplex y devuelve un dict con `status`, `objective_value`,
+    /// `variables` y `shadow_prices`. Un problema infactible o no acotado lanza `ValueError`.
+    #[pyo3(text_signature = "($self)")]
+    fn solve(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let solution = solve_primal(&self.inner)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        let dict = PyDict::new(py);
+        dict.set_item("status", format!("{:?}", solution.status))?;
+        dict.set_item("objective_value", solution.objective_value)?;
+        dict.set_item("variables", solution.variables)?;
+        dict.set_item("shadow_prices", solution.shadow_prices)?;
+        Ok(dict.into())
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name.clone()
+    }
+
+    #[getter]
+    fn num_constraints(&self) -> usize {
+        self.inner.constraints.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "LinearProblem(name='{}', {}, constraints={})",
+            self.inner.name, self.inner.objective, self.inner.constraints.len()
+        )
+    }
+}
+
+fn to_expression(coefficients: HashMap<String, f64>) -> LinearExpression {
+    let mut expression = LinearExpression::new();
+    for (var, coeff) in coefficients {
+        expression.add_term(&var, coeff);
+    }
+    expression
+}
+
+pub fn register(parent: &Bound<'_, PyModule>) -> PyResult<()> {
+    let submodule = PyModule::new(parent.py(), "optimization")?;
+
+    submodule.add_class::<PyLinearProblem>()?;
+
+    parent.add_submodule(&submodule)?;
+    parent.py().import("sys")?
+        .getattr("modules")?
+        .set_item("suma_ulsa.optimization", submodule)?;
+
+    Ok(())
+}
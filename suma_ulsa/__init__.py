@@ -14,6 +14,7 @@ except ImportError as e:
 from .conversions import *
 from .boolean_algebra import *
 from .networking import *
+from .optimization import *
 
 __version__ = "0.1.13"
 
@@ -40,5 +41,8 @@ __all__ = [
     # Networking
     "FLSMCalculator",
     "SubnetRow",
-    "VLSMCalculator"
+    "VLSMCalculator",
+
+    # Optimization
+    "LinearProblem",
 ]
@@ -0,0 +1,9 @@
+from .optimization import (
+    LinearProblem,
+)
+
+__all__ = [
+    "LinearProblem",
+]
+if False:
+    from .optimization import *  # Ayuda a linters
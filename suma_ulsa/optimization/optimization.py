@@ -0,0 +1,13 @@
+"""
+Optimization module bindings
+"""
+try:
+    # Import from Rust extension
+    from ..suma_ulsa.optimization import *
+except ImportError:
+    # Fallback for type checkers
+    pass
+
+__all__ = [
+    "LinearProblem",
+]
@@ -0,0 +1,58 @@
+from typing import Dict, Literal, Optional, TypedDict
+
+Direction = Literal["maximize", "minimize", "max", "min"]
+RelationOp = Literal["<=", ">=", "=", "=="]
+
+class Solution(TypedDict):
+    """Resultado de `LinearProblem.solve()`."""
+    status: str
+    objective_value: float
+    variables: Dict[str, float]
+    shadow_prices: Dict[str, float]
+
+class LinearProblem:
+    """Problema de programación lineal resuelto con el método Simplex.
+
+    Ejemplo
+    -------
+    >>> lp = LinearProblem("Produccion")
+    >>> lp.add_objective({"x": 30, "y": 50}, "maximize")
+    >>> lp.add_constraint({"x": 1, "y": 2}, "<=", 20, name="madera")
+    >>> lp.add_constraint({"x": 1}, "<=", 10, name="horas")
+    >>> lp.solve()["objective_value"]
+    550.0
+    """
+
+    def __init__(self, name: str = "LP") -> None: ...
+
+    def add_objective(
+        self,
+        coefficients: Dict[str, float],
+        direction: Direction = "maximize",
+        constant: float = 0.0,
+    ) -> None:
+        """Define (o reemplaza) la función objetivo."""
+        ...
+
+    def add_constraint(
+        self,
+        coefficients: Dict[str, float],
+        relation: RelationOp,
+        rhs: float,
+        name: Optional[str] = None,
+    ) -> None:
+        """Agrega una restricción. Sin nombre se usa `c0`, `c1`, ..."""
+        ...
+
+    def solve(self) -> Solution:
+        """Resuelve el problema.
+
+        Lanza `ValueError` si es infactible o no acotado.
+        """
+        ...
+
+    @property
+    def name(self) -> str: ...
+
+    @property
+    def num_constraints(self) -> int: ...